@@ -0,0 +1,14 @@
+-- Adds the provider + the provider's own id for a job,
+-- so re-running a search updates the existing row instead of creating a duplicate.
+ALTER TABLE jobs ADD COLUMN provider TEXT NOT NULL DEFAULT 'linkedin';
+
+ALTER TABLE jobs ADD COLUMN provider_job_id TEXT NOT NULL DEFAULT '';
+
+-- Rows created before this migration have no provider id, give them a unique placeholder
+UPDATE jobs
+SET
+  provider_job_id = 'legacy-' || id
+WHERE
+  provider_job_id = '';
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_jobs_provider_job_id ON jobs (provider, provider_job_id);
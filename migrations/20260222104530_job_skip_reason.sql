@@ -0,0 +1,3 @@
+-- Why a job was skipped during validation (already_applied | no_easy_apply | job_closed)
+-- NULL when the job has not been skipped
+ALTER TABLE jobs ADD COLUMN skip_reason TEXT;
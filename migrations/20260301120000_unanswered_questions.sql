@@ -0,0 +1,12 @@
+-- Questions from application forms we had no autofill answer for.
+-- Once the user supplies an answer (into db.autofill) the row is removed.
+CREATE TABLE IF NOT EXISTS unanswered_question (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  input_key TEXT NOT NULL UNIQUE, -- normalised label, matches db.autofill.input_key
+  label TEXT NOT NULL, -- the label as it was shown on the form
+  field_kind TEXT NOT NULL, -- text | number | bullet_point | tick_box | dropdown | ...
+  options TEXT NOT NULL DEFAULT '[]', -- serialized JSON array of the options offered (if any)
+  times_seen INTEGER NOT NULL DEFAULT 1,
+  first_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_seen DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
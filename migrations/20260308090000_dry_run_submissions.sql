@@ -0,0 +1,10 @@
+-- What a dry run would have submitted for a job.
+-- The form is filled up to the Submit button, then discarded instead of submitted.
+CREATE TABLE IF NOT EXISTS dry_run_submission (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  job_fk INTEGER NOT NULL,
+  answers TEXT NOT NULL DEFAULT '[]', -- serialized JSON array of { label, kind, value }
+  resume_path TEXT, -- NULL when the provider's pre-selected resume was kept
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (job_fk) REFERENCES jobs (id)
+);
@@ -0,0 +1,12 @@
+-- Why the filter rules rejected a job, one row per failed rule.
+-- A job rejected by the filters has its skip_reason set to 'filtered'.
+CREATE TABLE IF NOT EXISTS job_rejection (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  job_fk INTEGER NOT NULL,
+  rule TEXT NOT NULL, -- title_excluded | company_blocked | forbidden_keyword | ...
+  detail TEXT NOT NULL DEFAULT '', -- the pattern/keyword/value that failed the rule
+  created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (job_fk) REFERENCES jobs (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_rejection_job_fk ON job_rejection (job_fk);
@@ -0,0 +1,8 @@
+-- Posting dates as real timestamps (UTC), alongside the text they were parsed from.
+-- posted_at is NULL when neither full_date nor relative_date could be parsed,
+-- and for jobs stored before these columns existed.
+ALTER TABLE jobs ADD COLUMN posted_at DATETIME;
+-- When the job card was read, what relative_date ('1 week ago') is relative to
+ALTER TABLE jobs ADD COLUMN scraped_at DATETIME;
+
+CREATE INDEX IF NOT EXISTS idx_jobs_posted_at ON jobs (posted_at);
@@ -0,0 +1,12 @@
+-- What the job's details pane said, one row per job, refreshed whenever the job is opened again.
+CREATE TABLE IF NOT EXISTS job_description (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  job_fk INTEGER NOT NULL UNIQUE,
+  description_text TEXT NOT NULL, -- the description as it reads on the page
+  description_html TEXT NOT NULL, -- inner HTML of the description, for the formatting
+  salary TEXT, -- as written, ie: '$120,000 – $140,000 per year'
+  seniority_level TEXT, -- Mid-Senior level | Entry level | ...
+  employment_type TEXT, -- Full-time | Contract | ...
+  scraped_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (job_fk) REFERENCES jobs (id)
+);
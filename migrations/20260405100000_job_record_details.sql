@@ -0,0 +1,73 @@
+-- Full-time | Part-time | Contract | ... as the job's details pane words it, normalised
+CREATE TABLE IF NOT EXISTS employment_type (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  type TEXT NOT NULL UNIQUE -- full_time | part_time | contract | temporary | internship | volunteer | other
+);
+
+INSERT OR IGNORE INTO
+  employment_type (type)
+VALUES
+  ('full_time'),
+  ('part_time'),
+  ('contract'),
+  ('temporary'),
+  ('internship'),
+  ('volunteer'),
+  ('other');
+
+-- The job's own page, NULL for jobs only known by a fingerprint of their card
+ALTER TABLE jobs ADD COLUMN job_url TEXT;
+-- As written on the card or details pane, ie: '$120,000 – $140,000 per year'
+ALTER TABLE jobs ADD COLUMN salary TEXT;
+-- NULL until the job's description has been read
+ALTER TABLE jobs ADD COLUMN employment_type_fk INTEGER REFERENCES employment_type (id);
+-- Why the last application attempt failed, NULL when it didn't
+ALTER TABLE jobs ADD COLUMN failure_reason TEXT;
+-- SQLite can't add a column defaulting to CURRENT_TIMESTAMP, these are set on insert/update instead
+ALTER TABLE jobs ADD COLUMN discovered_at DATETIME;
+ALTER TABLE jobs ADD COLUMN updated_at DATETIME;
+
+-- applied_date has always defaulted to the time the row was inserted
+UPDATE jobs
+SET
+  discovered_at = COALESCE(applied_date, CURRENT_TIMESTAMP),
+  updated_at = COALESCE(scraped_at, applied_date, CURRENT_TIMESTAMP);
+
+UPDATE jobs
+SET
+  job_url = 'https://www.linkedin.com/jobs/view/' || provider_job_id || '/'
+WHERE
+  provider = 'linkedin'
+  AND provider_job_id NOT LIKE 'fp-%'
+  AND provider_job_id NOT LIKE 'legacy-%';
+
+UPDATE jobs
+SET
+  job_url = 'https://www.seek.com.au/job/' || provider_job_id
+WHERE
+  provider = 'seek'
+  AND provider_job_id NOT LIKE 'fp-%'
+  AND provider_job_id NOT LIKE 'legacy-%';
+
+-- Along with (provider, provider_job_id), a job seen again on a later run maps back onto its row
+CREATE UNIQUE INDEX IF NOT EXISTS idx_jobs_job_url ON jobs (job_url)
+WHERE
+  job_url IS NOT NULL;
+
+-- A rule is recorded once per job, see `delete_job_rejections`
+DELETE FROM job_rejection
+WHERE
+  id NOT IN (
+    SELECT
+      MIN(id)
+    FROM
+      job_rejection
+    GROUP BY
+      job_fk,
+      rule,
+      detail
+  );
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_job_rejection_rule ON job_rejection (job_fk, rule, detail);
+
+CREATE INDEX IF NOT EXISTS idx_jobs_updated_at ON jobs (updated_at);
@@ -0,0 +1,51 @@
+-- skipped: failed validation (no Easy Apply, closed, ...), see jobs.skip_reason
+-- filtered: rejected by the filter rules, see job_rejection
+-- needs_attention: the application stopped on something only the user can sort out
+-- submitted: the application was sent, completed once the provider shows it as applied
+INSERT OR IGNORE INTO
+  status_type (type)
+VALUES
+  ('skipped'),
+  ('filtered'),
+  ('needs_attention'),
+  ('submitted');
+
+-- Every status change a job went through, oldest first by id.
+-- from_status_fk is NULL for the job's first status (when it was discovered)
+CREATE TABLE IF NOT EXISTS job_status_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  job_fk INTEGER NOT NULL,
+  from_status_fk INTEGER,
+  to_status_fk INTEGER NOT NULL,
+  reason TEXT, -- why the job changed status, ie: the failure or skip reason
+  changed_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (job_fk) REFERENCES jobs (id),
+  FOREIGN KEY (from_status_fk) REFERENCES status_type (id),
+  FOREIGN KEY (to_status_fk) REFERENCES status_type (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_job_status_history_job_fk ON job_status_history (job_fk);
+
+-- Pending jobs that failed validation were only marked by their skip_reason until now
+UPDATE jobs
+SET
+  status_type_fk = (
+    SELECT id FROM status_type
+    WHERE type = CASE jobs.skip_reason WHEN 'filtered' THEN 'filtered' ELSE 'skipped' END
+  )
+WHERE
+  skip_reason IS NOT NULL
+  AND skip_reason != 'already_applied'
+  AND status_type_fk = (SELECT id FROM status_type WHERE type = 'pending');
+
+-- Existing jobs start their history at the status they're in now
+INSERT INTO
+  job_status_history (job_fk, from_status_fk, to_status_fk, reason, changed_at)
+SELECT
+  id,
+  NULL,
+  status_type_fk,
+  'status before history was kept',
+  COALESCE(updated_at, discovered_at, CURRENT_TIMESTAMP)
+FROM
+  jobs;
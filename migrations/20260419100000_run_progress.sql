@@ -0,0 +1,16 @@
+-- How far a run (or scan) got through the search results, so an interrupted one
+-- can carry on where it stopped instead of starting over from the first page
+CREATE TABLE IF NOT EXISTS run_progress (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  provider TEXT NOT NULL,
+  search_params TEXT NOT NULL, -- serialized JSON of the search settings, a resumed run must match them
+  pages_done INTEGER NOT NULL DEFAULT 0, -- pages of results fully worked through
+  jobs_done INTEGER NOT NULL DEFAULT 0, -- listings read on those pages
+  last_job_fk INTEGER, -- the last job the run finished with
+  started_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  finished_at DATETIME, -- NULL while the run can still be resumed
+  FOREIGN KEY (last_job_fk) REFERENCES jobs (id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_run_progress_provider ON run_progress (provider, finished_at);
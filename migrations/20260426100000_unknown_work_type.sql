@@ -0,0 +1,5 @@
+-- Job cards that don't say whether the job is on-site, remote or hybrid
+INSERT OR IGNORE INTO
+  work_type (type)
+VALUES
+  ('unknown');
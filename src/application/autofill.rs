@@ -0,0 +1,407 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use crate::application::fields::FormField;
+use crate::db::{
+    create_unanswered_question,
+    delete_unanswered_question,
+    get_autofill,
+    update_autofill,
+};
+use crate::prelude::*;
+use crate::states::ApplicationElements;
+
+/// A stored answer for a form question, serialized as JSON into `db.autofill.data`.
+///
+/// ```json
+/// { "type": "number", "value": 5 }
+/// { "type": "choice", "value": "Yes" }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum AutofillAnswer {
+    Text(String),
+    Number(f64),
+    Choice(String),
+    Boolean(bool),
+}
+
+impl AutofillAnswer {
+    /// Turns what the user typed at the pause prompt into an answer fitting the field.
+    ///
+    /// For bullet points/dropdowns, either the (1-based) option number or its text is accepted.
+    /// Returns `None` if the input doesn't fit (not a number, not one of the options, etc.)
+    pub fn from_user_input(field: &FormField, input: &str) -> Option<Self> {
+        let input = input.trim();
+
+        match field.kind {
+            ApplicationElements::TextField => Some(AutofillAnswer::Text(input.to_string())),
+            ApplicationElements::NumberField => {
+                input
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|n| n.is_finite())
+                    .map(AutofillAnswer::Number)
+            }
+            ApplicationElements::BulletPoint | ApplicationElements::Dropdown => {
+                let by_number = input
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|n| n.checked_sub(1))
+                    .and_then(|idx| field.options.get(idx));
+                let by_text = field
+                    .options
+                    .iter()
+                    .find(|option| option.trim().eq_ignore_ascii_case(input));
+
+                by_number
+                    .or(by_text)
+                    .map(|option| AutofillAnswer::Choice(option.clone()))
+            }
+            ApplicationElements::TickBox | ApplicationElements::FollowCompanyTickBox => {
+                match input.to_lowercase().as_str() {
+                    "y" | "yes" | "true" => Some(AutofillAnswer::Boolean(true)),
+                    "n" | "no" | "false" => Some(AutofillAnswer::Boolean(false)),
+                    _ => None,
+                }
+            }
+            ApplicationElements::ResumeUpload => None,
+        }
+    }
+
+    /// The answer as it would be typed into a text box or picked from a list.
+    pub fn as_input(&self) -> String {
+        match self {
+            AutofillAnswer::Text(text) | AutofillAnswer::Choice(text) => text.clone(),
+            AutofillAnswer::Number(n) if n.fract() == 0.0 => format!("{}", *n as i64),
+            AutofillAnswer::Number(n) => n.to_string(),
+            AutofillAnswer::Boolean(true) => "Yes".to_string(),
+            AutofillAnswer::Boolean(false) => "No".to_string(),
+        }
+    }
+}
+
+/// Normalises a form label into the `input_key` used by the autofill table.
+///
+/// ie: `How many years of work experience do you have with Rust?`
+///  -> `how_many_years_of_work_experience_do_you_have_with_rust`
+pub fn input_key(label: &str) -> String {
+    label
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Answers application form questions from the `autofill` table,
+/// and keeps track of the questions it couldn't answer.
+#[derive(Debug, Clone)]
+pub struct AutofillEngine {
+    pub pool: SqlitePool,
+}
+
+impl AutofillEngine {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn lookup(&self, field: &FormField) -> Option<AutofillAnswer> {
+        let key = input_key(&field.label);
+        let data = get_autofill(&self.pool, &key)
+            .await
+            .map_err(|e| warn!("Failed to look up an answer for '{}': {}", key, e))
+            .ok()
+            .flatten()?;
+
+        serde_json::from_str(&data)
+            .map_err(|e| {
+                warn!("Ignoring malformed autofill data for key '{}': {}", key, e);
+            })
+            .ok()
+    }
+
+    /// Fills the field from a stored answer.
+    ///
+    /// Returns `false` when there's no usable answer, the question is then
+    /// recorded in `db.unanswered_question` so the user can supply one.
+    pub async fn fill(&self, field: &FormField) -> Result<bool> {
+        if let Some(answer) = self.lookup(field).await {
+            match apply_answer(field, &answer).await {
+                Ok(true) => {
+                    debug!("Autofilled '{}' with {:?}", field.label, answer);
+                    return Ok(true);
+                }
+                Ok(false) => {
+                    warn!(
+                        "Stored answer {:?} doesn't fit '{}' ({:?}), options: {:?}",
+                        answer, field.label, field.kind, field.options
+                    );
+                }
+                Err(e) => warn!("Failed to autofill '{}': {}", field.label, e),
+            }
+        }
+
+        if field.required {
+            self.record_unanswered(field).await?;
+        }
+        Ok(false)
+    }
+
+    pub async fn record_unanswered(&self, field: &FormField) -> Result<()> {
+        let options = serde_json::to_string(&field.options).unwrap_or_else(|_| "[]".to_string());
+        create_unanswered_question(
+            &self.pool,
+            &input_key(&field.label),
+            &field.label,
+            field.kind.into(),
+            &options,
+        )
+        .await
+    }
+
+    /// Stores (or replaces) the answer for a question, so it's reused on every later application.
+    pub async fn save_answer(&self, label: &str, answer: &AutofillAnswer) -> Result<()> {
+        let key = input_key(label);
+        let data = serde_json::to_string(answer).map_err(|e| {
+            Error::Generic(format!("Failed to serialize autofill answer for '{}': {}", key, e))
+        })?;
+
+        update_autofill(&self.pool, &key, &data).await?;
+        delete_unanswered_question(&self.pool, &key).await
+    }
+}
+
+/// Applies an answer to the field's input(s), based on its `ApplicationElements` kind.
+///
+/// Returns `Ok(false)` when the answer doesn't fit the field,
+/// (ie: a choice that isn't one of the options)
+pub async fn apply_answer(field: &FormField, answer: &AutofillAnswer) -> Result<bool> {
+    let Some(first) = field.inputs.first() else {
+        return Ok(false);
+    };
+
+    match field.kind {
+        ApplicationElements::TextField | ApplicationElements::NumberField => {
+            if field.kind == ApplicationElements::NumberField
+                && answer.as_input().parse::<f64>().is_err()
+            {
+                return Ok(false);
+            }
+
+            first
+                .clear()
+                .await
+                .map_err(|e| cmd_error(&field.label, e))?;
+            first
+                .send_keys(&answer.as_input())
+                .await
+                .map_err(|e| cmd_error(&field.label, e))?;
+            Ok(true)
+        }
+        ApplicationElements::BulletPoint => {
+            let wanted = answer.as_input();
+            let Some(idx) = field
+                .options
+                .iter()
+                .position(|option| option.trim().eq_ignore_ascii_case(wanted.trim()))
+            else {
+                return Ok(false);
+            };
+
+            field.inputs[idx]
+                .click()
+                .await
+                .map_err(|e| cmd_error(&field.label, e))?;
+            Ok(true)
+        }
+        ApplicationElements::Dropdown => {
+            let wanted = answer.as_input();
+            let Some(option) = field
+                .options
+                .iter()
+                .find(|option| option.trim().eq_ignore_ascii_case(wanted.trim()))
+            else {
+                return Ok(false);
+            };
+
+            first
+                .select_by_label(option)
+                .await
+                .map_err(|e| cmd_error(&field.label, e))?;
+            Ok(true)
+        }
+        ApplicationElements::TickBox | ApplicationElements::FollowCompanyTickBox => {
+            let AutofillAnswer::Boolean(wanted) = answer else {
+                return Ok(false);
+            };
+
+            if first.is_selected().await.unwrap_or(false) != *wanted {
+                first
+                    .click()
+                    .await
+                    .map_err(|e| cmd_error(&field.label, e))?;
+            }
+            Ok(true)
+        }
+        // Handled by the wizard itself (config driven), never autofilled
+        ApplicationElements::ResumeUpload => Ok(false),
+    }
+}
+
+fn cmd_error(label: &str, e: fantoccini::error::CmdError) -> Error {
+    Error::Generic(format!("Failed to fill '{}': {}", label, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::get_unanswered_questions;
+    use crate::db::testing::test_pool;
+
+    fn field(kind: ApplicationElements, options: &[&str]) -> FormField {
+        FormField {
+            label: "Question".to_string(),
+            kind,
+            required: true,
+            options: options.iter().map(|option| option.to_string()).collect(),
+            inputs: vec![],
+        }
+    }
+
+    #[test]
+    fn input_keys() {
+        let cases = [
+            (
+                "How many years of work experience do you have with Rust?",
+                "how_many_years_of_work_experience_do_you_have_with_rust",
+            ),
+            ("Salary expectations (AUD$)?", "salary_expectations_aud"),
+            ("C++/C# experience", "c_c_experience"),
+            ("  Mobile   phone number*  ", "mobile_phone_number"),
+            ("Années d'expérience", "années_d_expérience"),
+            ("ÜBER Straße", "über_straße"),
+            ("🚀 Ready to launch?", "ready_to_launch"),
+            ("???", ""),
+        ];
+
+        for (label, key) in cases {
+            assert_eq!(input_key(label), key, "{}", label);
+        }
+    }
+
+    #[test]
+    fn answers_from_user_input() {
+        use ApplicationElements::{
+            BulletPoint,
+            Dropdown,
+            FollowCompanyTickBox,
+            NumberField,
+            ResumeUpload,
+            TextField,
+            TickBox,
+        };
+        use AutofillAnswer::{Boolean, Choice, Number, Text};
+
+        let years = ["1-2 years", "3-5 years", "5+ years"];
+        let numbered = ["10", "20"];
+        let choice = |option: &str| Some(Choice(option.to_string()));
+
+        let cases: [(ApplicationElements, &[&str], &str, Option<AutofillAnswer>); 27] = [
+            (TextField, &[], "  Sydney, NSW ", Some(Text("Sydney, NSW".to_string()))),
+            (TextField, &[], "", Some(Text(String::new()))),
+            (NumberField, &[], "5", Some(Number(5.0))),
+            (NumberField, &[], " 2.5 ", Some(Number(2.5))),
+            (NumberField, &[], "-1", Some(Number(-1.0))),
+            (NumberField, &[], "five", None),
+            (NumberField, &[], "", None),
+            (NumberField, &[], "NaN", None),
+            (NumberField, &[], "inf", None),
+            // 1-based option numbers
+            (BulletPoint, &years, "1", choice("1-2 years")),
+            (BulletPoint, &years, "3", choice("5+ years")),
+            (BulletPoint, &years, "0", None),
+            (BulletPoint, &years, "4", None),
+            (BulletPoint, &years, "-1", None),
+            // ...or the option text, case insensitive
+            (BulletPoint, &years, "3-5 YEARS", choice("3-5 years")),
+            (Dropdown, &years, " 5+ years ", choice("5+ years")),
+            (Dropdown, &years, "6 years", None),
+            // A number is an option number first, then option text
+            (Dropdown, &numbered, "2", choice("20")),
+            (Dropdown, &numbered, "10", choice("10")),
+            (TickBox, &[], "yes", Some(Boolean(true))),
+            (TickBox, &[], "Y", Some(Boolean(true))),
+            (TickBox, &[], "TRUE", Some(Boolean(true))),
+            (FollowCompanyTickBox, &[], "no", Some(Boolean(false))),
+            (TickBox, &[], "n", Some(Boolean(false))),
+            (TickBox, &[], "False", Some(Boolean(false))),
+            (TickBox, &[], "maybe", None),
+            (ResumeUpload, &[], "resume.pdf", None),
+        ];
+
+        for (kind, options, input, expected) in cases {
+            assert_eq!(
+                AutofillAnswer::from_user_input(&field(kind, options), input),
+                expected,
+                "{:?} {:?}",
+                kind,
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn answers_as_input() {
+        let cases = [
+            (AutofillAnswer::Number(5.0), "5"),
+            (AutofillAnswer::Number(0.0), "0"),
+            (AutofillAnswer::Number(-3.0), "-3"),
+            (AutofillAnswer::Number(2.5), "2.5"),
+            (AutofillAnswer::Number(120000.0), "120000"),
+            (AutofillAnswer::Text("Sydney".to_string()), "Sydney"),
+            (AutofillAnswer::Choice("3-5 years".to_string()), "3-5 years"),
+            (AutofillAnswer::Boolean(true), "Yes"),
+            (AutofillAnswer::Boolean(false), "No"),
+        ];
+
+        for (answer, input) in cases {
+            assert_eq!(answer.as_input(), input, "{:?}", answer);
+        }
+    }
+
+    #[tokio::test]
+    async fn saving_an_answer_clears_the_unanswered_question() {
+        let engine = AutofillEngine::new(test_pool().await);
+        let mut years = field(ApplicationElements::NumberField, &[]);
+        years.label = "Years of experience with Rust?".to_string();
+        let mut city = field(ApplicationElements::TextField, &[]);
+        city.label = "Which city are you in?".to_string();
+
+        engine.record_unanswered(&years).await.unwrap();
+        engine.record_unanswered(&city).await.unwrap();
+        assert_eq!(engine.lookup(&years).await, None);
+
+        // Same question, as long as the label has the same key
+        engine
+            .save_answer("years of experience with rust", &AutofillAnswer::Number(4.0))
+            .await
+            .unwrap();
+
+        let unanswered: Vec<String> = get_unanswered_questions(&engine.pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|question| question.input_key)
+            .collect();
+        assert_eq!(unanswered, ["which_city_are_you_in"]);
+        assert_eq!(engine.lookup(&years).await, Some(AutofillAnswer::Number(4.0)));
+
+        // Saving again replaces the answer
+        engine
+            .save_answer(&years.label, &AutofillAnswer::Number(5.0))
+            .await
+            .unwrap();
+        assert_eq!(engine.lookup(&years).await, Some(AutofillAnswer::Number(5.0)));
+    }
+}
@@ -0,0 +1,222 @@
+use std::sync::Arc;
+
+use fantoccini::elements::Element;
+
+use crate::prelude::*;
+use crate::providers::{Provider, SelectorKind};
+use crate::selectors::{ApplicationSelectors, SelectorField};
+use crate::states::ApplicationElements;
+
+/// Placeholder text used by dropdowns that haven't had a value picked yet.
+const UNSELECTED_OPTION: &str = "Select an option";
+
+/// A single question/field on a page of the application form.
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub label:    String,
+    pub kind:     ApplicationElements,
+    pub required: bool,
+    /// Radio labels or dropdown options, empty for free text fields.
+    pub options:  Vec<String>,
+    /// The input element(s) making up this field (one per radio button, etc.)
+    pub inputs:   Vec<Element>,
+}
+
+/// Finds and classifies every form field on the current page of the application.
+///
+/// The inputs inside each container are looked up with the same kind of selectors
+/// the containers were found by.
+/// Containers we can't classify (plain text blocks, headings, etc.) are skipped.
+pub async fn detect_fields(
+    client: &fantoccini::Client,
+    provider: Arc<dyn Provider + Send + Sync>,
+) -> Result<Vec<FormField>> {
+    let containers = provider
+        .find_all_field(client, SelectorField::application("form_field"))
+        .await?;
+    let kind = containers.kind();
+    let selectors = provider.get_application_selectors(kind);
+    let containers = containers.into_inner();
+
+    let mut fields = Vec::with_capacity(containers.len());
+
+    for container in containers {
+        match detect_field(&container, Arc::clone(&provider), &selectors, kind).await? {
+            Some(field) => fields.push(field),
+            None => trace!("Skipping form container with no recognised inputs."),
+        }
+    }
+
+    Ok(fields)
+}
+
+async fn detect_field(
+    container: &Element,
+    provider: Arc<dyn Provider + Send + Sync>,
+    selectors: &ApplicationSelectors,
+    kind: SelectorKind,
+) -> Result<Option<FormField>> {
+    // Order matters here, ie: a number field is also matched by some text input selectors
+    let candidates = [
+        (ApplicationElements::ResumeUpload, selectors.file_input),
+        (ApplicationElements::BulletPoint, selectors.radio_input),
+        (ApplicationElements::Dropdown, selectors.select_input),
+        (ApplicationElements::TickBox, selectors.checkbox_input),
+        (ApplicationElements::NumberField, selectors.number_input),
+        (ApplicationElements::TextField, selectors.text_input),
+    ];
+
+    let mut detected = None;
+    for (element_kind, selector) in candidates {
+        let inputs = provider
+            .with_child_elements(container, kind, selector)
+            .await?;
+        if !inputs.is_empty() {
+            detected = Some((element_kind, inputs));
+            break;
+        }
+    }
+
+    let Some((element_kind, inputs)) = detected else {
+        return Ok(None);
+    };
+
+    let raw_label = match provider
+        .with_child_elements(container, kind, selectors.field_label)
+        .await?
+        .first()
+    {
+        Some(label) => label.text().await.unwrap_or_default(),
+        None => String::new(),
+    };
+
+    let mut required = raw_label.trim_end().ends_with('*');
+    for input in &inputs {
+        let html_required = input.attr("required").await.ok().flatten().is_some();
+        let aria_required = input
+            .attr("aria-required")
+            .await
+            .ok()
+            .flatten()
+            .is_some_and(|v| v == "true");
+        required |= html_required || aria_required;
+    }
+
+    let options = match element_kind {
+        ApplicationElements::BulletPoint => {
+            let mut options = Vec::with_capacity(inputs.len());
+            for input in &inputs {
+                options.push(input.attr("value").await.ok().flatten().unwrap_or_default());
+            }
+            options
+        }
+        ApplicationElements::Dropdown => {
+            let mut options = vec![];
+            for option in provider
+                .with_child_elements(&inputs[0], kind, selectors.select_option)
+                .await?
+            {
+                let text = option.text().await.unwrap_or_default().trim().to_string();
+                if !text.is_empty() && text != UNSELECTED_OPTION {
+                    options.push(text);
+                }
+            }
+            options
+        }
+        _ => vec![],
+    };
+
+    Ok(Some(FormField {
+        label: clean_label(&raw_label),
+        kind: element_kind,
+        required,
+        options,
+        inputs,
+    }))
+}
+
+/// Strips the required marker and any duplicated text LinkedIn puts into labels.
+/// ie: `How many years of experience do you have with Rust?*`
+fn clean_label(raw: &str) -> String {
+    raw.lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .trim_end_matches('*')
+        .trim()
+        .to_string()
+}
+
+impl FormField {
+    /// Whether the field already holds a value, either pre-filled by the provider
+    /// (contact details, previously used resume, etc.) or by us on an earlier pass.
+    pub async fn is_filled(&self) -> bool {
+        match self.kind {
+            ApplicationElements::TextField | ApplicationElements::NumberField => {
+                match self.inputs.first() {
+                    Some(input) => {
+                        input
+                            .prop("value")
+                            .await
+                            .ok()
+                            .flatten()
+                            .is_some_and(|v| !v.trim().is_empty())
+                    }
+                    None => false,
+                }
+            }
+            ApplicationElements::BulletPoint
+            | ApplicationElements::TickBox
+            | ApplicationElements::FollowCompanyTickBox => {
+                for input in &self.inputs {
+                    if input.is_selected().await.unwrap_or(false) {
+                        return true;
+                    }
+                }
+                false
+            }
+            ApplicationElements::Dropdown => {
+                match self.inputs.first() {
+                    Some(select) => {
+                        select
+                            .prop("value")
+                            .await
+                            .ok()
+                            .flatten()
+                            .is_some_and(|v| !v.trim().is_empty() && v != UNSELECTED_OPTION)
+                    }
+                    None => false,
+                }
+            }
+            // Providers pre-select the last used resume, we can't reliably read that back
+            ApplicationElements::ResumeUpload => true,
+        }
+    }
+
+    /// The value the field currently holds, as it would be submitted.
+    /// `None` when nothing has been entered/picked (or for resume uploads).
+    pub async fn value(&self) -> Option<String> {
+        match self.kind {
+            ApplicationElements::TextField
+            | ApplicationElements::NumberField
+            | ApplicationElements::Dropdown => {
+                let value = self.inputs.first()?.prop("value").await.ok().flatten()?;
+                let value = value.trim();
+                (!value.is_empty() && value != UNSELECTED_OPTION).then(|| value.to_string())
+            }
+            ApplicationElements::BulletPoint => {
+                for (input, option) in self.inputs.iter().zip(&self.options) {
+                    if input.is_selected().await.unwrap_or(false) {
+                        return Some(option.clone());
+                    }
+                }
+                None
+            }
+            ApplicationElements::TickBox | ApplicationElements::FollowCompanyTickBox => {
+                let selected = self.inputs.first()?.is_selected().await.unwrap_or(false);
+                Some(if selected { "Yes" } else { "No" }.to_string())
+            }
+            ApplicationElements::ResumeUpload => None,
+        }
+    }
+}
@@ -0,0 +1,18 @@
+mod autofill;
+mod fields;
+mod pending;
+mod quota;
+mod resume;
+mod wizard;
+
+pub use crate::application::autofill::{AutofillAnswer, AutofillEngine, apply_answer, input_key};
+pub use crate::application::fields::{FormField, detect_fields};
+pub use crate::application::pending::apply_pending;
+pub use crate::application::quota::{QuotaHit, QuotaKind, check_quotas, wait_for_quotas};
+pub use crate::application::resume::{finish_run, resume_run, start_run};
+pub use crate::application::wizard::{
+    ApplicationOutcome,
+    ApplicationWizard,
+    DryRunSubmission,
+    SubmittedAnswer,
+};
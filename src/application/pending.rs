@@ -0,0 +1,133 @@
+use crate::application::{ApplicationOutcome, wait_for_quotas};
+use crate::db::{
+    JobRecord,
+    get_pending_jobs,
+    mark_job_failed,
+    update_job_skip_reason,
+    update_job_status,
+};
+use crate::output::ReportRow;
+use crate::pre_fsm::{scrape_job_description, wait_millis};
+use crate::prelude::*;
+use crate::states::{InvalidReason, RunContext, check_job_details, record_rejections};
+
+/// Applies to jobs left pending in the database (ie: by a `scan`),
+/// opening each job on its own page instead of going through the search results.
+/// Each job is re-validated first, when scanning (`RunContext::scan_only`) that's all that's
+/// done and the jobs that are still valid stay pending.
+///
+/// Jobs without the provider's own id (fingerprinted cards) can't be opened directly
+/// and are left pending. `limit` caps how many jobs are attempted, `None` for all of them.
+pub async fn apply_pending(ctx: &mut RunContext, limit: Option<usize>) -> Result<()> {
+    let limit = limit.map_or(-1, |limit| limit as i64);
+    let jobs = get_pending_jobs(&ctx.pool, ctx.provider.name(), limit).await?;
+    info!("Found {} pending {} jobs to apply to.", jobs.len(), ctx.provider.name());
+
+    for job in jobs {
+        if !ctx.scan_only && !wait_for_quotas(ctx).await? {
+            break;
+        }
+        ctx.counters.listings_found += 1;
+
+        if !job.has_provider_id() {
+            warn!(
+                "Job {} ({} @ {}) has no {} job id, it can't be opened directly.",
+                job.id,
+                job.job_title,
+                job.company_name,
+                ctx.provider.name()
+            );
+            ctx.counters.skipped += 1;
+            continue;
+        }
+
+        let outcome = match revalidate(ctx, &job).await {
+            Ok(Some(_)) => {
+                ctx.counters.skipped += 1;
+                continue;
+            }
+            Ok(None) if ctx.scan_only => {
+                info!("Job {} is still valid, leaving it pending.", job.id);
+                ctx.counters.validated += 1;
+                continue;
+            }
+            Ok(None) => {
+                ctx.counters.validated += 1;
+                ctx.wizard()
+                    .apply_job(job.id, &job.job_title, &job.company_name)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        ctx.reports
+            .record(&ReportRow::for_record(ctx.provider.as_ref(), &job).with_outcome(&outcome));
+
+        match outcome {
+            Ok(ApplicationOutcome::Submitted) => {
+                info!("Applied to job {}.", job.id);
+                ctx.counters.applied += 1;
+            }
+            Ok(ApplicationOutcome::DryRun(_)) => {
+                info!("Dry run, would have applied to job {}.", job.id);
+                ctx.counters.dry_runs += 1;
+            }
+            Ok(outcome) => {
+                warn!("Did not apply to job {}: {:?}", job.id, outcome);
+                ctx.counters.failed += 1;
+            }
+            // One bad job shouldn't stop the rest
+            Err(e) => {
+                error!("Application for job {} encountered an error: {}", job.id, e);
+                mark_job_failed(&ctx.pool, job.id, &e).await;
+                ctx.counters.errors += 1;
+            }
+        }
+    }
+
+    let c = &ctx.counters;
+    info!(
+        "Pending jobs done. Attempted: {} | Still valid: {} | Applied: {} | Dry runs: {} | Not applied: {} | Skipped: {} | Errors: {}",
+        c.listings_found, c.validated, c.applied, c.dry_runs, c.failed, c.skipped, c.errors
+    );
+    Ok(())
+}
+
+/// Opens the job's page and re-validates it against the details shown there and the
+/// description filter rules, returning why the job can't be applied to anymore, if it can't.
+async fn revalidate(ctx: &RunContext, job: &JobRecord) -> Result<Option<InvalidReason>> {
+    let url = ctx.provider.job_url(&job.provider_job_id);
+    debug!("Opening job {} at {}", job.id, url);
+    ctx.client
+        .goto(&url)
+        .await
+        .map_err(|e| Error::Generic(format!("Failed to open job page '{}': {}", url, e)))?;
+    wait_millis(1500).await;
+
+    let description =
+        scrape_job_description(&ctx.client, ctx.provider.as_ref(), &ctx.pool, job.id).await;
+
+    let mut reason = check_job_details(&ctx.client, ctx.provider.as_ref()).await?;
+    if reason.is_none() && ctx.filters.has_description_rules() {
+        let rejections = ctx
+            .filters
+            .check_description(description.as_ref().map(|d| d.text.as_str()));
+        if !rejections.is_empty() {
+            record_rejections(&ctx.pool, job.id, &rejections).await?;
+            reason = Some(InvalidReason::Filtered);
+        }
+    }
+
+    let Some(reason) = reason else {
+        return Ok(None);
+    };
+
+    info!("Skipping job {} ({} @ {}): {:?}", job.id, job.job_title, job.company_name, reason);
+    update_job_skip_reason(&ctx.pool, job.id, Some(reason.into())).await?;
+    update_job_status(&ctx.pool, job.id, reason.status(), Some(reason.into())).await?;
+
+    if reason != InvalidReason::AlreadyApplied {
+        ctx.reports
+            .record(&ReportRow::for_record(ctx.provider.as_ref(), job).with_skip_reason(reason));
+    }
+    Ok(Some(reason))
+}
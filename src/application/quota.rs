@@ -0,0 +1,344 @@
+use std::fmt;
+
+use chrono::{DateTime, Local, NaiveDate, NaiveTime, TimeZone, Utc};
+use sqlx::SqlitePool;
+
+use crate::config::QuotaConfig;
+use crate::db::count_applications_since;
+use crate::prelude::*;
+use crate::providers::ProviderKind;
+use crate::states::{PausedState, RunContext};
+
+/// Which of the `QuotaConfig` caps was used up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaKind {
+    Run,
+    Day,
+    ProviderDay(ProviderKind),
+}
+
+/// A quota with no room left for another application.
+#[derive(Debug, Clone, Copy)]
+pub struct QuotaHit {
+    pub kind:  QuotaKind,
+    pub limit: u32,
+    /// Applications sent so far in the quota's window.
+    pub used:  i64,
+}
+
+impl fmt::Display for QuotaHit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            QuotaKind::Run => write!(f, "Run quota of {} applications used up", self.limit),
+            QuotaKind::Day => {
+                write!(f, "Daily quota of {} applications used up ({} sent)", self.limit, self.used)
+            }
+            QuotaKind::ProviderDay(provider) => {
+                write!(
+                    f,
+                    "Daily {} quota of {} applications used up ({} sent)",
+                    provider, self.limit, self.used
+                )
+            }
+        }
+    }
+}
+
+impl QuotaHit {
+    /// When the quota frees up again, `None` for the run quota which only a new run resets.
+    pub fn resets_at(&self) -> Option<DateTime<Utc>> {
+        match self.kind {
+            QuotaKind::Run => None,
+            QuotaKind::Day | QuotaKind::ProviderDay(_) => {
+                local_midnight(Local::now().date_naive().succ_opt()?)
+            }
+        }
+    }
+
+    /// A pause lasting until the quota resets, when the config says to wait for it.
+    /// The caller sets the state to carry on with.
+    pub fn pause(&self, quotas: &QuotaConfig) -> Option<PausedState> {
+        if !quotas.wait_for_next_window {
+            return None;
+        }
+
+        let resets_at = self.resets_at()?;
+        let wait = resets_at.signed_duration_since(Utc::now()).num_seconds();
+        Some(PausedState {
+            reason:        format!(
+                "{}, waiting until {}",
+                self,
+                resets_at.with_timezone(&Local).format("%Y-%m-%d %H:%M %Z")
+            ),
+            // Zero would wait on the user instead
+            duration_secs: u64::try_from(wait).unwrap_or(0).max(1),
+            can_resume:    true,
+            question:      None,
+            resume:        None,
+        })
+    }
+}
+
+/// The first quota without room for another application, `None` while there's room.
+///
+/// The run quota counts this run's applications, the daily ones count what the database
+/// has moved to `submitted` since midnight.
+pub async fn check_quotas(ctx: &RunContext) -> Result<Option<QuotaHit>> {
+    quota_hit(
+        &ctx.pool,
+        &ctx.config.quotas,
+        ctx.provider.name(),
+        ctx.counters.applied,
+        Local::now().date_naive(),
+    )
+    .await
+}
+
+/// `check_quotas` on `today`, with `applied` applications sent so far this run.
+async fn quota_hit(
+    pool: &SqlitePool,
+    quotas: &QuotaConfig,
+    provider: ProviderKind,
+    applied: usize,
+    today: NaiveDate,
+) -> Result<Option<QuotaHit>> {
+    if let Some(limit) = quotas.max_per_run
+        && applied >= limit as usize
+    {
+        return Ok(Some(QuotaHit {
+            kind: QuotaKind::Run,
+            limit,
+            used: applied as i64,
+        }));
+    }
+
+    let Some(since) = local_midnight(today) else {
+        return Ok(None);
+    };
+
+    let daily = [
+        (QuotaKind::Day, quotas.max_per_day, None),
+        (QuotaKind::ProviderDay(provider), quotas.provider_max_per_day(provider), Some(provider)),
+    ];
+    for (kind, limit, provider) in daily {
+        let Some(limit) = limit else {
+            continue;
+        };
+
+        let used = count_applications_since(pool, provider, since).await?;
+        if used >= i64::from(limit) {
+            return Ok(Some(QuotaHit { kind, limit, used }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Waits out used up daily quotas when the config says to,
+/// `false` when a quota is used up and applying has to stop instead.
+pub async fn wait_for_quotas(ctx: &RunContext) -> Result<bool> {
+    while let Some(hit) = check_quotas(ctx).await? {
+        let Some(pause) = hit.pause(&ctx.config.quotas) else {
+            warn!("{}, no more applications this run.", hit);
+            return Ok(false);
+        };
+        pause.wait_for_user().await?;
+    }
+    Ok(true)
+}
+
+/// The start of `date` in local time, as the daily quotas count from it.
+fn local_midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    Local
+        .from_local_datetime(&date.and_time(NaiveTime::MIN))
+        .earliest()
+        .map(|midnight| midnight.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::{Duration, Timelike};
+
+    use super::*;
+    use crate::db::StatusType;
+    use crate::db::testing::{store_job, test_pool};
+
+    /// Mid-June, clear of the DST changes on either side of the world.
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 6, 15).unwrap()
+    }
+
+    fn midnight() -> DateTime<Utc> {
+        local_midnight(today()).unwrap()
+    }
+
+    /// Stores a job on `provider` that was submitted at `submitted_at`.
+    async fn submitted(pool: &SqlitePool, provider: ProviderKind, submitted_at: DateTime<Utc>) {
+        let company = format!("Company {}", submitted_at.timestamp());
+        let job_id = store_job(pool, provider, "Rust Engineer", &company, &[]).await;
+
+        let status: &str = StatusType::Submitted.into();
+        sqlx::query(
+            "INSERT INTO job_status_history (job_fk, to_status_fk, changed_at)
+             VALUES (?1, (SELECT id FROM status_type WHERE type = ?2), ?3)",
+        )
+        .bind(job_id)
+        .bind(status)
+        .bind(submitted_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn check(
+        pool: &SqlitePool,
+        quotas: &QuotaConfig,
+        provider: ProviderKind,
+        applied: usize,
+    ) -> Option<QuotaHit> {
+        quota_hit(pool, quotas, provider, applied, today())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn run_quota() {
+        let pool = test_pool().await;
+        let quotas = QuotaConfig {
+            max_per_run: Some(3),
+            ..QuotaConfig::default()
+        };
+
+        assert!(
+            check(&pool, &quotas, ProviderKind::LinkedIn, 2)
+                .await
+                .is_none()
+        );
+
+        let hit = check(&pool, &quotas, ProviderKind::LinkedIn, 3)
+            .await
+            .unwrap();
+        assert_eq!(hit.kind, QuotaKind::Run);
+        assert_eq!((hit.limit, hit.used), (3, 3));
+        assert_eq!(hit.resets_at(), None);
+    }
+
+    #[tokio::test]
+    async fn daily_quota_counts_status_history() {
+        let pool = test_pool().await;
+        submitted(&pool, ProviderKind::LinkedIn, midnight() - Duration::hours(2)).await;
+        submitted(&pool, ProviderKind::LinkedIn, midnight() + Duration::hours(9)).await;
+        submitted(&pool, ProviderKind::Seek, midnight() + Duration::hours(10)).await;
+
+        let quotas = |max_per_day| {
+            QuotaConfig {
+                max_per_day: Some(max_per_day),
+                ..QuotaConfig::default()
+            }
+        };
+
+        // Yesterday's application doesn't count, both providers' do
+        let hit = check(&pool, &quotas(2), ProviderKind::LinkedIn, 0)
+            .await
+            .unwrap();
+        assert_eq!(hit.kind, QuotaKind::Day);
+        assert_eq!((hit.limit, hit.used), (2, 2));
+
+        assert!(
+            check(&pool, &quotas(3), ProviderKind::LinkedIn, 0)
+                .await
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn provider_quota_ignores_key_case() {
+        let quotas = QuotaConfig {
+            max_per_day_by_provider: BTreeMap::from([("LinkedIn".to_string(), 1)]),
+            ..QuotaConfig::default()
+        };
+        assert_eq!(quotas.provider_max_per_day(ProviderKind::LinkedIn), Some(1));
+        assert_eq!(quotas.provider_max_per_day(ProviderKind::Seek), None);
+        assert!(quotas.validate().is_ok());
+
+        let pool = test_pool().await;
+        submitted(&pool, ProviderKind::LinkedIn, midnight() + Duration::hours(9)).await;
+        submitted(&pool, ProviderKind::Seek, midnight() + Duration::hours(9)).await;
+        submitted(&pool, ProviderKind::Seek, midnight() + Duration::hours(10)).await;
+
+        let hit = check(&pool, &quotas, ProviderKind::LinkedIn, 0)
+            .await
+            .unwrap();
+        assert_eq!(hit.kind, QuotaKind::ProviderDay(ProviderKind::LinkedIn));
+        assert_eq!((hit.limit, hit.used), (1, 1));
+
+        // Seek has no cap of its own
+        assert!(check(&pool, &quotas, ProviderKind::Seek, 0).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn daily_quota_starts_at_local_midnight() {
+        let pool = test_pool().await;
+        let quotas = QuotaConfig {
+            max_per_day: Some(1),
+            ..QuotaConfig::default()
+        };
+
+        submitted(&pool, ProviderKind::LinkedIn, midnight() - Duration::seconds(1)).await;
+        assert!(
+            check(&pool, &quotas, ProviderKind::LinkedIn, 0)
+                .await
+                .is_none()
+        );
+
+        submitted(&pool, ProviderKind::LinkedIn, midnight()).await;
+        let hit = check(&pool, &quotas, ProviderKind::LinkedIn, 0)
+            .await
+            .unwrap();
+        assert_eq!(hit.used, 1);
+    }
+
+    #[test]
+    fn local_midnight_is_the_start_of_the_local_day() {
+        let midnight = midnight().with_timezone(&Local);
+
+        assert_eq!(midnight.date_naive(), today());
+        assert_eq!((midnight.hour(), midnight.minute(), midnight.second()), (0, 0, 0));
+        assert_eq!(
+            local_midnight(today().succ_opt().unwrap()).unwrap() - midnight.with_timezone(&Utc),
+            Duration::days(1)
+        );
+    }
+
+    #[test]
+    fn pause_only_when_waiting_for_the_next_window() {
+        let day = QuotaHit {
+            kind:  QuotaKind::Day,
+            limit: 10,
+            used:  10,
+        };
+        let run = QuotaHit {
+            kind: QuotaKind::Run,
+            ..day
+        };
+
+        let stop = QuotaConfig::default();
+        assert!(day.pause(&stop).is_none());
+        assert!(run.pause(&stop).is_none());
+
+        let wait = QuotaConfig {
+            wait_for_next_window: true,
+            ..QuotaConfig::default()
+        };
+        let pause = day.pause(&wait).unwrap();
+        assert!(pause.can_resume);
+        assert!(pause.duration_secs >= 1);
+        assert!(pause.duration_secs <= 24 * 60 * 60 + 60 * 60);
+        assert!(pause.question.is_none());
+
+        // Only a new run resets the run quota
+        assert!(run.pause(&wait).is_none());
+    }
+}
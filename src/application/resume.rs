@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use crate::application::apply_pending;
+use crate::config::SearchConfig;
+use crate::db::{
+    JobQuery,
+    Page,
+    RunProgress,
+    StatusType,
+    create_run_progress,
+    find_jobs,
+    get_unfinished_run,
+    update_job_status,
+    update_run_finished,
+};
+use crate::pre_fsm::{next_results_page, scroll_job_list, wait_millis};
+use crate::prelude::*;
+use crate::states::{JobListingSearch, RunContext};
+
+/// Starts tracking the run's progress in `db.run_progress`, setting `RunContext::run_id`.
+///
+/// When the provider's last run didn't get to the end of its search and searched with the same
+/// settings, that run is carried on with and returned (unless `resume` is off). Any other
+/// unfinished run is closed off, its search results won't line up with this one's.
+pub async fn start_run(ctx: &mut RunContext, resume: bool) -> Result<Option<RunProgress>> {
+    let search_params = search_params(&ctx.config.search)?;
+
+    if let Some(run) = get_unfinished_run(&ctx.pool, ctx.provider.name()).await? {
+        if resume && run.search_params == search_params {
+            info!(
+                "Resuming run {} (started {}) after {} pages / {} listings.",
+                run.id, run.started_at, run.pages_done, run.jobs_done
+            );
+            ctx.run_id = Some(run.id);
+            return Ok(Some(run));
+        }
+
+        if resume {
+            info!("Run {} searched with other settings, starting over.", run.id);
+        } else {
+            info!("Not resuming run {}, starting over.", run.id);
+        }
+        update_run_finished(&ctx.pool, run.id).await?;
+    }
+
+    ctx.run_id = Some(create_run_progress(&ctx.pool, ctx.provider.name(), &search_params).await?);
+    Ok(None)
+}
+
+/// Marks the run as finished once it has worked through the whole search,
+/// otherwise it's left to be resumed by the next run.
+pub async fn finish_run(ctx: &RunContext, completed: bool) -> Result<()> {
+    let Some(run_id) = ctx.run_id else {
+        return Ok(());
+    };
+
+    if completed {
+        update_run_finished(&ctx.pool, run_id).await
+    } else {
+        info!("Run {} stopped early, the next run will carry on from where it got to.", run_id);
+        Ok(())
+    }
+}
+
+/// Picks an interrupted run back up, the search results (first page) must already be showing.
+///
+/// 1. Jobs left `in_progress` by the interruption go back to pending
+/// 2. The pending jobs are re-validated, and unless scanning applied to (see `apply_pending`)
+/// 3. The results pages the run had already worked through are skipped
+///
+/// Returns the listing search to carry on with.
+pub async fn resume_run(ctx: &mut RunContext, run: &RunProgress) -> Result<Box<JobListingSearch>> {
+    requeue_interrupted_jobs(ctx).await?;
+
+    let results_url = ctx
+        .client
+        .current_url()
+        .await
+        .map_err(|e| Error::Generic(format!("Failed to read the search results URL: {}", e)))?;
+
+    apply_pending(ctx, None).await?;
+
+    ctx.client
+        .goto(results_url.as_str())
+        .await
+        .map_err(|e| Error::Generic(format!("Failed to go back to the search results: {}", e)))?;
+    wait_millis(1500).await;
+
+    let mut pages_done = 0;
+    while pages_done < run.pages_done {
+        scroll_job_list(&ctx.client, Arc::clone(&ctx.provider)).await?;
+        if !next_results_page(&ctx.client, Arc::clone(&ctx.provider)).await? {
+            warn!(
+                "Only {} of the {} pages run {} got through are left, carrying on from the last one.",
+                pages_done + 1,
+                run.pages_done,
+                run.id
+            );
+            break;
+        }
+        pages_done += 1;
+    }
+    debug!("Skipped {} pages of search results.", pages_done);
+
+    Ok(Box::new(JobListingSearch {
+        pages_read: u32::try_from(pages_done).unwrap_or(u32::MAX),
+        jobs_read: usize::try_from(run.jobs_done).unwrap_or(0),
+        last_handled: run.last_job_id,
+        ..JobListingSearch::default()
+    }))
+}
+
+/// Puts the provider's jobs an interrupted application left `in_progress` back to pending.
+async fn requeue_interrupted_jobs(ctx: &RunContext) -> Result<()> {
+    let query = JobQuery {
+        provider: Some(ctx.provider.name()),
+        ..JobQuery::with_status(StatusType::InProgress)
+    };
+
+    for job in find_jobs(&ctx.pool, &query, Page::ALL).await? {
+        info!("Job {} was interrupted mid application, it's pending again.", job.id);
+        update_job_status(&ctx.pool, job.id, StatusType::Pending, Some("interrupted run")).await?;
+    }
+    Ok(())
+}
+
+/// The search settings that decide which results (and pages) a run goes through,
+/// a run can only be resumed with the same ones.
+fn search_params(search: &SearchConfig) -> Result<String> {
+    serde_json::to_string(&serde_json::json!({
+        "use_keywords": search.use_keywords,
+        "keywords": search.keywords,
+        "location": search.location,
+        "job_board_url": search.job_board_url,
+        "remote_only": search.remote_only,
+        "posted_last_day": search.posted_last_day,
+    }))
+    .map_err(|e| Error::Generic(format!("Failed to serialize the search settings: {}", e)))
+}
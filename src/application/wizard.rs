@@ -0,0 +1,528 @@
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use fantoccini::Client;
+use fantoccini::wd::WindowHandle;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use crate::application::autofill::{AutofillAnswer, AutofillEngine, apply_answer};
+use crate::application::fields::{FormField, detect_fields};
+use crate::config::{AppConfig, ApplicationConfig};
+use crate::db::{
+    StatusType,
+    create_dry_run_submission,
+    update_job_failure_reason,
+    update_job_status,
+};
+use crate::pre_fsm::{ScannedJob, wait_millis};
+use crate::prelude::*;
+use crate::providers::{Provider, SelectorKind};
+use crate::selectors::{ApplicationSelectors, FieldMatch, SelectorField};
+use crate::states::{ActionState, ApplicationElements, PausedState, read_line};
+
+/// How an application attempt ended.
+#[derive(Debug, Clone)]
+pub enum ApplicationOutcome {
+    /// The application was submitted and the provider confirmed it.
+    Submitted,
+
+    /// Stopped on a required question we have no answer for.
+    /// The form is left open on that page so the application can be resumed.
+    NeedsAnswer(FormField),
+
+    /// Something went wrong, the form has been discarded.
+    Failed(String),
+
+    /// `ApplicationConfig::dry_run`: the form was filled up to the Submit button,
+    /// which was left alone. Holds what would have been submitted.
+    DryRun(DryRunSubmission),
+}
+
+/// What a dry run would have submitted, recorded in `db.dry_run_submission`.
+#[derive(Debug, Clone, Default)]
+pub struct DryRunSubmission {
+    pub answers: Vec<SubmittedAnswer>,
+    /// The resume that was uploaded, `None` when the provider's pre-selected one was kept
+    /// (or the form never asked for one).
+    pub resume:  Option<PathBuf>,
+}
+
+/// A form field as it stood when the application would have been submitted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmittedAnswer {
+    pub label: String,
+    pub kind:  String,
+    pub value: Option<String>,
+}
+
+/// Where the application form was opened, so we can get back to the search results afterwards.
+#[derive(Debug, Clone)]
+enum FormLocation {
+    /// A modal over the search results (LinkedIn)
+    Modal,
+    /// A new tab/window, closed once we're done with it
+    NewWindow { results: WindowHandle },
+    /// The results page itself navigated to the form (Seek)
+    Navigated { results: String },
+}
+
+/// Drives the multi-step Easy Apply form for a single job at a time:
+/// opens the form, fills each page, and presses Next/Review/Submit until done.
+#[derive(Debug, Clone)]
+pub struct ApplicationWizard {
+    pub client:             Client,
+    pub provider:           Arc<dyn Provider + Send + Sync>,
+    pub pool:               SqlitePool,
+    pub selector_kind:      SelectorKind,
+    pub config:             ApplicationConfig,
+    pub unfollow_companies: bool,
+    pub autofill:           AutofillEngine,
+    /// Every field filled so far, only kept track of for dry runs.
+    answers:                Arc<Mutex<Vec<SubmittedAnswer>>>,
+    /// The resume uploaded so far, only kept track of for dry runs.
+    uploaded_resume:        Arc<Mutex<Option<PathBuf>>>,
+}
+
+impl ApplicationWizard {
+    pub fn new(
+        client: Client,
+        provider: Arc<dyn Provider + Send + Sync>,
+        pool: SqlitePool,
+        app_config: &AppConfig,
+        selector_kind: SelectorKind,
+    ) -> Self {
+        Self {
+            client,
+            provider,
+            autofill: AutofillEngine::new(pool.clone()),
+            pool,
+            selector_kind,
+            config: app_config.application.clone(),
+            unfollow_companies: app_config.general.unfollow_companies,
+            answers: Arc::default(),
+            uploaded_resume: Arc::default(),
+        }
+    }
+
+    fn selectors(&self) -> ApplicationSelectors {
+        self.provider.get_application_selectors(self.selector_kind)
+    }
+
+    /// Applies to the job (which must already be open in the details pane, see `JobValidation`)
+    /// and records the outcome against the job row.
+    pub async fn apply(&self, job: &ScannedJob) -> Result<ApplicationOutcome> {
+        self.apply_job(job.job_id, &job.card.job_title, &job.card.company_name)
+            .await
+    }
+
+    /// `apply` for a stored job rather than a card on the results page,
+    /// the job still has to be open (ie: on its own page) already.
+    pub async fn apply_job(
+        &self,
+        job_id: i64,
+        job_title: &str,
+        company_name: &str,
+    ) -> Result<ApplicationOutcome> {
+        info!("Applying to job {} ({} @ {})", job_id, job_title, company_name);
+        update_job_status(&self.pool, job_id, StatusType::InProgress, None).await?;
+
+        let location = match self.open().await {
+            Ok(location) => location,
+            Err(e) => {
+                let outcome =
+                    ApplicationOutcome::Failed(format!("Failed to open application form: {}", e));
+                self.record(job_id, &outcome).await?;
+                return Ok(outcome);
+            }
+        };
+
+        // The form is closed whatever happens, or it'd be in the way of the next job
+        let outcome = self.fill_in().await;
+        let recorded = match &outcome {
+            Ok(outcome) => self.record(job_id, outcome).await,
+            Err(_) => {
+                self.discard().await;
+                Ok(())
+            }
+        };
+        self.back_to_results(location).await;
+
+        recorded?;
+        outcome
+    }
+
+    /// Walks the open form, asking the user for anything we couldn't answer
+    /// and carrying on from the same page.
+    async fn fill_in(&self) -> Result<ApplicationOutcome> {
+        let mut outcome = self.walk().await?;
+
+        while let ApplicationOutcome::NeedsAnswer(field) = &outcome
+            && self.config.pause_on_unanswered
+        {
+            if !self.ask_user(field).await? {
+                break;
+            }
+            outcome = self.walk().await?;
+        }
+
+        Ok(outcome)
+    }
+
+    /// Records the outcome of an application attempt against the job row,
+    /// closing or discarding the form where needed.
+    pub async fn record(&self, job_id: i64, outcome: &ApplicationOutcome) -> Result<()> {
+        match outcome {
+            ApplicationOutcome::Submitted => {
+                info!("Application submitted for job {}.", job_id);
+                self.dismiss().await;
+                update_job_failure_reason(&self.pool, job_id, None).await?;
+                update_job_status(&self.pool, job_id, StatusType::Submitted, None).await
+            }
+            ApplicationOutcome::NeedsAnswer(field) => {
+                warn!(
+                    "Application for job {} needs an answer for: '{}' ({:?})",
+                    job_id, field.label, field.kind
+                );
+                self.discard().await;
+                let reason = format!("No answer for '{}'", field.label);
+                update_job_failure_reason(&self.pool, job_id, Some(&reason)).await?;
+                update_job_status(&self.pool, job_id, StatusType::NeedsAttention, Some(&reason))
+                    .await
+            }
+            ApplicationOutcome::Failed(reason) => {
+                error!("Application for job {} failed: {}", job_id, reason);
+                self.discard().await;
+                update_job_failure_reason(&self.pool, job_id, Some(reason)).await?;
+                update_job_status(&self.pool, job_id, StatusType::Failed, Some(reason)).await
+            }
+            // Nothing was sent, so the job stays up for a real application later on
+            ApplicationOutcome::DryRun(submission) => {
+                info!(
+                    "Dry run for job {}: would have submitted {} answers.",
+                    job_id,
+                    submission.answers.len()
+                );
+                self.discard().await;
+
+                let answers = serde_json::to_string(&submission.answers).map_err(|e| {
+                    Error::Generic(format!(
+                        "Failed to serialize dry run answers for job {}: {}",
+                        job_id, e
+                    ))
+                })?;
+                let resume = submission
+                    .resume
+                    .as_ref()
+                    .map(|path| path.to_string_lossy().to_string());
+                create_dry_run_submission(&self.pool, job_id, &answers, resume.as_deref()).await?;
+                update_job_status(&self.pool, job_id, StatusType::Pending, Some("dry run")).await
+            }
+        }
+    }
+
+    /// Pauses and asks the user to answer the field, optionally saving the answer for next time.
+    ///
+    /// Returns `false` when the user skipped the question (the job is then failed as usual).
+    async fn ask_user(&self, field: &FormField) -> Result<bool> {
+        let paused = PausedState::for_question(field, self.config.pause_timeout_secs);
+
+        loop {
+            let Some(input) = paused.wait_for_user().await? else {
+                info!("Question '{}' skipped by user.", field.label);
+                return Ok(false);
+            };
+
+            let Some(answer) = AutofillAnswer::from_user_input(field, &input) else {
+                println!("'{}' isn't a valid answer for this question, try again.", input);
+                continue;
+            };
+
+            if !apply_answer(field, &answer).await? {
+                println!("Couldn't apply '{}' to the form, try again.", input);
+                continue;
+            }
+
+            let save = read_line("Save this answer for future applications? [Y/n]: ").await?;
+            if !matches!(save.to_lowercase().as_str(), "n" | "no") {
+                self.autofill.save_answer(&field.label, &answer).await?;
+                info!("Saved answer for '{}'.", field.label);
+            }
+
+            return Ok(true);
+        }
+    }
+
+    /// Clicks the Easy Apply button and waits for the form to show up,
+    /// following it into a new window if that's where it opened.
+    async fn open(&self) -> Result<FormLocation> {
+        let apply_button = self
+            .provider
+            .find_field(&self.client, SelectorField::job_listing("easy_apply_button"))
+            .await?
+            .into_inner();
+
+        let windows_before = self.client.windows().await.map_err(window_error)?;
+        let results_url = self.client.current_url().await.map_err(window_error)?;
+
+        self.provider
+            .with_action(ActionState::Click {
+                element: apply_button,
+            })
+            .await?;
+        wait_millis(1000).await;
+
+        let windows = self.client.windows().await.map_err(window_error)?;
+        let location = match windows.into_iter().find(|w| !windows_before.contains(w)) {
+            Some(form_window) => {
+                let results = self.client.window().await.map_err(window_error)?;
+                self.client
+                    .switch_to_window(form_window)
+                    .await
+                    .map_err(window_error)?;
+                FormLocation::NewWindow { results }
+            }
+            None if self.client.current_url().await.map_err(window_error)? != results_url => {
+                FormLocation::Navigated {
+                    results: results_url.to_string(),
+                }
+            }
+            None => FormLocation::Modal,
+        };
+        debug!("Application form opened in: {:?}", location);
+
+        self.provider
+            .find_field(&self.client, SelectorField::application("modal"))
+            .await?;
+        wait_millis(500).await;
+        Ok(location)
+    }
+
+    /// Returns to the search results once the application is over.
+    async fn back_to_results(&self, location: FormLocation) {
+        let result = match location {
+            FormLocation::Modal => Ok(()),
+            FormLocation::NewWindow { results } => {
+                match self.client.close_window().await {
+                    Ok(()) => self.client.switch_to_window(results).await,
+                    Err(e) => Err(e),
+                }
+            }
+            FormLocation::Navigated { results } => self.client.goto(&results).await,
+        };
+
+        match result {
+            Ok(()) => wait_millis(1000).await,
+            Err(e) => error!("Failed to return to the search results: {}", e),
+        }
+    }
+
+    /// Walks the form from whichever page is currently showing,
+    /// so it can also be used to resume a paused application.
+    pub async fn walk(&self) -> Result<ApplicationOutcome> {
+        for page in 1..=self.config.max_form_pages {
+            debug!("Filling application form page {}", page);
+
+            if let Some(field) = self.fill_page().await? {
+                return Ok(ApplicationOutcome::NeedsAnswer(field));
+            }
+
+            self.follow_company().await;
+
+            if let Some(submit) = self.optional("submit_button").await? {
+                if self.config.dry_run {
+                    debug!("Dry run, leaving the Submit button alone.");
+                    return Ok(ApplicationOutcome::DryRun(self.dry_run_submission()));
+                }
+
+                self.click(submit).await?;
+                wait_millis(1500).await;
+                return self.confirm_submitted().await;
+            }
+
+            let Some(button) = self.next_or_review().await? else {
+                return Ok(ApplicationOutcome::Failed(format!(
+                    "No Next/Review/Submit button found on page {}",
+                    page
+                )));
+            };
+            self.click(button).await?;
+            wait_millis(750).await;
+
+            if let Some(error) = self.optional("field_error").await? {
+                let message = error.text().await.unwrap_or_default();
+                return Ok(ApplicationOutcome::Failed(format!(
+                    "Form rejected page {}: {}",
+                    page,
+                    message.trim()
+                )));
+            }
+        }
+
+        Ok(ApplicationOutcome::Failed(format!(
+            "Form had more than {} pages",
+            self.config.max_form_pages
+        )))
+    }
+
+    /// Fills every field on the current page that needs it.
+    ///
+    /// Returns the first required field we couldn't answer, if any.
+    async fn fill_page(&self) -> Result<Option<FormField>> {
+        let fields = detect_fields(&self.client, Arc::clone(&self.provider)).await?;
+
+        for field in fields {
+            if field.kind == ApplicationElements::ResumeUpload {
+                self.upload_resume(&field).await?;
+                continue;
+            }
+
+            if field.is_filled().await || self.autofill.fill(&field).await? || !field.required {
+                if self.config.dry_run {
+                    self.keep_answer(&field).await;
+                }
+                continue;
+            }
+
+            return Ok(Some(field));
+        }
+
+        Ok(None)
+    }
+
+    /// Keeps track of the field's value for the dry run record.
+    /// A page walked again (after pausing for an answer) replaces what it had before.
+    async fn keep_answer(&self, field: &FormField) {
+        let answer = SubmittedAnswer {
+            label: field.label.clone(),
+            kind:  <&str>::from(field.kind).to_string(),
+            value: field.value().await,
+        };
+
+        let mut answers = self.answers.lock().unwrap_or_else(|e| e.into_inner());
+        match answers.iter_mut().find(|kept| kept.label == answer.label) {
+            Some(kept) => *kept = answer,
+            None => answers.push(answer),
+        }
+    }
+
+    fn dry_run_submission(&self) -> DryRunSubmission {
+        DryRunSubmission {
+            answers: self
+                .answers
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone(),
+            resume:  self
+                .uploaded_resume
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .clone(),
+        }
+    }
+
+    async fn upload_resume(&self, field: &FormField) -> Result<()> {
+        let (Some(path), Some(input)) = (&self.config.resume_path, field.inputs.first()) else {
+            debug!("No resume configured, keeping the provider's pre-selected resume.");
+            return Ok(());
+        };
+
+        let path = path.canonicalize().map_err(|e| {
+            Error::Generic(format!("Resume not found at {}: {}", path.display(), e))
+        })?;
+
+        info!("Uploading resume: {}", path.display());
+        self.provider
+            .with_action(ActionState::InputText {
+                element: input,
+                input:   path.to_string_lossy().to_string(),
+            })
+            .await?;
+
+        *self
+            .uploaded_resume
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(path);
+        Ok(())
+    }
+
+    /// Un-ticks the "follow company" box when `unfollow_companies` is set.
+    async fn follow_company(&self) {
+        if !self.unfollow_companies {
+            return;
+        }
+
+        let tickbox = self
+            .provider
+            .find_all_field(&self.client, SelectorField::application("follow_company_tickbox"))
+            .await
+            .map(FieldMatch::into_inner)
+            .unwrap_or_default();
+
+        if let Some(tickbox) = tickbox.into_iter().next()
+            && tickbox.is_selected().await.unwrap_or(false)
+        {
+            debug!("Un-ticking follow company tickbox.");
+            if let Err(e) = self.click(tickbox).await {
+                warn!("Failed to un-tick follow company tickbox: {}", e);
+            }
+        }
+    }
+
+    async fn next_or_review(&self) -> Result<Option<fantoccini::elements::Element>> {
+        if let Some(review) = self.optional("review_button").await? {
+            return Ok(Some(review));
+        }
+        self.optional("next_button").await
+    }
+
+    async fn confirm_submitted(&self) -> Result<ApplicationOutcome> {
+        let confirmed = self.optional("submitted_indicator").await?.is_some()
+            || self.optional("modal").await?.is_none();
+
+        if confirmed {
+            Ok(ApplicationOutcome::Submitted)
+        } else {
+            Ok(ApplicationOutcome::Failed(
+                "Submit was pressed but the provider didn't confirm the application".to_string(),
+            ))
+        }
+    }
+
+    /// Closes the confirmation dialog after a successful submit.
+    async fn dismiss(&self) {
+        if let Ok(Some(dismiss)) = self.optional("dismiss_button").await {
+            let _ = self.click(dismiss).await;
+        }
+    }
+
+    /// Closes the form without submitting, discarding anything entered.
+    async fn discard(&self) {
+        if let Ok(Some(dismiss)) = self.optional("dismiss_button").await {
+            let _ = self.click(dismiss).await;
+            wait_millis(300).await;
+            if let Ok(Some(discard)) = self.optional("discard_button").await {
+                let _ = self.click(discard).await;
+            }
+        }
+    }
+
+    /// Looks up an optional `ApplicationSelectors` field, through its fallback chain.
+    async fn optional(&self, field: &'static str) -> Result<Option<fantoccini::elements::Element>> {
+        Ok(self
+            .provider
+            .find_optional_field(&self.client, SelectorField::application(field))
+            .await?
+            .map(FieldMatch::into_inner))
+    }
+
+    async fn click(&self, element: fantoccini::elements::Element) -> Result<()> {
+        self.provider
+            .with_action(ActionState::Click { element })
+            .await
+    }
+}
+
+fn window_error(e: fantoccini::error::CmdError) -> Error {
+    Error::Generic(format!("Failed to track the application window: {}", e))
+}
@@ -0,0 +1,83 @@
+use sqlx::SqlitePool;
+
+use crate::application::{AutofillAnswer, AutofillEngine, input_key};
+use crate::cli::{AnswerKind, AutofillCommand};
+use crate::db::{delete_autofill, get_all_autofill, get_unanswered_questions};
+use crate::prelude::*;
+
+pub async fn autofill_command(pool: &SqlitePool, command: AutofillCommand) -> Result<()> {
+    match command {
+        AutofillCommand::List { unanswered: false } => {
+            let entries = get_all_autofill(pool).await?;
+            if entries.is_empty() {
+                println!("No autofill answers stored.");
+            }
+            for entry in entries {
+                match serde_json::from_str::<AutofillAnswer>(&entry.data) {
+                    Ok(answer) => println!("{}: {}", entry.input_key, answer.as_input()),
+                    Err(_) => println!("{}: {} (malformed)", entry.input_key, entry.data),
+                }
+            }
+            Ok(())
+        }
+        AutofillCommand::List { unanswered: true } => {
+            let questions = get_unanswered_questions(pool).await?;
+            if questions.is_empty() {
+                println!("No unanswered questions.");
+            }
+            for question in questions {
+                println!(
+                    "{} [{}] seen {} time(s): {}",
+                    question.input_key, question.field_kind, question.times_seen, question.label
+                );
+                if question.options != "[]" {
+                    println!("    options: {}", question.options);
+                }
+            }
+            Ok(())
+        }
+        AutofillCommand::Set {
+            label,
+            answer,
+            kind,
+        } => {
+            let answer = parse_answer(kind, &answer)?;
+            AutofillEngine::new(pool.clone())
+                .save_answer(&label, &answer)
+                .await?;
+            println!("Saved {}: {}", input_key(&label), answer.as_input());
+            Ok(())
+        }
+        AutofillCommand::Delete { label } => {
+            let key = input_key(&label);
+            if delete_autofill(pool, &key).await? {
+                println!("Deleted {}.", key);
+                Ok(())
+            } else {
+                Err(Error::Generic(format!("No autofill answer stored for '{}'", key)))
+            }
+        }
+    }
+}
+
+fn parse_answer(kind: AnswerKind, value: &str) -> Result<AutofillAnswer> {
+    let value = value.trim();
+
+    match kind {
+        AnswerKind::Text => Ok(AutofillAnswer::Text(value.to_string())),
+        AnswerKind::Choice => Ok(AutofillAnswer::Choice(value.to_string())),
+        AnswerKind::Number => {
+            value
+                .parse()
+                .map(AutofillAnswer::Number)
+                .map_err(|_| Error::Generic(format!("'{}' isn't a number", value)))
+        }
+        AnswerKind::Boolean => {
+            match value.to_lowercase().as_str() {
+                "y" | "yes" | "true" => Ok(AutofillAnswer::Boolean(true)),
+                "n" | "no" | "false" => Ok(AutofillAnswer::Boolean(false)),
+                _ => Err(Error::Generic(format!("'{}' isn't yes or no", value))),
+            }
+        }
+    }
+}
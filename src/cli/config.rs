@@ -0,0 +1,48 @@
+use crate::cli::{ConfigCommand, ConfigOverrides, provider_for};
+use crate::config::AppConfig;
+use crate::filters::FilterRules;
+use crate::prelude::*;
+use crate::providers::ProviderKind;
+use crate::selectors::{SelectorPack, selector_pack_path};
+
+const MASKED: &str = "********";
+
+/// Handled before the config is loaded as usual, so a broken file is reported
+/// rather than replaced with the defaults.
+pub async fn config_command(command: ConfigCommand, overrides: &ConfigOverrides) -> Result<()> {
+    let path = overrides.config_path();
+
+    match command {
+        ConfigCommand::Init { force } => {
+            AppConfig::write_default(&path, force).await?;
+            println!("Wrote the default config to {}", path.display());
+            Ok(())
+        }
+        ConfigCommand::Validate => {
+            let mut config = AppConfig::from_file(&path)?;
+            overrides.apply(&mut config);
+            provider_for(&config)?;
+            FilterRules::new(&config.filters)?;
+            config.quotas.validate()?;
+
+            for provider in [ProviderKind::LinkedIn, ProviderKind::Seek] {
+                if let Some(pack_path) = selector_pack_path(&config.selectors_dir(), provider) {
+                    SelectorPack::from_file(&pack_path, provider)?;
+                    println!("Selector pack {} is valid.", pack_path.display());
+                }
+            }
+
+            println!("Config {} is valid.", path.display());
+            Ok(())
+        }
+        ConfigCommand::Show => {
+            let mut config = AppConfig::from_file(&path)?;
+            overrides.apply(&mut config);
+            config.login.password = MASKED.to_string();
+
+            println!("# {}", path.display());
+            println!("{}", config.to_toml()?);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use chrono::{Duration, Local, Utc};
+use sqlx::SqlitePool;
+
+use crate::application::SubmittedAnswer;
+use crate::cli::{JobFilterArgs, JobStatusArg, JobsCommand};
+use crate::db::{
+    JobDescriptionRecord,
+    JobOrder,
+    JobQuery,
+    JobRecord,
+    Page,
+    StatusType,
+    count_jobs,
+    count_jobs_by_status,
+    delete_job_by_id,
+    delete_jobs,
+    find_jobs,
+    get_dry_run_submissions,
+    get_job_by_id,
+    get_job_description,
+    get_job_rejections,
+    get_job_status_history,
+    search_job_descriptions,
+};
+use crate::prelude::*;
+use crate::providers::{ProviderKind, get_provider};
+
+impl From<JobStatusArg> for StatusType {
+    fn from(status: JobStatusArg) -> Self {
+        match status {
+            JobStatusArg::Pending => StatusType::Pending,
+            JobStatusArg::InProgress => StatusType::InProgress,
+            JobStatusArg::Completed => StatusType::Completed,
+            JobStatusArg::Failed => StatusType::Failed,
+            JobStatusArg::Skipped => StatusType::Skipped,
+            JobStatusArg::Filtered => StatusType::Filtered,
+            JobStatusArg::NeedsAttention => StatusType::NeedsAttention,
+            JobStatusArg::Submitted => StatusType::Submitted,
+        }
+    }
+}
+
+impl JobFilterArgs {
+    /// The filters as a `JobQuery`, limited to `provider`'s jobs when given.
+    fn to_query(&self, provider: Option<ProviderKind>) -> JobQuery {
+        JobQuery {
+            status: self.status.map(StatusType::from),
+            provider,
+            company: self.company.clone(),
+            posted_after: self
+                .posted_within
+                .map(|days| Utc::now() - Duration::days(i64::from(days))),
+            ..JobQuery::default()
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.status.is_none() && self.company.is_none() && self.posted_within.is_none()
+    }
+}
+
+/// `provider` is the global `--provider`, when given only that provider's jobs are listed/deleted.
+pub async fn jobs_command(
+    pool: &SqlitePool,
+    command: JobsCommand,
+    provider: Option<ProviderKind>,
+) -> Result<()> {
+    match command {
+        JobsCommand::List {
+            filter,
+            by_posted,
+            limit,
+            page,
+        } => {
+            let mut query = filter.to_query(provider);
+            if by_posted {
+                query.order = JobOrder::Posted;
+            }
+
+            let total = count_jobs(pool, &query).await?;
+            let page = Page {
+                limit,
+                offset: (page - 1) * limit,
+            };
+            let jobs = find_jobs(pool, &query, page).await?;
+            if jobs.is_empty() {
+                match total {
+                    0 => println!("No jobs stored."),
+                    _ => println!("No jobs on this page, there are {} in total.", total),
+                }
+                return Ok(());
+            }
+
+            print_job_table(&jobs);
+            println!(
+                "Showing {}-{} of {} job(s).",
+                page.offset + 1,
+                page.offset + jobs.len() as i64,
+                total
+            );
+            Ok(())
+        }
+        JobsCommand::Stats => {
+            let counts = count_jobs_by_status(pool).await?;
+            for count in &counts {
+                println!("{:<16} {:>6}", count.status, count.count);
+            }
+            println!("{:<16} {:>6}", "total", counts.iter().map(|c| c.count).sum::<i64>());
+            Ok(())
+        }
+        JobsCommand::Delete { ids, filter, all } => {
+            if !ids.is_empty() {
+                for id in ids {
+                    if delete_job_by_id(pool, id).await? {
+                        println!("Deleted job {}.", id);
+                    } else {
+                        println!("No job with id {}.", id);
+                    }
+                }
+                return Ok(());
+            }
+
+            if filter.is_empty() && provider.is_none() && !all {
+                return Err(Error::Generic(
+                    "Give the ids of the jobs to delete, some filters, or --all".to_string(),
+                ));
+            }
+
+            let deleted = delete_jobs(pool, &filter.to_query(provider)).await?;
+            println!("Deleted {} job(s).", deleted);
+            Ok(())
+        }
+        JobsCommand::Search { text, limit } => {
+            let jobs = search_job_descriptions(pool, &text, limit).await?;
+            if jobs.is_empty() {
+                println!("No stored job descriptions mention '{}'.", text);
+                return Ok(());
+            }
+
+            print_job_table(&jobs);
+            println!("{} job(s).", jobs.len());
+            Ok(())
+        }
+        JobsCommand::Show { id, full } => {
+            let job = get_job_by_id(pool, id)
+                .await?
+                .ok_or_else(|| Error::Generic(format!("No job with id {}", id)))?;
+            print_job(&job);
+
+            if let Some(description) = get_job_description(pool, id).await? {
+                print_description(&description, full);
+            }
+
+            let history = get_job_status_history(pool, id).await?;
+            if !history.is_empty() {
+                println!("Status history:");
+                for change in history {
+                    println!(
+                        "  {}  {} -> {}{}",
+                        change
+                            .changed_at
+                            .with_timezone(&Local)
+                            .format("%Y-%m-%d %H:%M %Z"),
+                        change.from_status.as_deref().unwrap_or("-"),
+                        change.to_status,
+                        change
+                            .reason
+                            .map(|reason| format!(" ({})", reason))
+                            .unwrap_or_default()
+                    );
+                }
+            }
+
+            let rejections = get_job_rejections(pool, id).await?;
+            if !rejections.is_empty() {
+                println!("Rejected by filters:");
+                for rejection in rejections {
+                    println!("  {}: {}", rejection.rule, rejection.detail);
+                }
+            }
+
+            if let Some(dry_run) = get_dry_run_submissions(pool, id).await?.first() {
+                println!("Last dry run ({}):", dry_run.created_at);
+                println!(
+                    "  resume:      {}",
+                    dry_run.resume_path.as_deref().unwrap_or("provider default")
+                );
+                match serde_json::from_str::<Vec<SubmittedAnswer>>(&dry_run.answers) {
+                    Ok(answers) => {
+                        for answer in answers {
+                            println!(
+                                "  {} [{}]: {}",
+                                answer.label,
+                                answer.kind,
+                                answer.value.as_deref().unwrap_or("-")
+                            );
+                        }
+                    }
+                    Err(_) => println!("  answers:     {}", dry_run.answers),
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+fn print_job_table(jobs: &[JobRecord]) {
+    println!(
+        "{:>6}  {:<9} {:<15} {:<10} {:<40} {:<30} skip reason",
+        "id", "provider", "status", "posted", "title", "company"
+    );
+    for job in jobs {
+        println!(
+            "{:>6}  {:<9} {:<15} {:<10} {:<40} {:<30} {}",
+            job.id,
+            job.provider,
+            job.status,
+            job.posted_at
+                .map(|posted| posted.with_timezone(&Local).format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            truncate(&job.job_title, 40),
+            truncate(&job.company_name, 30),
+            job.skip_reason.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+fn print_job(job: &JobRecord) {
+    println!("Job {}", job.id);
+    println!("  title:       {}", job.job_title);
+    println!("  company:     {}", job.company_name);
+    println!("  location:    {}, {} ({})", job.state, job.country, job.work_type);
+    println!("  provider:    {} ({})", job.provider, job.provider_job_id);
+    println!("  easy apply:  {}", job.has_easy_apply);
+    match job.posted_at {
+        Some(posted) => {
+            println!(
+                "  posted:      {} ({})",
+                posted.with_timezone(&Local).format("%Y-%m-%d %H:%M %Z"),
+                describe_posting(&job.full_date, &job.relative_date)
+            )
+        }
+        None => println!("  posted:      {}", describe_posting(&job.full_date, &job.relative_date)),
+    }
+    if let Some(scraped) = job.scraped_at {
+        println!("  scraped:     {}", scraped.with_timezone(&Local).format("%Y-%m-%d %H:%M %Z"));
+    }
+    println!("  status:      {}", job.status);
+    println!("  skip reason: {}", job.skip_reason.as_deref().unwrap_or("-"));
+    println!("  failure:     {}", job.failure_reason.as_deref().unwrap_or("-"));
+    println!("  applied:     {}", job.applied_date.as_deref().unwrap_or("-"));
+    println!("  salary:      {}", job.salary.as_deref().unwrap_or("-"));
+    println!("  employment:  {}", job.employment_type.as_deref().unwrap_or("-"));
+    if let Some(discovered) = job.discovered_at {
+        println!("  discovered:  {}", discovered.with_timezone(&Local).format("%Y-%m-%d %H:%M %Z"));
+    }
+    if let Some(updated) = job.updated_at {
+        println!("  updated:     {}", updated.with_timezone(&Local).format("%Y-%m-%d %H:%M %Z"));
+    }
+
+    if let Some(url) = &job.job_url {
+        println!("  url:         {}", url);
+    } else if job.has_provider_id()
+        && let Ok(kind) = job.provider.parse::<ProviderKind>()
+        && let Some(provider) = get_provider(kind)
+    {
+        println!("  url:         {}", provider.job_url(&job.provider_job_id));
+    }
+}
+
+/// How much of the description `jobs show` prints without `--full`.
+const DESCRIPTION_PREVIEW_CHARS: usize = 600;
+
+fn print_description(description: &JobDescriptionRecord, full: bool) {
+    println!("  seniority:   {}", description.seniority_level.as_deref().unwrap_or("-"));
+    println!("Description (scraped {}):", description.scraped_at);
+
+    let text = if full {
+        description.description_text.clone()
+    } else {
+        truncate(&description.description_text, DESCRIPTION_PREVIEW_CHARS)
+    };
+    for line in text.lines().filter(|line| !line.trim().is_empty()) {
+        println!("  {}", line.trim());
+    }
+}
+
+/// The posting date text as it was on the card, ie: `January 19, 2026, 10:43 AM, 1 week ago`.
+fn describe_posting(full_date: &str, relative_date: &str) -> String {
+    match (full_date.is_empty(), relative_date.is_empty()) {
+        (true, true) => "-".to_string(),
+        (false, true) => full_date.to_string(),
+        (true, false) => relative_date.to_string(),
+        (false, false) => format!("{}, {}", full_date, relative_date),
+    }
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let mut truncated: String = s.chars().take(max - 1).collect();
+        truncated.push('…');
+        truncated
+    }
+}
@@ -0,0 +1,262 @@
+mod autofill;
+mod config;
+mod jobs;
+mod run;
+
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+pub use crate::cli::autofill::autofill_command;
+pub use crate::cli::config::config_command;
+pub use crate::cli::jobs::jobs_command;
+pub use crate::cli::run::{RunMode, check_selectors_command, provider_for, run_command};
+use crate::config::{AppConfig, LoggingLevel};
+use crate::providers::ProviderKind;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "job_apply",
+    version,
+    about = "Automates job applications on LinkedIn and Seek"
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub overrides: ConfigOverrides,
+
+    /// Defaults to `run` when omitted.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Flags taking precedence over the values in the config file.
+#[derive(Debug, Clone, Default, Args)]
+pub struct ConfigOverrides {
+    /// Config file to use instead of `config/config.toml`.
+    #[arg(short, long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Job provider to use: linkedin | seek.
+    #[arg(short, long, global = true, value_parser = parse_provider)]
+    pub provider: Option<ProviderKind>,
+
+    /// trace | debug | info | warn | error
+    #[arg(short, long, global = true, value_name = "LEVEL")]
+    pub log_level: Option<LoggingLevel>,
+
+    /// Walk through applications without submitting them.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Stop the state machine after this many iterations (states executed, not jobs).
+    #[arg(long, global = true, value_name = "N")]
+    pub max_iterations: Option<usize>,
+}
+
+impl ConfigOverrides {
+    /// The config file the flags point at, or the default one.
+    pub fn config_path(&self) -> PathBuf {
+        self.config.clone().unwrap_or_else(AppConfig::default_path)
+    }
+
+    pub fn apply(&self, config: &mut AppConfig) {
+        if let Some(provider) = self.provider {
+            config.login.provider = <&str>::from(provider).to_string();
+        }
+        if let Some(log_level) = self.log_level {
+            config.logging.log_level = log_level;
+        }
+        if self.dry_run {
+            config.application.dry_run = true;
+        }
+        if let Some(max_iterations) = self.max_iterations {
+            config.general.maximum_iterations = Some(max_iterations);
+        }
+    }
+}
+
+fn parse_provider(s: &str) -> std::result::Result<ProviderKind, String> {
+    s.parse()
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum Command {
+    /// Log in, search, and apply to every valid job found.
+    ///
+    /// Carries on from where an interrupted run or scan stopped.
+    Run {
+        /// Start over from the first page instead of resuming an interrupted run.
+        #[arg(long)]
+        fresh: bool,
+    },
+
+    /// Log in and search, storing valid jobs as pending without applying to them.
+    ///
+    /// Carries on from where an interrupted run or scan stopped.
+    Scan {
+        /// Start over from the first page instead of resuming an interrupted scan.
+        #[arg(long)]
+        fresh: bool,
+    },
+
+    /// Log in and apply to the jobs left pending in the database (ie: by `scan`).
+    Apply {
+        /// Apply to at most this many jobs.
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+    },
+
+    /// Inspect the stored jobs.
+    #[command(subcommand)]
+    Jobs(JobsCommand),
+
+    /// Manage the stored answers for application form questions.
+    #[command(subcommand)]
+    Autofill(AutofillCommand),
+
+    /// Create, check, or print the config file.
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// Check the provider's selectors against saved HTML snapshots and/or live pages.
+    CheckSelectors {
+        /// HTML snapshot paths or URLs.
+        #[arg(required = true, value_name = "SNAPSHOT")]
+        targets: Vec<String>,
+    },
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum JobsCommand {
+    /// List stored jobs, newest first. `--provider` limits it to that provider's jobs.
+    List {
+        #[command(flatten)]
+        filter: JobFilterArgs,
+
+        /// Most recently posted first, rather than most recently stored.
+        #[arg(long)]
+        by_posted: bool,
+
+        #[arg(long, default_value_t = 50)]
+        limit: i64,
+
+        /// Which page of `--limit` jobs to show, starting from 1.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(i64).range(1..))]
+        page: i64,
+    },
+
+    /// How many stored jobs there are in each status.
+    Stats,
+
+    /// Delete stored jobs, along with everything recorded against them.
+    Delete {
+        /// Jobs to delete, by id.
+        #[arg(conflicts_with_all = ["status", "company", "posted_within", "all"])]
+        ids: Vec<i64>,
+
+        /// Delete the jobs matching these filters (and `--provider`) instead.
+        #[command(flatten)]
+        filter: JobFilterArgs,
+
+        /// Delete every stored job.
+        #[arg(long)]
+        all: bool,
+    },
+
+    /// Show everything stored for a single job.
+    Show {
+        id: i64,
+
+        /// Print the whole job description rather than the start of it.
+        #[arg(long)]
+        full: bool,
+    },
+
+    /// List jobs whose description mentions TEXT, newest first.
+    Search {
+        text: String,
+
+        #[arg(long, default_value_t = 50)]
+        limit: i64,
+    },
+}
+
+/// Narrows down which stored jobs a `jobs` subcommand works on.
+#[derive(Debug, Clone, Default, Args)]
+pub struct JobFilterArgs {
+    /// Only jobs in this status.
+    #[arg(long, value_enum)]
+    pub status: Option<JobStatusArg>,
+
+    /// Only jobs at companies whose name contains this.
+    #[arg(long)]
+    pub company: Option<String>,
+
+    /// Only jobs posted within the last DAYS days.
+    #[arg(long, value_name = "DAYS")]
+    pub posted_within: Option<u32>,
+}
+
+/// `StatusType`, as accepted on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum JobStatusArg {
+    Pending,
+    InProgress,
+    Completed,
+    Failed,
+    Skipped,
+    Filtered,
+    NeedsAttention,
+    Submitted,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum AutofillCommand {
+    /// List stored answers.
+    List {
+        /// List the questions still waiting on an answer instead.
+        #[arg(long)]
+        unanswered: bool,
+    },
+
+    /// Store (or replace) the answer for a question.
+    Set {
+        /// The question's label as shown on the form, or its stored key.
+        label:  String,
+        answer: String,
+
+        #[arg(long, value_enum, default_value_t = AnswerKind::Text)]
+        kind: AnswerKind,
+    },
+
+    /// Remove the stored answer for a question.
+    Delete {
+        /// The question's label as shown on the form, or its stored key.
+        label: String,
+    },
+}
+
+/// `AutofillAnswer`'s variants, as accepted on the command line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum AnswerKind {
+    Text,
+    Number,
+    Choice,
+    Boolean,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ConfigCommand {
+    /// Write the default config file.
+    Init {
+        /// Overwrite an existing config file.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Check the config file (and any selector pack overrides) can be loaded.
+    Validate,
+
+    /// Print the config in effect, flags applied and password masked.
+    Show,
+}
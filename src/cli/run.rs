@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+
+use crate::application::{apply_pending, finish_run, resume_run, start_run};
+use crate::config::AppConfig;
+use crate::pre_fsm;
+use crate::prelude::*;
+use crate::providers::{Provider, ProviderKind, get_provider};
+use crate::selectors::{self, SnapshotTarget};
+use crate::state::State;
+use crate::states::{NavigateState, RunContext, States, start_state_machine};
+
+/// The browser driven subcommands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunMode {
+    Run,
+    Scan,
+    Apply { limit: Option<usize> },
+}
+
+/// The provider named by `LoginConfig::provider` (or `--provider`).
+pub fn provider_for(config: &AppConfig) -> Result<Arc<dyn Provider + Send + Sync>> {
+    let kind = config
+        .login
+        .provider
+        .parse::<ProviderKind>()
+        .map_err(|e| Error::ConfigError(format!("login.provider: {}", e)))?;
+
+    get_provider(kind).ok_or_else(|| {
+        Error::ConfigError(format!("Unsupported provider: {}", config.login.provider))
+    })
+}
+
+/// Logs in, then either runs the search + apply state machine (`run`, `scan`)
+/// or works through the jobs left pending in the database (`apply`).
+///
+/// `run`/`scan` carry on from where an interrupted one stopped, unless `resume` is off.
+pub async fn run_command(
+    state: &State,
+    pool: SqlitePool,
+    mode: RunMode,
+    resume: bool,
+) -> Result<()> {
+    let provider = provider_for(&state.config)?;
+    info!("Using provider: {}", provider.name());
+
+    let mut ctx = RunContext::new(
+        state.client.clone(),
+        Arc::clone(&provider),
+        state.config.clone(),
+        pool,
+        provider.primary_selector_kind(),
+    )?;
+
+    if let RunMode::Apply { limit } = mode {
+        pre_fsm::login(
+            &state.client,
+            Arc::clone(&provider),
+            &state.config.login,
+            ctx.selector_kind,
+        )
+        .await?;
+        return apply_pending(&mut ctx, limit).await;
+    }
+
+    pre_fsm::run_all_steps(&state.client, Arc::clone(&provider), &state.config, ctx.selector_kind)
+        .await
+        .inspect_err(|e| error!("Pre-FSM process encountered an error: {}", e))?;
+    info!("Pre-FSM steps completed successfully.");
+
+    ctx.scan_only = mode == RunMode::Scan;
+    let search = match start_run(&mut ctx, resume).await? {
+        Some(run) => resume_run(&mut ctx, &run).await?,
+        None => Box::default(),
+    };
+
+    let summary =
+        start_state_machine(&mut ctx, States::Navigate(NavigateState::ToJobSearch(search))).await;
+    finish_run(&ctx, summary.completed()).await?;
+
+    match summary.error {
+        Some(error) => Err(Error::StateMachineError(error)),
+        None => Ok(()),
+    }
+}
+
+/// Evaluates the configured provider's selector pack against saved HTML snapshots
+/// and/or live pages, printing a per-selector report.
+pub async fn check_selectors_command(state: &State, targets: &[String]) -> Result<()> {
+    let provider = provider_for(&state.config)?;
+    let targets: Vec<SnapshotTarget> = targets
+        .iter()
+        .map(|arg| SnapshotTarget::parse(arg))
+        .collect();
+
+    let report = selectors::check_selectors(&state.client, provider, &targets).await?;
+    report.print();
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApplicationConfig {
+    /// Resume to upload when the form asks for one.
+    /// When `None`, whatever resume the provider has pre-selected is used.
+    pub resume_path: Option<PathBuf>,
+
+    /// Upper bound on the number of pages we'll walk through in a single application,
+    /// guards against looping forever on a form we can't get past.
+    pub max_form_pages: u8,
+
+    /// Pause and ask in the terminal when a form question has no autofill answer,
+    /// instead of failing the application.
+    pub pause_on_unanswered: bool,
+
+    /// How long to wait for an answer at the prompt before skipping the job,
+    /// `0` waits forever.
+    pub pause_timeout_secs: u64,
+
+    /// Walk the application forms without ever pressing Submit.
+    pub dry_run: bool,
+}
+
+impl Default for ApplicationConfig {
+    fn default() -> Self {
+        Self {
+            resume_path:         None,
+            max_form_pages:      10,
+            pause_on_unanswered: true,
+            pause_timeout_secs:  0,
+            dry_run:             false,
+        }
+    }
+}
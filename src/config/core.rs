@@ -1,16 +1,19 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use config::Config;
 use serde::{Deserialize, Serialize};
 
 use crate::config::database::DatabaseConfig;
 use crate::config::{
+    ApplicationConfig,
     DriverConfig,
+    FilterConfig,
     GeneralConfig,
     LoggingConfig,
     LoginConfig,
     OutputConfig,
     ProcessHandleExt,
+    QuotaConfig,
     SearchConfig,
     ValidatePath as _,
 };
@@ -34,6 +37,15 @@ pub struct AppConfig {
     #[serde(default)]
     pub search: SearchConfig,
 
+    #[serde(default)]
+    pub application: ApplicationConfig,
+
+    #[serde(default)]
+    pub filters: FilterConfig,
+
+    #[serde(default)]
+    pub quotas: QuotaConfig,
+
     #[serde(skip)]
     pub config_path: PathBuf,
 }
@@ -73,12 +85,85 @@ impl AppConfig {
     // TODO: We will need ot update the global logging level based on the config file
     // ( If the user provided one that is )
 
-    pub async fn new() -> Self {
-        info!("Initializing AppConfig with default values.");
-        let config_path = crate::prelude::current_path()
+    /// `config/config.toml`, relative to the working directory (debug) or executable (release).
+    pub fn default_path() -> PathBuf {
+        crate::prelude::current_path()
             .expect("Failed to get current path")
             .join(crate::constants::CONFIG_DIR)
-            .join(crate::constants::CONFIG_FILE);
+            .join(crate::constants::CONFIG_FILE)
+    }
+
+    /// Loads the config from `path` when one is given (ie: `--config`), failing if it can't
+    /// be read, or from the default location with `AppConfig::new`'s fallbacks otherwise.
+    pub async fn load(path: Option<&Path>) -> Result<Self> {
+        let Some(path) = path else {
+            return Ok(Self::new().await);
+        };
+
+        info!("Using provided config file path: {:?}", path);
+        let mut app_config = Self::from_file(path)?;
+        app_config.validate_database_path().await;
+        Ok(app_config)
+    }
+
+    /// Strictly reads the config file (plus `APP_` environment overrides),
+    /// without falling back to, or writing out, the defaults.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        if !path.is_file() {
+            return Err(Error::ConfigError(format!("No config file at {}", path.display())));
+        }
+
+        let mut app_config: AppConfig = Config::builder()
+            .add_source(config::File::from(path))
+            .add_source(config::Environment::with_prefix("APP"))
+            .build()
+            .and_then(Config::try_deserialize)
+            .map_err(|e| Error::ConfigError(format!("{}: {}", path.display(), e)))?;
+
+        app_config.config_path = path.to_path_buf();
+        Ok(app_config)
+    }
+
+    /// Writes the default config to `path`, refusing to replace an existing file unless `force`.
+    pub async fn write_default(path: &Path, force: bool) -> Result<()> {
+        if path.exists() && !force {
+            return Err(Error::ConfigError(format!(
+                "{} already exists, use --force to overwrite it",
+                path.display()
+            )));
+        }
+
+        if let Some(dir) = path.parent() {
+            tokio::fs::create_dir_all(dir).await.map_err(|e| {
+                Error::ConfigError(format!("Failed to create {}: {}", dir.display(), e))
+            })?;
+        }
+
+        let config = AppConfig {
+            config_path: path.to_path_buf(),
+            ..Default::default()
+        };
+        tokio::fs::write(path, config.to_toml()?)
+            .await
+            .map_err(|e| Error::ConfigError(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    pub fn to_toml(&self) -> Result<String> {
+        toml::to_string_pretty(self)
+            .map_err(|e| Error::ConfigError(format!("Failed to serialize config: {}", e)))
+    }
+
+    async fn validate_database_path(&mut self) {
+        self.database.validate_path().await
+            .unwrap_or_else(|e| {
+                warn!("Database file validation failed: {e}. A new database has been created at the path provided in the config file: {}.",
+                    self.database.database_path.display());
+            });
+    }
+
+    pub async fn new() -> Self {
+        info!("Initializing AppConfig with default values.");
+        let config_path = Self::default_path();
 
         let config_path_own = Self::with_config_path()
             .await
@@ -120,14 +205,19 @@ impl AppConfig {
 
         app_config.config_path = config_path;
 
-        app_config.database.validate_path().await
-            .unwrap_or_else(|e| {
-                warn!("Database file validation failed: {e}. A new database has been created at the path provided in the config file: {}.",
-                    app_config.database.database_path.display());
-            });
-
+        app_config.validate_database_path().await;
         app_config
     }
+
+    /// Where selector pack overrides live, next to the config file.
+    pub fn selectors_dir(&self) -> PathBuf {
+        self.config_path
+            .parent()
+            .map(|dir| dir.join(crate::constants::SELECTORS_DIR))
+            .unwrap_or_else(|| {
+                PathBuf::from(crate::constants::CONFIG_DIR).join(crate::constants::SELECTORS_DIR)
+            })
+    }
 }
 
 async fn fallback_deserialize(config_path: PathBuf) -> AppConfig {
@@ -182,6 +272,9 @@ impl Default for AppConfig {
             output: OutputConfig::default(),
             login: LoginConfig::default(),
             search: SearchConfig::default(),
+            application: ApplicationConfig::default(),
+            filters: FilterConfig::default(),
+            quotas: QuotaConfig::default(),
             config_path,
         }
     }
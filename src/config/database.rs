@@ -13,6 +13,9 @@ pub struct DatabaseConfig {
     pub max_connections:   u32,
     pub idle_timeout_secs: u64,
     pub min_connections:   u32,
+    /// How long a connection waits on another one's lock before giving up on a write.
+    #[serde(default = "default_busy_timeout_secs")]
+    pub busy_timeout_secs: u64,
 
     #[serde(skip)]
     pub database_path: PathBuf,
@@ -91,11 +94,16 @@ impl Default for DatabaseConfig {
             max_connections: 10,
             idle_timeout_secs: 300, // Five minutes default
             min_connections: 2,     // 2, so we can swap between them without waiting when at min.
+            busy_timeout_secs: default_busy_timeout_secs(),
             database_path: path,
         }
     }
 }
 
+fn default_busy_timeout_secs() -> u64 {
+    10
+}
+
 impl From<DatabaseConfig> for sqlx::sqlite::SqlitePoolOptions {
     fn from(db_config: DatabaseConfig) -> Self {
         sqlx::sqlite::SqlitePoolOptions::new()
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+
+/// Rules a job has to pass before we apply to it, see `FilterRules`.
+///
+/// Every list is optional, an empty list doesn't filter anything.
+/// Text comparisons are case insensitive.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FilterConfig {
+    /// Regexes, the job title has to match at least one of them.
+    pub include_titles: Vec<String>,
+
+    /// Regexes, jobs whose title matches any of them are rejected.
+    pub exclude_titles: Vec<String>,
+
+    /// Company names to never apply to.
+    pub blocked_companies: Vec<String>,
+
+    /// Words/phrases the job description has to contain, every one of them.
+    pub required_keywords: Vec<String>,
+
+    /// Words/phrases that reject the job when found in its description.
+    pub forbidden_keywords: Vec<String>,
+
+    /// on-site | remote | hybrid | unknown (the job card didn't say)
+    pub work_types: Vec<String>,
+
+    /// The job's location has to contain one of these, ie: `Sydney` or `New Zealand`.
+    pub locations: Vec<String>,
+
+    /// Reject jobs posted more than this many days ago.
+    pub max_posting_age_days: Option<u32>,
+
+    /// Only jobs marked as "Actively reviewing applicants",
+    /// providers that don't show it (ie: Seek) let every job through.
+    pub actively_reviewing_only: bool,
+}
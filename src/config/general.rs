@@ -4,9 +4,9 @@ use serde::{Deserialize, Serialize};
 pub struct GeneralConfig {
     pub unfollow_companies: bool,
 
-    /// When it has a value we use that, otherwise
-    /// when `None`, will be set to usize::MAX
-    pub maximum_iterations: Option<u8>,
+    /// Stops the state machine after this many states have been executed (not jobs, a job
+    /// takes a few states and every results page one more), `None` runs without a limit.
+    pub maximum_iterations: Option<usize>,
     //
 
     // Impl. later
@@ -17,7 +17,7 @@ impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
             unfollow_companies: true,
-            maximum_iterations: Some(255),
+            maximum_iterations: None,
         }
     }
 }
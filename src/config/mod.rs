@@ -1,21 +1,27 @@
+mod application;
 mod core;
 mod database;
 mod driver;
 mod driver_type;
+mod filters;
 mod general;
 mod logging;
 mod login;
 mod output;
+mod quotas;
 mod search;
 
+pub use crate::config::application::ApplicationConfig;
 pub use crate::config::core::AppConfig;
 pub use crate::config::database::DatabaseConfig;
 pub use crate::config::driver::{DriverConfig, start_driver};
+pub use crate::config::filters::FilterConfig;
 //
 pub use crate::config::general::GeneralConfig;
-pub use crate::config::logging::LoggingConfig;
+pub use crate::config::logging::{LoggingConfig, LoggingLevel};
 pub use crate::config::login::LoginConfig;
 pub use crate::config::output::OutputConfig;
+pub use crate::config::quotas::QuotaConfig;
 pub use crate::config::search::SearchConfig;
 use crate::prelude::Result;
 
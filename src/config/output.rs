@@ -68,7 +68,7 @@ impl Default for SuccessOutputConfig {
         // let file_path = Self::with_config_path()
         //     .expect("Failed to get config file path for SuccessOutputConfig");
         Self {
-            enabled: false,
+            enabled: true,
             file_path,
         }
     }
@@ -133,7 +133,7 @@ impl Default for FailureOutputConfig {
         //     .expect("Failed to get config file path for SuccessOutputConfig");
 
         Self {
-            enabled: false,
+            enabled: true,
             file_path,
         }
     }
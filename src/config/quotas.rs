@@ -0,0 +1,49 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+use crate::providers::ProviderKind;
+
+/// Caps on how many applications are sent, providers flag accounts that apply too fast.
+///
+/// Every cap is optional, days run from midnight to midnight local time.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuotaConfig {
+    /// Applications sent in a single run.
+    pub max_per_run: Option<u32>,
+
+    /// Applications sent in a day, across every provider.
+    pub max_per_day: Option<u32>,
+
+    /// Applications sent in a day on one provider, ie: `linkedin = 25`.
+    pub max_per_day_by_provider: BTreeMap<String, u32>,
+
+    /// Once a daily cap is used up, wait for the next day instead of stopping the run.
+    pub wait_for_next_window: bool,
+}
+
+impl QuotaConfig {
+    /// The daily cap for `provider`, when one is set.
+    pub fn provider_max_per_day(&self, provider: ProviderKind) -> Option<u32> {
+        let name: &str = provider.into();
+        self.max_per_day_by_provider
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, max)| *max)
+    }
+
+    /// Checks every `max_per_day_by_provider` key names a provider we know of.
+    pub fn validate(&self) -> Result<()> {
+        for key in self.max_per_day_by_provider.keys() {
+            if !matches!(key.to_lowercase().as_str(), "linkedin" | "seek") {
+                return Err(Error::ConfigError(format!(
+                    "Unknown provider '{}' in quotas.max_per_day_by_provider, expected linkedin or seek",
+                    key
+                )));
+            }
+        }
+        Ok(())
+    }
+}
@@ -1,18 +1,28 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SearchConfig {
     pub use_keywords: bool,
     pub keywords:     Vec<String>,
     pub location:     Option<String>,
-}
 
-impl Default for SearchConfig {
-    fn default() -> Self {
-        Self {
-            use_keywords: false,
-            keywords:     vec![],
-            location:     None,
-        }
-    }
+    /// Overrides the provider's job board URL, ie: `https://www.seek.co.nz/` for Seek NZ.
+    #[serde(default)]
+    pub job_board_url: Option<String>,
+
+    /// Only show remote jobs.
+    #[serde(default)]
+    pub remote_only: bool,
+
+    /// Only show jobs posted in the last 24 hours.
+    #[serde(default)]
+    pub posted_last_day: bool,
+
+    /// Stop after this many pages of search results, `None` walks every page.
+    #[serde(default)]
+    pub max_pages: Option<u32>,
+
+    /// Stop once this many job listings have been read, across all pages.
+    #[serde(default)]
+    pub max_jobs: Option<usize>,
 }
@@ -1,6 +1,9 @@
 pub const CONFIG_DIR: &str = "config";
 pub const CONFIG_FILE: &str = "config.toml";
 
+/// Selector pack overrides, relative to the config directory.
+pub const SELECTORS_DIR: &str = "selectors";
+
 pub const DATABASE_DIR: &str = "database";
 pub const DATABASE_FILE: &str = "jobs.db";
 pub const DATABASE_MIGRATIONS_DIR: &str = "migrations";
@@ -1,46 +1,75 @@
 use sqlx::SqlitePool;
 
-use crate::db::data::{JobCardData, WorkType};
+use crate::db::data::{JobCardData, JobDescriptionData, StatusType};
+use crate::db::retry::retry_busy;
+use crate::db::update::insert_status_change;
 use crate::error::DatabaseError;
 use crate::prelude::{Error, Result};
+use crate::providers::ProviderKind;
 
 pub type JobCreationRowId = i64;
 
-pub async fn create_job(pool: &SqlitePool, data: &JobCardData) -> JobCreationRowId {
+pub async fn create_job(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    data: &JobCardData,
+) -> Result<JobCreationRowId> {
     let idx = data.idx as i64;
-    let work_type = match data.work_type {
-        WorkType::OnSite => "on-site",
-        WorkType::Remote => "remote",
-        WorkType::Hybrid => "hybrid",
-    };
+    let provider: &str = provider.into();
+    let work_type: &str = data.work_type.into();
+    let status: &str = StatusType::Pending.into();
 
-    let is_actively_reviewing = data.is_actively_reviewing as i64;
+    let is_actively_reviewing = data.is_actively_reviewing.unwrap_or(false) as i64;
     let already_viewed = data.already_viewed as i64;
     let has_easy_apply = data.has_easy_apply as i64;
 
-    sqlx::query!(
-        r#"
-        INSERT INTO jobs ( idx, card_title, job_title, company_name, state, country,
-            work_type_fk, is_actively_reviewing, 
-            already_viewed, full_date, 
-            relative_date, has_easy_apply, 
-            status_type_fk)
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'pending')
-        "#,
-        idx,
-        data.card_title,
-        data.job_title,
-        data.company_name,
-        data.location.state,
-        data.location.country,
-        work_type,
-        is_actively_reviewing,
-        already_viewed,
-        data.posted_date.full_date,
-        data.posted_date.relative,
-        has_easy_apply
-    )
-    .execute(pool)
+    // The job and the first entry of its status history go in together
+    retry_busy(|| {
+        async move {
+            let mut tx = pool.begin().await?;
+
+            let job_id = sqlx::query!(
+                r#"
+                INSERT INTO jobs ( provider, provider_job_id, idx, card_title, job_title, company_name,
+                    state, country, work_type_fk, is_actively_reviewing,
+                    already_viewed, full_date,
+                    relative_date, posted_at, scraped_at, has_easy_apply,
+                    status_type_fk, job_url, salary, discovered_at, updated_at)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?,
+                    (SELECT id FROM work_type WHERE type = ?),
+                    ?, ?, ?, ?, ?, ?, ?,
+                    (SELECT id FROM status_type WHERE type = ?),
+                    ?, ?, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+                "#,
+                provider,
+                data.provider_job_id,
+                idx,
+                data.card_title,
+                data.job_title,
+                data.company_name,
+                data.location.state,
+                data.location.country,
+                work_type,
+                is_actively_reviewing,
+                already_viewed,
+                data.posted_date.full_date,
+                data.posted_date.relative,
+                data.posted_date.posted_at,
+                data.posted_date.scraped_at,
+                has_easy_apply,
+                status,
+                data.job_url,
+                data.salary
+            )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+
+            insert_status_change(&mut tx, job_id, None, status, Some("discovered")).await?;
+            tx.commit().await?;
+            Ok(job_id)
+        }
+    })
     .await
     .map_err(|e| {
         Error::DatabaseError(DatabaseError::Create(format!(
@@ -48,22 +77,26 @@ pub async fn create_job(pool: &SqlitePool, data: &JobCardData) -> JobCreationRow
             data.idx, e
         )))
     })
-    .expect("Failed to insert job into database")
-    .last_insert_rowid()
 }
 
 pub type AutofillCreationRowId = i64;
 
-pub async fn create_autofill(pool: &SqlitePool, key: &str, data: &str) -> AutofillCreationRowId {
-    sqlx::query!(
-        r#"
-        INSERT INTO autofill ( input_key, data )
-        VALUES (?, ?)
-        "#,
-        key,
-        data
-    )
-    .execute(pool)
+pub async fn create_autofill(
+    pool: &SqlitePool,
+    key: &str,
+    data: &str,
+) -> Result<AutofillCreationRowId> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT INTO autofill ( input_key, data )
+            VALUES (?, ?)
+            "#,
+            key,
+            data
+        )
+        .execute(pool)
+    })
     .await
     .map_err(|e| {
         Error::DatabaseError(DatabaseError::Create(format!(
@@ -71,6 +104,168 @@ pub async fn create_autofill(pool: &SqlitePool, key: &str, data: &str) -> Autofi
             key, e
         )))
     })
-    .expect("Failed to insert autofill data into database")
-    .last_insert_rowid()
+    .map(|res| res.last_insert_rowid())
+}
+
+/// Records a form question we couldn't answer.
+/// Seeing the same question again only bumps its `times_seen` count.
+pub async fn create_unanswered_question(
+    pool: &SqlitePool,
+    key: &str,
+    label: &str,
+    field_kind: &str,
+    options: &str,
+) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT INTO unanswered_question ( input_key, label, field_kind, options )
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT (input_key) DO UPDATE
+            SET times_seen = times_seen + 1,
+                last_seen = CURRENT_TIMESTAMP,
+                label = excluded.label,
+                field_kind = excluded.field_kind,
+                options = excluded.options
+            "#,
+            key,
+            label,
+            field_kind,
+            options
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Create(format!(
+            "Failed to record unanswered question for key {}: {}",
+            key, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Records what a dry run would have submitted for the job.
+pub async fn create_dry_run_submission(
+    pool: &SqlitePool,
+    job_id: i64,
+    answers: &str,
+    resume_path: Option<&str>,
+) -> Result<i64> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT INTO dry_run_submission ( job_fk, answers, resume_path )
+            VALUES (?, ?, ?)
+            "#,
+            job_id,
+            answers,
+            resume_path
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Create(format!(
+            "Failed to record dry run submission for job_id {}: {}",
+            job_id, e
+        )))
+    })
+    .map(|res| res.last_insert_rowid())
+}
+
+/// Records a filter rule the job failed.
+pub async fn create_job_rejection(
+    pool: &SqlitePool,
+    job_id: i64,
+    rule: &str,
+    detail: &str,
+) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO job_rejection ( job_fk, rule, detail )
+            VALUES (?, ?, ?)
+            "#,
+            job_id,
+            rule,
+            detail
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Create(format!(
+            "Failed to record rejection '{}' for job_id {}: {}",
+            rule, job_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Stores the job's description, replacing the one stored the last time it was opened.
+pub async fn create_job_description(
+    pool: &SqlitePool,
+    job_id: i64,
+    data: &JobDescriptionData,
+) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT INTO job_description ( job_fk, description_text, description_html,
+                salary, seniority_level, employment_type )
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT (job_fk) DO UPDATE
+            SET description_text = excluded.description_text,
+                description_html = excluded.description_html,
+                salary = excluded.salary,
+                seniority_level = excluded.seniority_level,
+                employment_type = excluded.employment_type,
+                scraped_at = CURRENT_TIMESTAMP
+            "#,
+            job_id,
+            data.text,
+            data.html,
+            data.salary,
+            data.seniority_level,
+            data.employment_type
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Create(format!(
+            "Failed to store job description for job_id {}: {}",
+            job_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Starts recording a run's progress through the search results, returning the run's id.
+pub async fn create_run_progress(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    search_params: &str,
+) -> Result<i64> {
+    let provider: &str = provider.into();
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT INTO run_progress ( provider, search_params )
+            VALUES (?, ?)
+            "#,
+            provider,
+            search_params
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Create(format!(
+            "Failed to record a new {} run: {}",
+            provider, e
+        )))
+    })
+    .map(|res| res.last_insert_rowid())
 }
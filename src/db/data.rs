@@ -1,67 +1,344 @@
-use fantoccini::elements::Element;
+use std::str::FromStr;
+
 use sqlx::types::chrono::{DateTime, Utc};
 
+use crate::db::dates::{DateInfo, is_relative_date, parse_date};
+use crate::error::{DatabaseError, JobCardError};
+use crate::providers::ProviderKind;
+
+#[derive(Debug, Clone)]
 pub struct JobCardData {
     // there's also random dot things in some of the data
     // ·
     // whatever that is... we need to strip those out
     pub idx:                   usize,
+    pub provider_job_id:       String, // data-job-id attribute, or a fingerprint of the card when missing
     pub card_title:            String, // invisible // Full Stack Engineer (Verified job)
     pub job_title:             String, // Full Stack Engineer
     pub company_name:          String, // Nityo Infotech
     pub location:              Location, // { state: New South Wales, contry:  Australia }
     pub work_type:             WorkType, // (Hybrid)
-    pub is_actively_reviewing: bool,   // Actively reviewing applicants
-    pub already_viewed:        bool,   // Viewed
-    pub posted_date:           DateInfo, //  Posted on January 19, 2026, 10:43 AM
-    pub has_easy_apply:        bool,   // the icon for it //Easy Apply
+    pub is_actively_reviewing: Option<bool>, // Actively reviewing applicants, None when not shown (Seek)
+    pub already_viewed:        bool,         // Viewed
+    pub posted_date:           DateInfo,     //  Posted on January 19, 2026, 10:43 AM
+    pub has_easy_apply:        bool,         // the icon for it //Easy Apply
+    pub salary:                Option<String>, // $120,000 – $140,000 per year, when the card shows it
+    pub job_url:               Option<String>, // set once the provider's own job id is known
 }
 
-// TODO: [same_fn_1] :
-// Prefer to use From<Element> here for parsing job card data
-// instead of standalone functions.
-//
-// PROBLEM:
-// many components of parsing itself is async
-//
-// SOLUTION:
-// We could do what we do when creating default *Config structs,
-// tokio::task::block_in_place -> runtime::Handle::current().block_on(async { ... })
-// This is ugly as hell, but fantoccini's Element methods are all async.
-//
-impl From<Element> for JobCardData {
-    fn from(_element: Element) -> Self {
-        //
+const ACTIVELY_REVIEWING_MARKER: &str = "Actively reviewing applicants";
+const VIEWED_MARKER: &str = "Viewed";
+const EASY_APPLY_MARKER: &str = "Easy Apply";
+const POSTED_ON_MARKER: &str = "Posted on ";
+const QUICK_APPLY_MARKER: &str = "Quick apply";
+const SALARY_MARKERS: [char; 4] = ['$', '£', '€', '¥'];
+/// Lines Seek puts above the job title on promoted/new cards.
+const SEEK_BADGE_MARKERS: [&str; 2] = ["Featured", "New to you"];
+
+// NOTE: `From<Element>` isn't possible here as all of fantoccini's Element methods are async,
+// so the element side lives in `db::extract_job_card`, which reads the card text and
+// hands it to `JobCardData::from_card_text`.
+
+impl JobCardData {
+    /// Builds a `JobCardData` from the visible text of a single job card.
+    ///
+    /// The text is expected to be laid out one item per line, as LinkedIn renders it:
+    ///
+    /// ```text
+    /// Full Stack Engineer (Verified job)
+    /// Full Stack Engineer
+    /// Nityo Infotech
+    /// New South Wales, Australia (Hybrid)
+    /// Actively reviewing applicants
+    /// Viewed
+    ///  ·
+    /// Posted on January 19, 2026, 10:43 AM
+    /// 1 week ago
+    ///  ·
+    ///  Easy Apply
+    /// ```
+    ///
+    /// The first four lines are positional, everything after them is matched by content
+    /// and may be missing or appear in any order. A location line without a `(work type)`
+    /// gives `WorkType::Unknown`, and a card without any posting date an empty `DateInfo`.
+    pub fn from_card_text(idx: usize, text: &str) -> Result<Self, JobCardError> {
+        let lines: Vec<&str> = text
+            .lines()
+            .map(|line| line.trim_matches(|c: char| c == '·' || c.is_whitespace()))
+            .filter(|line| !line.is_empty())
+            .collect();
 
-        todo!()
+        let positional = |pos: usize, field: &'static str| {
+            lines
+                .get(pos)
+                .map(|line| line.to_string())
+                .ok_or(JobCardError::MissingField { idx, field })
+        };
+
+        let card_title = positional(0, "card_title")?;
+        let job_title = positional(1, "job_title")?;
+        let company_name = positional(2, "company_name")?;
+        let location_line = positional(3, "location")?;
+
+        // Cards for a plain "Sydney, NSW" or "Australia" don't say where the work is done
+        let (location, work_type) = match split_location_line(&location_line) {
+            (location, Some(work_type)) => {
+                match work_type.parse::<WorkType>() {
+                    Ok(work_type) => (location, work_type),
+                    // Part of the location, ie: "Greater Sydney (NSW)"
+                    Err(_) => (location_line, WorkType::Unknown),
+                }
+            }
+            (location, None) => (location, WorkType::Unknown),
+        };
+
+        let mut is_actively_reviewing = false;
+        let mut already_viewed = false;
+        let mut has_easy_apply = false;
+        let mut full_date = None;
+        let mut relative_date = None;
+        let mut salary = None;
+
+        for line in lines.iter().skip(4) {
+            match *line {
+                ACTIVELY_REVIEWING_MARKER => is_actively_reviewing = true,
+                VIEWED_MARKER => already_viewed = true,
+                EASY_APPLY_MARKER => has_easy_apply = true,
+                l if l.starts_with(POSTED_ON_MARKER) => full_date = Some(*line),
+                l if is_relative_date(l) => relative_date = Some(*line),
+                l if is_salary(l) => salary = Some(l.to_string()),
+                _ => {
+                    tracing::trace!("Ignoring unrecognised line on job card idx {}: {}", idx, line);
+                }
+            }
+        }
+
+        if full_date.is_none() && relative_date.is_none() {
+            // ie: "Promoted" cards, stored with an unknown posting date (`posted_at` is NULL)
+            tracing::debug!("No posting date on job card idx {}", idx);
+        }
+
+        let location = Location::from(location.as_str());
+        let provider_job_id = fingerprint(&job_title, &company_name, &location);
+
+        Ok(Self {
+            idx,
+            provider_job_id,
+            card_title,
+            job_title,
+            company_name,
+            location,
+            work_type,
+            is_actively_reviewing: Some(is_actively_reviewing),
+            already_viewed,
+            posted_date: parse_date(
+                full_date.unwrap_or_default(),
+                relative_date.unwrap_or_default(),
+            ),
+            has_easy_apply,
+            salary,
+            job_url: None,
+        })
     }
 }
 
+impl JobCardData {
+    /// Builds a `JobCardData` from the visible text of a single Seek job card:
+    ///
+    /// ```text
+    /// Featured
+    /// Senior Rust Engineer
+    /// at Acme Pty Ltd
+    /// Sydney NSW (Hybrid)
+    /// Information & Communication Technology
+    /// $150,000 – $170,000 per year
+    /// Quick apply
+    /// 2d ago
+    /// ```
+    ///
+    /// Seek cards have no separate card title, no country, and the work type is only shown
+    /// when it isn't on-site. The posting date is missing on some featured cards.
+    pub fn from_seek_card_text(idx: usize, text: &str) -> Result<Self, JobCardError> {
+        let lines: Vec<&str> = text
+            .lines()
+            .map(|line| line.trim_matches(|c: char| c == '·' || c.is_whitespace()))
+            .filter(|line| !line.is_empty())
+            .skip_while(|line| SEEK_BADGE_MARKERS.contains(line))
+            .collect();
+
+        let positional = |pos: usize, field: &'static str| {
+            lines
+                .get(pos)
+                .map(|line| line.to_string())
+                .ok_or(JobCardError::MissingField { idx, field })
+        };
+
+        let job_title = positional(0, "job_title")?;
+        let company_name = positional(1, "company_name")?;
+        let company_name = company_name
+            .strip_prefix("at ")
+            .unwrap_or(&company_name)
+            .to_string();
+        let location_line = positional(2, "location")?;
+
+        let (location, work_type) = split_location_line(&location_line);
+        let mut work_type = match work_type {
+            Some(value) => {
+                value.parse::<WorkType>().map_err(|value| {
+                    JobCardError::InvalidField {
+                        idx,
+                        field: "work_type",
+                        value,
+                    }
+                })?
+            }
+            None => WorkType::OnSite,
+        };
+
+        let mut already_viewed = false;
+        let mut has_easy_apply = false;
+        let mut relative_date = None;
+        let mut salary = None;
+
+        for line in lines.iter().skip(3) {
+            match *line {
+                VIEWED_MARKER => already_viewed = true,
+                l if l.eq_ignore_ascii_case(QUICK_APPLY_MARKER) => has_easy_apply = true,
+                l if is_relative_date(l) => relative_date = Some(*line),
+                l if is_salary(l) => salary = Some(l.to_string()),
+                // Remote/hybrid jobs get their own line, rather than being part of the location
+                l => {
+                    match l.parse::<WorkType>() {
+                        Ok(parsed) => work_type = parsed,
+                        Err(_) => {
+                            tracing::trace!(
+                                "Ignoring unrecognised line on job card idx {}: {}",
+                                idx,
+                                line
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        // Seek locations are `Suburb STATE`, the country is implied by the site (AU/NZ)
+        let location = Location {
+            state:   location,
+            country: String::new(),
+        };
+        let provider_job_id = fingerprint(&job_title, &company_name, &location);
+
+        Ok(Self {
+            idx,
+            provider_job_id,
+            card_title: job_title.clone(),
+            job_title,
+            company_name,
+            location,
+            work_type,
+            is_actively_reviewing: None,
+            already_viewed,
+            posted_date: parse_date("", relative_date.unwrap_or_default()),
+            has_easy_apply,
+            salary,
+            job_url: None,
+        })
+    }
+
+    /// The `provider_job_id` the card is stored under when the provider's own id is missing.
+    pub fn fingerprint(&self) -> String {
+        fingerprint(&self.job_title, &self.company_name, &self.location)
+    }
+}
+
+/// Splits `New South Wales, Australia (Hybrid)` into the location part
+/// and the (optional) work type found inside the trailing parentheses.
+fn split_location_line(line: &str) -> (String, Option<String>) {
+    match (line.rfind('('), line.ends_with(')')) {
+        (Some(open), true) => {
+            let location = line[..open].trim().to_string();
+            let work_type = line[open + 1..line.len() - 1].trim().to_string();
+            (location, Some(work_type))
+        }
+        _ => (line.trim().to_string(), None),
+    }
+}
+
+/// `$120k – $140k`, `£45,000 a year`, `$55 - $65 per hour`, etc.
+fn is_salary(line: &str) -> bool {
+    line.contains(SALARY_MARKERS) && line.chars().any(|c| c.is_ascii_digit())
+}
+
+/// Stable identifier for a card when the provider doesn't expose its own job id.
+/// FNV-1a over the fields that identify a posting, so it is identical across runs.
+fn fingerprint(job_title: &str, company_name: &str, location: &Location) -> String {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let key = format!("{}|{}|{}|{}", job_title, company_name, location.state, location.country)
+        .to_lowercase();
+
+    let hash = key
+        .bytes()
+        .fold(FNV_OFFSET, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME));
+
+    format!("fp-{:016x}", hash)
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct Location {
     pub state:   String, // New South Wales
     pub country: String, // Australia
 }
 
+/// Country codes that can end a location, any other short all-caps part is a region code.
+const COUNTRY_CODES: [&str; 5] = ["UK", "US", "USA", "UAE", "NZ"];
+
 impl From<&str> for Location {
+    /// The country is the last comma separated part, everything before it is treated as the
+    /// state (city, region, etc.) ie: `Sydney, New South Wales, Australia` ->
+    /// { state: `Sydney, New South Wales`, country: `Australia` }
+    ///
+    /// Unless the last part is a region code, ie: `Sydney, NSW` or `Austin, TX`,
+    /// then the whole location is the state and the country is left empty.
     fn from(s: &str) -> Self {
-        let parts: Vec<&str> = s.split(',').map(|part| part.trim()).collect();
-        let state = parts.first().unwrap_or(&"").to_string();
-        let country = parts.get(1).unwrap_or(&"").to_string();
+        let mut parts: Vec<&str> = s
+            .split(',')
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+            .collect();
+
+        let country = match parts.last() {
+            Some(last) if parts.len() > 1 && is_region_code(last) => String::new(),
+            _ => parts.pop().unwrap_or_default().to_string(),
+        };
+        let state = parts.join(", ");
 
         Location { state, country }
     }
 }
 
+/// `NSW`, `VIC`, `CA`, `TX`, etc.
+fn is_region_code(part: &str) -> bool {
+    (2..=3).contains(&part.len())
+        && part.chars().all(|c| c.is_ascii_uppercase())
+        && !COUNTRY_CODES.contains(&part)
+}
+
 impl From<Location> for String {
     fn from(location: Location) -> Self {
         format!("{}, {}", location.state, location.country)
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WorkType {
     OnSite,
     Remote,
     Hybrid,
+    /// The card didn't say, ie: a location line without `(Hybrid)`.
+    Unknown,
 }
 
 impl From<&str> for WorkType {
@@ -70,6 +347,7 @@ impl From<&str> for WorkType {
             "on-site" => WorkType::OnSite,
             "remote" => WorkType::Remote,
             "hybrid" => WorkType::Hybrid,
+            "unknown" => WorkType::Unknown,
             _ => {
                 unimplemented!(
                     "You've attempted to turn an unrecognized work kind string into a WorkKind enum variant: {}",
@@ -80,36 +358,88 @@ impl From<&str> for WorkType {
     }
 }
 
+impl FromStr for WorkType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "on-site" | "onsite" => Ok(WorkType::OnSite),
+            "remote" => Ok(WorkType::Remote),
+            "hybrid" => Ok(WorkType::Hybrid),
+            "unknown" => Ok(WorkType::Unknown),
+            _ => Err(s.to_string()),
+        }
+    }
+}
+
 impl From<WorkType> for &str {
     fn from(work_kind: WorkType) -> Self {
         match work_kind {
             WorkType::OnSite => "on-site",
             WorkType::Remote => "remote",
             WorkType::Hybrid => "hybrid",
+            WorkType::Unknown => "unknown",
         }
     }
 }
 
-pub struct DateInfo {
-    // We'd want to actually strip the 'Posted on' part, and use a proper DT type
-    pub full_date: String, // Posted on January 19, 2026, 10:43 AM
-    pub relative:  String, // 1 week ago
-}
-
-pub fn parse_date(full: &str, relative: &str) -> DateInfo {
-    let full_clean = full.replace("Posted on ", "").trim().to_string();
-    let _parsed_dt: DateTime<Utc> = full_clean.parse().unwrap_or_default();
-    DateInfo {
-        full_date: full_clean,
-        relative:  relative.trim().to_string(),
-    }
-}
-
+/// Where a job is at, see `db.job_status_history` for how it got there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum StatusType {
+    /// Found, waiting to be validated/applied to.
     Pending,
+    /// An application is underway.
     InProgress,
+    /// The provider shows the job as applied to.
     Completed,
+    /// The application failed, see `jobs.failure_reason`.
     Failed,
+    /// Failed validation (no Easy Apply, closed, ...), see `jobs.skip_reason`.
+    Skipped,
+    /// Rejected by the filter rules, see `db.job_rejection`.
+    Filtered,
+    /// The application stopped on something only the user can sort out (ie: an unanswered question).
+    NeedsAttention,
+    /// The application was sent.
+    Submitted,
+}
+
+impl StatusType {
+    /// Whether a job in this status may move on to `next`.
+    ///
+    /// Completed is final and a submitted application can only be completed.
+    /// Applications go through InProgress, everything else that didn't end up applied to
+    /// can be validated (and applied to) again. Staying in the same status is always allowed.
+    pub fn can_transition_to(self, next: StatusType) -> bool {
+        use StatusType::{
+            Completed,
+            Failed,
+            Filtered,
+            InProgress,
+            NeedsAttention,
+            Pending,
+            Skipped,
+            Submitted,
+        };
+
+        if self == next {
+            return true;
+        }
+
+        match self {
+            Completed => false,
+            Submitted => next == Completed,
+            InProgress => {
+                matches!(
+                    next,
+                    Pending | Submitted | Completed | Failed | NeedsAttention | Skipped | Filtered
+                )
+            }
+            Pending | Failed | Skipped | Filtered | NeedsAttention => {
+                matches!(next, Pending | InProgress | Completed | Failed | Skipped | Filtered)
+            }
+        }
+    }
 }
 
 pub struct Status {
@@ -117,19 +447,22 @@ pub struct Status {
     status: StatusType,
 }
 
-impl From<&str> for StatusType {
-    fn from(s: &str) -> Self {
+impl FromStr for StatusType {
+    type Err = DatabaseError;
+
+    /// A status read back from `db.status_type`, one this build doesn't know of
+    /// (ie: written by a newer one) is a read error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "pending" => StatusType::Pending,
-            "in_progress" => StatusType::InProgress,
-            "completed" => StatusType::Completed,
-            "failed" => StatusType::Failed,
-            _ => {
-                unimplemented!(
-                    "You've attempted to turn an unrecognized status string into a StatusTypes enum variant: {}",
-                    s
-                )
-            }
+            "pending" => Ok(StatusType::Pending),
+            "in_progress" => Ok(StatusType::InProgress),
+            "completed" => Ok(StatusType::Completed),
+            "failed" => Ok(StatusType::Failed),
+            "skipped" => Ok(StatusType::Skipped),
+            "filtered" => Ok(StatusType::Filtered),
+            "needs_attention" => Ok(StatusType::NeedsAttention),
+            "submitted" => Ok(StatusType::Submitted),
+            _ => Err(DatabaseError::Read(format!("Unknown job status '{}'", s))),
         }
     }
 }
@@ -141,16 +474,607 @@ impl From<StatusType> for &str {
             StatusType::InProgress => "in_progress",
             StatusType::Completed => "completed",
             StatusType::Failed => "failed",
+            StatusType::Skipped => "skipped",
+            StatusType::Filtered => "filtered",
+            StatusType::NeedsAttention => "needs_attention",
+            StatusType::Submitted => "submitted",
+        }
+    }
+}
+
+/// One status change from `db.job_status_history`.
+#[derive(Debug, Clone)]
+pub struct StatusChange {
+    /// `None` for the status the job was discovered in.
+    pub from_status: Option<String>,
+    pub to_status:   String,
+    pub reason:      Option<String>,
+    pub changed_at:  DateTime<Utc>,
+}
+
+/// A form question we had no autofill answer for, see `db.unanswered_question`.
+#[derive(Debug, Clone)]
+pub struct UnansweredQuestion {
+    pub input_key:  String,
+    pub label:      String,
+    pub field_kind: String,
+    pub options:    String, // serialized JSON array
+    pub times_seen: i64,
+}
+
+/// A stored job as read back from `db.jobs`, for listing/inspecting outside of a run.
+#[derive(Debug, Clone)]
+pub struct JobRecord {
+    pub id:              i64,
+    pub provider:        String,
+    pub provider_job_id: String,
+    pub job_title:       String,
+    pub company_name:    String,
+    pub state:           String,
+    pub country:         String,
+    pub work_type:       String,
+    pub has_easy_apply:  bool,
+    pub full_date:       String,
+    pub relative_date:   String,
+    pub posted_at:       Option<DateTime<Utc>>,
+    pub scraped_at:      Option<DateTime<Utc>>,
+    pub status:          String,
+    pub skip_reason:     Option<String>,
+    pub applied_date:    Option<String>,
+    pub job_url:         Option<String>,
+    pub salary:          Option<String>,
+    pub employment_type: Option<String>,
+    pub failure_reason:  Option<String>,
+    pub discovered_at:   Option<DateTime<Utc>>,
+    pub updated_at:      Option<DateTime<Utc>>,
+}
+
+impl JobRecord {
+    /// Whether `provider_job_id` is the provider's own id, rather than a fingerprint
+    /// of the card text (or a placeholder for rows stored before ids were kept).
+    pub fn has_provider_id(&self) -> bool {
+        !self.provider_job_id.starts_with("fp-") && !self.provider_job_id.starts_with("legacy-")
+    }
+}
+
+/// Which stored jobs `find_jobs`, `count_jobs` and `delete_jobs` work on.
+///
+/// Every filter is optional, the default query matches every job.
+#[derive(Debug, Clone, Default)]
+pub struct JobQuery {
+    pub id:            Option<i64>,
+    pub status:        Option<StatusType>,
+    pub provider:      Option<ProviderKind>,
+    /// Matches any company name containing it, case insensitive.
+    pub company:       Option<String>,
+    /// Posted at or after, jobs without a known posting date never match.
+    pub posted_after:  Option<DateTime<Utc>>,
+    /// Posted before, jobs without a known posting date never match.
+    pub posted_before: Option<DateTime<Utc>>,
+    /// Matches jobs whose stored description contains it, case insensitive.
+    pub description:   Option<String>,
+    /// Only jobs without a `skip_reason`.
+    pub not_skipped:   bool,
+    pub order:         JobOrder,
+}
+
+impl JobQuery {
+    pub fn with_id(id: i64) -> Self {
+        Self {
+            id: Some(id),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_status(status: StatusType) -> Self {
+        Self {
+            status: Some(status),
+            ..Self::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JobOrder {
+    /// Most recently stored first.
+    #[default]
+    Newest,
+    /// First stored first.
+    Oldest,
+    /// Most recently posted first, jobs without a known posting date last.
+    Posted,
+}
+
+/// A window onto a listing, `offset` rows in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Page {
+    pub limit:  i64,
+    pub offset: i64,
+}
+
+impl Page {
+    /// Every row, no paging.
+    pub const ALL: Page = Page {
+        limit:  -1,
+        offset: 0,
+    };
+
+    pub fn first(limit: i64) -> Self {
+        Self { limit, offset: 0 }
+    }
+
+    /// The page after this one.
+    pub fn next(self) -> Self {
+        Self {
+            offset: self.offset + self.limit,
+            ..self
         }
     }
 }
 
-// pub enum EmploymentType {
-//     FullTime,
-//     PartTime,
-//     Contract,
-//     Temporary,
-//     Internship,
-//     Volunteer,
-//     Other,
-// }
+/// How many stored jobs are in a status, see `count_jobs_by_status`.
+#[derive(Debug, Clone)]
+pub struct StatusCount {
+    pub status: String,
+    pub count:  i64,
+}
+
+/// How far a run got through the search results, see `db.run_progress`.
+#[derive(Debug, Clone)]
+pub struct RunProgress {
+    pub id:            i64,
+    pub provider:      String,
+    pub search_params: String, // serialized JSON of the search settings
+    /// Pages of results fully worked through.
+    pub pages_done:    i64,
+    /// Listings read on those pages.
+    pub jobs_done:     i64,
+    /// The last job the run finished with.
+    pub last_job_id:   Option<i64>,
+    pub started_at:    DateTime<Utc>,
+    pub updated_at:    DateTime<Utc>,
+}
+
+/// What a dry run would have submitted, see `db.dry_run_submission`.
+#[derive(Debug, Clone)]
+pub struct DryRunRecord {
+    pub id:          i64,
+    pub answers:     String, // serialized JSON array of { label, kind, value }
+    pub resume_path: Option<String>,
+    pub created_at:  String,
+}
+
+/// What the details pane says about a job, read once the job has been opened.
+///
+/// Everything but the description itself is optional, not every posting (or provider) has them.
+#[derive(Debug, Clone, Default)]
+pub struct JobDescriptionData {
+    pub text:            String,
+    pub html:            String,
+    pub salary:          Option<String>, // $120,000 – $140,000 per year
+    pub seniority_level: Option<String>, // Mid-Senior level
+    pub employment_type: Option<String>, // Full-time
+}
+
+/// A stored job description as read back from `db.job_description`.
+#[derive(Debug, Clone)]
+pub struct JobDescriptionRecord {
+    pub job_id:           i64,
+    pub description_text: String,
+    pub description_html: String,
+    pub salary:           Option<String>,
+    pub seniority_level:  Option<String>,
+    pub employment_type:  Option<String>,
+    pub scraped_at:       String,
+}
+
+/// A filter rule a job failed, see `db.job_rejection`.
+#[derive(Debug, Clone)]
+pub struct JobRejection {
+    pub rule:   String,
+    pub detail: String,
+}
+
+/// A stored answer from `db.autofill`, `data` being a serialized `AutofillAnswer`.
+#[derive(Debug, Clone)]
+pub struct AutofillEntry {
+    pub input_key: String,
+    pub data:      String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmploymentType {
+    FullTime,
+    PartTime,
+    Contract,
+    Temporary,
+    Internship,
+    Volunteer,
+    Other,
+}
+
+impl FromStr for EmploymentType {
+    type Err = String;
+
+    /// Lenient, providers word these differently: `Full-time`, `Full time`, `Contract/Temp`, etc.
+    /// Anything that isn't recognised is `Other`, only an empty string is an error.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalised = s.trim().to_lowercase().replace(['-', '_'], " ");
+
+        let employment_type = match normalised.as_str() {
+            "" => return Err(s.to_string()),
+            "full time" | "fulltime" | "permanent" => EmploymentType::FullTime,
+            "part time" | "parttime" => EmploymentType::PartTime,
+            t if t.starts_with("contract") => EmploymentType::Contract,
+            "temporary" | "temp" | "casual" | "casual/vacation" => EmploymentType::Temporary,
+            "internship" | "intern" => EmploymentType::Internship,
+            "volunteer" => EmploymentType::Volunteer,
+            _ => EmploymentType::Other,
+        };
+
+        Ok(employment_type)
+    }
+}
+
+impl From<EmploymentType> for &str {
+    fn from(employment_type: EmploymentType) -> Self {
+        match employment_type {
+            EmploymentType::FullTime => "full_time",
+            EmploymentType::PartTime => "part_time",
+            EmploymentType::Contract => "contract",
+            EmploymentType::Temporary => "temporary",
+            EmploymentType::Internship => "internship",
+            EmploymentType::Volunteer => "volunteer",
+            EmploymentType::Other => "other",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::dates::parse_absolute_date;
+
+    /// Job card text as the browser reads it off the page, see `tests/fixtures/job_cards`.
+    fn fixture(name: &str) -> String {
+        let path = format!("{}/tests/fixtures/job_cards/{}", env!("CARGO_MANIFEST_DIR"), name);
+        std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+    }
+
+    fn card(name: &str) -> JobCardData {
+        JobCardData::from_card_text(0, &fixture(name)).expect(name)
+    }
+
+    #[test]
+    fn full_card_reads_every_field() {
+        let card = JobCardData::from_card_text(7, &fixture("linkedin_full.txt")).unwrap();
+
+        assert_eq!(card.idx, 7);
+        assert_eq!(card.card_title, "Full Stack Engineer (Verified job)");
+        assert_eq!(card.job_title, "Full Stack Engineer");
+        assert_eq!(card.company_name, "Nityo Infotech");
+        assert_eq!(
+            card.location,
+            Location {
+                state:   "New South Wales".to_string(),
+                country: "Australia".to_string(),
+            }
+        );
+        assert_eq!(card.work_type, WorkType::Hybrid);
+        assert_eq!(card.is_actively_reviewing, Some(true));
+        assert!(card.already_viewed);
+        assert!(card.has_easy_apply);
+        assert_eq!(card.salary.as_deref(), Some("$120,000 – $140,000 per year"));
+        assert_eq!(card.posted_date.full_date, "January 19, 2026, 10:43 AM");
+        assert_eq!(card.posted_date.relative, "1 week ago");
+        assert_eq!(card.posted_date.posted_at, parse_absolute_date("January 19, 2026, 10:43 AM"));
+        assert!(card.posted_date.posted_at.is_some());
+        assert_eq!(card.job_url, None);
+        assert!(card.provider_job_id.starts_with("fp-"));
+    }
+
+    #[test]
+    fn optional_lines_default_to_off() {
+        let card = card("linkedin_minimal.txt");
+
+        assert_eq!(card.job_title, "Rust Developer");
+        assert_eq!(card.location.state, "Melbourne, Victoria");
+        assert_eq!(card.location.country, "Australia");
+        assert_eq!(card.work_type, WorkType::Remote);
+        assert_eq!(card.is_actively_reviewing, Some(false));
+        assert!(!card.already_viewed);
+        assert!(!card.has_easy_apply);
+        assert_eq!(card.salary, None);
+        assert_eq!(card.posted_date.full_date, "");
+        assert_eq!(card.posted_date.relative, "3 days ago");
+        assert_eq!(card.posted_date.age_days(), Some(3));
+    }
+
+    #[test]
+    fn card_without_work_type_or_date_is_kept() {
+        let card = card("linkedin_promoted.txt");
+
+        assert_eq!(card.job_title, "Backend Engineer");
+        assert_eq!(card.location.state, "Sydney, NSW");
+        assert_eq!(card.location.country, "");
+        assert_eq!(card.work_type, WorkType::Unknown);
+        assert!(card.has_easy_apply);
+        assert_eq!(card.posted_date.full_date, "");
+        assert_eq!(card.posted_date.relative, "");
+        assert_eq!(card.posted_date.posted_at, None);
+    }
+
+    #[test]
+    fn country_only_location() {
+        let card = card("linkedin_country_only.txt");
+
+        assert_eq!(card.location.state, "");
+        assert_eq!(card.location.country, "Australia");
+        assert_eq!(card.work_type, WorkType::Unknown);
+        assert_eq!(card.posted_date.full_date, "Jan 5, 2026, 9:00 AM");
+        assert!(card.posted_date.posted_at.is_some());
+    }
+
+    #[test]
+    fn parentheses_that_arent_a_work_type_stay_in_the_location() {
+        let card = card("linkedin_location_parens.txt");
+
+        assert_eq!(card.location.country, "Greater Sydney Area (NSW)");
+        assert_eq!(card.work_type, WorkType::Unknown);
+        assert_eq!(card.posted_date.relative, "2 weeks ago");
+    }
+
+    #[test]
+    fn card_missing_positional_lines_is_an_error() {
+        let err = JobCardData::from_card_text(3, &fixture("linkedin_truncated.txt")).unwrap_err();
+
+        assert!(matches!(
+            err,
+            JobCardError::MissingField {
+                idx:   3,
+                field: "location",
+            }
+        ));
+    }
+
+    fn seek_card(name: &str) -> JobCardData {
+        JobCardData::from_seek_card_text(0, &fixture(name)).expect(name)
+    }
+
+    #[test]
+    fn seek_card_reads_every_field() {
+        let card = seek_card("seek_full.txt");
+
+        assert_eq!(card.card_title, "Senior Rust Engineer");
+        assert_eq!(card.job_title, "Senior Rust Engineer");
+        assert_eq!(card.company_name, "Acme Pty Ltd");
+        assert_eq!(card.location.state, "Surry Hills, Sydney NSW");
+        assert_eq!(card.location.country, "");
+        assert_eq!(card.work_type, WorkType::Hybrid);
+        assert_eq!(card.salary.as_deref(), Some("$150,000 – $170,000 per year"));
+        assert!(card.already_viewed);
+        assert!(card.has_easy_apply);
+        assert_eq!(card.is_actively_reviewing, None);
+        assert_eq!(card.posted_date.relative, "2d ago");
+        assert_eq!(card.posted_date.age_days(), Some(2));
+        assert_eq!(card.job_url, None);
+        assert!(card.provider_job_id.starts_with("fp-"));
+    }
+
+    #[test]
+    fn seek_card_without_suburb_or_salary() {
+        let card = seek_card("seek_minimal.txt");
+
+        assert_eq!(card.job_title, "Backend Developer");
+        assert_eq!(card.company_name, "Widgets Co");
+        assert_eq!(card.location.state, "Melbourne VIC");
+        // Given its own line instead of being part of the location
+        assert_eq!(card.work_type, WorkType::Remote);
+        assert_eq!(card.salary, None);
+        assert!(!card.already_viewed);
+        assert!(!card.has_easy_apply);
+        assert_eq!(card.posted_date.relative, "5d ago");
+    }
+
+    #[test]
+    fn seek_card_badges_and_missing_date() {
+        let card = seek_card("seek_no_date.txt");
+
+        // "New to you" is a badge, not the title
+        assert_eq!(card.job_title, "Platform Engineer");
+        assert_eq!(card.company_name, "Kiwi Cloud Ltd");
+        assert_eq!(card.location.state, "Wellington");
+        assert_eq!(card.work_type, WorkType::OnSite);
+        // No amount, so not a salary
+        assert_eq!(card.salary, None);
+        assert_eq!(card.posted_date.relative, "");
+        assert_eq!(card.posted_date.posted_at, None);
+    }
+
+    #[test]
+    fn seek_card_errors() {
+        let err =
+            JobCardData::from_seek_card_text(2, "Featured\nRust Engineer\nat Acme").unwrap_err();
+        assert!(matches!(
+            err,
+            JobCardError::MissingField {
+                idx:   2,
+                field: "location",
+            }
+        ));
+
+        let err =
+            JobCardData::from_seek_card_text(4, "Rust Engineer\nat Acme\nSydney NSW (Fly-in)")
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            JobCardError::InvalidField {
+                idx: 4,
+                field: "work_type",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn split_location_line_work_type_suffix() {
+        let cases = [
+            ("New South Wales, Australia (Hybrid)", "New South Wales, Australia", Some("Hybrid")),
+            ("Australia (Remote)", "Australia", Some("Remote")),
+            ("Sydney, NSW ( On-site )", "Sydney, NSW", Some("On-site")),
+            ("Sydney, NSW", "Sydney, NSW", None),
+            ("  Australia  ", "Australia", None),
+            ("Sydney (CBD), NSW", "Sydney (CBD), NSW", None),
+            // Seek, with and without the suburb
+            ("Surry Hills, Sydney NSW (Hybrid)", "Surry Hills, Sydney NSW", Some("Hybrid")),
+            ("Melbourne VIC (Remote)", "Melbourne VIC", Some("Remote")),
+            ("Melbourne VIC", "Melbourne VIC", None),
+        ];
+
+        for (line, location, work_type) in cases {
+            let (got_location, got_work_type) = split_location_line(line);
+            assert_eq!(got_location, location, "{}", line);
+            assert_eq!(got_work_type.as_deref(), work_type, "{}", line);
+        }
+    }
+
+    #[test]
+    fn salary_lines() {
+        let cases = [
+            ("$150,000 – $170,000 per year", true),
+            ("$55 - $65 per hour", true),
+            ("£45,000 a year", true),
+            ("€60k", true),
+            ("Up to $120k + super", true),
+            ("Competitive package + bonus", false),
+            ("$$$", false),
+            ("Information & Communication Technology", false),
+            ("2d ago", false),
+            ("", false),
+        ];
+
+        for (line, expected) in cases {
+            assert_eq!(is_salary(line), expected, "{}", line);
+        }
+    }
+
+    #[test]
+    fn fingerprint_is_stable() {
+        let first = card("linkedin_full.txt");
+        let again = card("linkedin_full.txt");
+        assert_eq!(first.provider_job_id, again.provider_job_id);
+
+        // Pinned, a change here would store every known job a second time
+        let location = Location::from("New South Wales, Australia");
+        assert_eq!(
+            fingerprint("Full Stack Engineer", "Nityo Infotech", &location),
+            first.provider_job_id
+        );
+        assert_eq!(first.provider_job_id, "fp-b6799cc964fe48e3");
+
+        // Case doesn't matter, every identifying field does
+        assert_eq!(
+            fingerprint("FULL STACK ENGINEER", "nityo infotech", &location),
+            first.provider_job_id
+        );
+        assert_ne!(fingerprint("Full Stack Engineer", "Nityo", &location), first.provider_job_id);
+        assert_ne!(
+            fingerprint("Full Stack Engineer", "Nityo Infotech", &Location::from("Australia")),
+            first.provider_job_id
+        );
+    }
+
+    #[test]
+    fn location_from_str() {
+        let cases = [
+            ("Sydney, New South Wales, Australia", "Sydney, New South Wales", "Australia"),
+            ("New South Wales, Australia", "New South Wales", "Australia"),
+            ("Australia", "", "Australia"),
+            (" Sydney ,  NSW ", "Sydney, NSW", ""),
+            ("Austin, TX", "Austin, TX", ""),
+            ("Wellington, NZ", "Wellington", "NZ"),
+            ("NSW", "", "NSW"),
+            ("Sydney,, Australia,", "Sydney", "Australia"),
+            ("", "", ""),
+        ];
+
+        for (text, state, country) in cases {
+            let location = Location::from(text);
+            assert_eq!(location.state, state, "{}", text);
+            assert_eq!(location.country, country, "{}", text);
+        }
+    }
+
+    #[test]
+    fn status_transitions() {
+        use StatusType::{
+            Completed,
+            Failed,
+            Filtered,
+            InProgress,
+            NeedsAttention,
+            Pending,
+            Skipped,
+            Submitted,
+        };
+
+        const ALL: [StatusType; 8] = [
+            Pending,
+            InProgress,
+            Completed,
+            Failed,
+            Skipped,
+            Filtered,
+            NeedsAttention,
+            Submitted,
+        ];
+
+        // Where each status may go, besides staying put
+        let cases: [(StatusType, &[StatusType]); 8] = [
+            (Pending, &[InProgress, Completed, Failed, Skipped, Filtered]),
+            (
+                InProgress,
+                &[
+                    Pending,
+                    Submitted,
+                    Completed,
+                    Failed,
+                    NeedsAttention,
+                    Skipped,
+                    Filtered,
+                ],
+            ),
+            (Completed, &[]),
+            (Submitted, &[Completed]),
+            (Failed, &[Pending, InProgress, Completed, Skipped, Filtered]),
+            (Skipped, &[Pending, InProgress, Completed, Failed, Filtered]),
+            (Filtered, &[Pending, InProgress, Completed, Failed, Skipped]),
+            (NeedsAttention, &[Pending, InProgress, Completed, Failed, Skipped, Filtered]),
+        ];
+
+        for (from, allowed) in cases {
+            for to in ALL {
+                let legal = from == to || allowed.contains(&to);
+                assert_eq!(from.can_transition_to(to), legal, "{:?} -> {:?}", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn status_strings_round_trip() {
+        for status in [
+            StatusType::Pending,
+            StatusType::InProgress,
+            StatusType::Completed,
+            StatusType::Failed,
+            StatusType::Skipped,
+            StatusType::Filtered,
+            StatusType::NeedsAttention,
+            StatusType::Submitted,
+        ] {
+            assert_eq!(<&str>::from(status).parse::<StatusType>().unwrap(), status);
+        }
+
+        assert!(matches!("archived".parse::<StatusType>(), Err(DatabaseError::Read(_))));
+    }
+}
@@ -0,0 +1,310 @@
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+
+const POSTED_ON_PREFIX: &str = "Posted on ";
+
+/// Absolute posting dates as the providers render them, in the browser's (local) timezone.
+const DATETIME_FORMATS: [&str; 4] = [
+    "%B %d, %Y, %I:%M %p",   // January 19, 2026, 10:43 AM
+    "%B %d, %Y at %I:%M %p", // January 19, 2026 at 10:43 AM
+    "%b %d, %Y, %I:%M %p",   // Jan 19, 2026, 10:43 AM
+    "%Y-%m-%d %H:%M:%S",     // 2026-01-19 10:43:00
+];
+
+const DATE_FORMATS: [&str; 5] = [
+    "%B %d, %Y", // January 19, 2026
+    "%b %d, %Y", // Jan 19, 2026
+    "%d %B %Y",  // 19 January 2026
+    "%d %b %Y",  // 19 Jan 2026
+    "%Y-%m-%d",  // 2026-01-19
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct DateInfo {
+    pub full_date:  String, // January 19, 2026, 10:43 AM, with the 'Posted on' stripped
+    pub relative:   String, // 1 week ago
+    /// When the job was posted, from `full_date` when there is one, otherwise
+    /// worked out from `relative` and `scraped_at`. `None` if neither could be parsed.
+    pub posted_at:  Option<DateTime<Utc>>,
+    /// When the card was read, what `relative` is relative to.
+    pub scraped_at: DateTime<Utc>,
+}
+
+impl DateInfo {
+    /// How many whole days ago the job was posted, `None` when the posting date is unknown.
+    pub fn age_days(&self) -> Option<u32> {
+        let age = Utc::now().signed_duration_since(self.posted_at?);
+        Some(u32::try_from(age.num_days()).unwrap_or(0))
+    }
+}
+
+/// Parses the posting date lines of a job card read just now.
+pub fn parse_date(full: &str, relative: &str) -> DateInfo {
+    parse_date_at(full, relative, Utc::now())
+}
+
+/// Parses the posting date lines of a job card read at `scraped_at`.
+///
+/// The absolute date is preferred as it's precise to the minute,
+/// the relative one (`1 week ago`) is only as precise as its unit.
+pub fn parse_date_at(full: &str, relative: &str, scraped_at: DateTime<Utc>) -> DateInfo {
+    let full_date = full
+        .trim()
+        .trim_start_matches(POSTED_ON_PREFIX)
+        .trim()
+        .to_string();
+    let relative = relative.trim().to_string();
+
+    let posted_at =
+        parse_absolute_date(&full_date).or_else(|| parse_relative_date(&relative, scraped_at));
+
+    if posted_at.is_none() && !(full_date.is_empty() && relative.is_empty()) {
+        tracing::debug!("Couldn't parse posting date from '{}' / '{}'", full_date, relative);
+    }
+
+    DateInfo {
+        full_date,
+        relative,
+        posted_at,
+        scraped_at,
+    }
+}
+
+/// `January 19, 2026, 10:43 AM` and friends.
+///
+/// Dates without an offset are in the browser's timezone, which is this machine's.
+/// Dates without a time are taken as midnight.
+pub fn parse_absolute_date(text: &str) -> Option<DateTime<Utc>> {
+    let text = text.trim();
+    if text.is_empty() {
+        return None;
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    let naive = DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            DATE_FORMATS
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })?;
+
+    // `earliest` picks a side when a DST change makes the local time ambiguous;
+    // a time skipped over by DST has no local equivalent, so fall back to reading it as UTC.
+    Some(
+        Local
+            .from_local_datetime(&naive)
+            .earliest()
+            .map(|dt| dt.with_timezone(&Utc))
+            .unwrap_or_else(|| naive.and_utc()),
+    )
+}
+
+/// Whether a card line is a relative posting date, see `parse_relative_date`.
+pub fn is_relative_date(line: &str) -> bool {
+    let lower = line.to_lowercase();
+    lower.ends_with(" ago") || lower == "just now" || lower == "today" || lower == "yesterday"
+}
+
+/// `3 days ago`, `2w ago`, `30+ days ago`, `Reposted an hour ago`, `Just now`, etc.
+/// counted back from `scraped_at`.
+pub fn parse_relative_date(text: &str, scraped_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let text = text.trim().to_lowercase();
+
+    match text.as_str() {
+        "" => return None,
+        "just now" | "today" => return Some(scraped_at),
+        "yesterday" => return scraped_at.checked_sub_signed(Duration::days(1)),
+        _ => {}
+    }
+
+    let text = text.strip_suffix(" ago")?.trim();
+    let mut words = text.split_whitespace().rev();
+
+    // Either `3 days` / `an hour` or `3d` / `30+d`, possibly after a `Reposted`/`Posted`
+    let last = words.next()?;
+    let (amount, unit) = match last.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(0) => (words.next()?, last),
+        Some(split) => last.split_at(split),
+        None => return None,
+    };
+
+    let amount: i64 = match amount.trim_end_matches('+') {
+        "a" | "an" | "one" => 1,
+        digits => digits.parse().ok()?,
+    };
+
+    // Seek writes `30d+ ago`, plurals are dropped so `mins`/`hrs`/`days` line up with `min`/`hr`/`day`
+    let unit = unit.trim_end_matches('+');
+    let unit = unit
+        .strip_suffix('s')
+        .filter(|u| !u.is_empty())
+        .unwrap_or(unit);
+
+    let duration = match unit {
+        "s" | "sec" | "second" => Duration::try_seconds(amount),
+        "m" | "min" | "minute" => Duration::try_minutes(amount),
+        "h" | "hr" | "hour" => Duration::try_hours(amount),
+        "d" | "day" => Duration::try_days(amount),
+        "w" | "wk" | "week" => Duration::try_weeks(amount),
+        "mo" | "month" => Duration::try_days(amount.checked_mul(30)?),
+        "y" | "yr" | "year" => Duration::try_days(amount.checked_mul(365)?),
+        _ => None,
+    }?;
+
+    scraped_at.checked_sub_signed(duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// When the cards in these tests were read.
+    fn scraped_at() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 2, 1, 12, 0, 0).unwrap()
+    }
+
+    /// January 19, 2026 at `hour:minute` local time, as UTC.
+    fn local(hour: u32, minute: u32) -> DateTime<Utc> {
+        Local
+            .with_ymd_and_hms(2026, 1, 19, hour, minute, 0)
+            .earliest()
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    #[test]
+    fn every_datetime_format() {
+        let samples = [
+            "January 19, 2026, 10:43 AM",
+            "January 19, 2026 at 10:43 AM",
+            "Jan 19, 2026, 10:43 AM",
+            "2026-01-19 10:43:00",
+        ];
+        assert_eq!(samples.len(), DATETIME_FORMATS.len());
+
+        for (format, text) in DATETIME_FORMATS.iter().zip(samples) {
+            assert!(NaiveDateTime::parse_from_str(text, format).is_ok(), "{} / {}", format, text);
+            assert_eq!(parse_absolute_date(text), Some(local(10, 43)), "{}", text);
+        }
+    }
+
+    #[test]
+    fn every_date_format() {
+        let samples = [
+            "January 19, 2026",
+            "Jan 19, 2026",
+            "19 January 2026",
+            "19 Jan 2026",
+            "2026-01-19",
+        ];
+        assert_eq!(samples.len(), DATE_FORMATS.len());
+
+        for (format, text) in DATE_FORMATS.iter().zip(samples) {
+            assert!(NaiveDate::parse_from_str(text, format).is_ok(), "{} / {}", format, text);
+            assert_eq!(parse_absolute_date(text), Some(local(0, 0)), "{}", text);
+        }
+    }
+
+    #[test]
+    fn absolute_dates() {
+        assert_eq!(
+            parse_absolute_date("2026-01-19T10:43:00+11:00"),
+            Some(Utc.with_ymd_and_hms(2026, 1, 18, 23, 43, 0).unwrap())
+        );
+        assert_eq!(parse_absolute_date("  January 19, 2026  "), Some(local(0, 0)));
+        assert_eq!(parse_absolute_date(""), None);
+        assert_eq!(parse_absolute_date("last Tuesday"), None);
+    }
+
+    #[test]
+    fn relative_dates() {
+        let cases = [
+            ("30+ days ago", Some(Duration::days(30))),
+            ("30d+ ago", Some(Duration::days(30))),
+            ("2w ago", Some(Duration::weeks(2))),
+            ("Reposted 2 weeks ago", Some(Duration::weeks(2))),
+            ("an hour ago", Some(Duration::hours(1))),
+            ("Reposted an hour ago", Some(Duration::hours(1))),
+            ("1 week ago", Some(Duration::weeks(1))),
+            ("3 days ago", Some(Duration::days(3))),
+            ("2d ago", Some(Duration::days(2))),
+            ("5 mins ago", Some(Duration::minutes(5))),
+            ("12h ago", Some(Duration::hours(12))),
+            ("45 seconds ago", Some(Duration::seconds(45))),
+            ("1 month ago", Some(Duration::days(30))),
+            ("a year ago", Some(Duration::days(365))),
+            ("Just now", Some(Duration::zero())),
+            ("Today", Some(Duration::zero())),
+            ("Yesterday", Some(Duration::days(1))),
+            ("", None),
+            ("ago", None),
+            ("some days ago", None),
+            ("3 fortnights ago", None),
+            ("Posted on January 19, 2026", None),
+        ];
+
+        for (text, age) in cases {
+            assert_eq!(
+                parse_relative_date(text, scraped_at()),
+                age.map(|age| scraped_at() - age),
+                "{}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn relative_date_lines() {
+        for line in [
+            "1 week ago",
+            "30d+ ago",
+            "Reposted 2 weeks ago",
+            "Just now",
+            "yesterday",
+        ] {
+            assert!(is_relative_date(line), "{}", line);
+        }
+        for line in [
+            "Viewed",
+            "Easy Apply",
+            "Posted on January 19, 2026, 10:43 AM",
+            "Agoda",
+        ] {
+            assert!(!is_relative_date(line), "{}", line);
+        }
+    }
+
+    #[test]
+    fn full_date_is_preferred() {
+        let date =
+            parse_date_at("Posted on January 19, 2026, 10:43 AM", "1 week ago", scraped_at());
+
+        assert_eq!(date.full_date, "January 19, 2026, 10:43 AM");
+        assert_eq!(date.relative, "1 week ago");
+        assert_eq!(date.posted_at, Some(local(10, 43)));
+        assert_eq!(date.scraped_at, scraped_at());
+    }
+
+    #[test]
+    fn relative_date_is_the_fallback() {
+        let date = parse_date_at("Posted on sometime", "2w ago", scraped_at());
+        assert_eq!(date.posted_at, Some(scraped_at() - Duration::weeks(2)));
+
+        let date = parse_date_at("", "2w ago", scraped_at());
+        assert_eq!(date.full_date, "");
+        assert_eq!(date.posted_at, Some(scraped_at() - Duration::weeks(2)));
+    }
+
+    #[test]
+    fn unknown_date() {
+        let date = parse_date_at("", "", scraped_at());
+
+        assert_eq!(date.posted_at, None);
+        assert_eq!(date.age_days(), None);
+    }
+}
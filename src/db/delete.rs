@@ -1 +1,244 @@
+use sqlx::{Sqlite, SqlitePool, Transaction};
 
+use crate::db::data::{JobQuery, Page};
+use crate::db::read::find_jobs;
+use crate::db::retry::retry_busy;
+use crate::error::DatabaseError;
+use crate::prelude::{Error, Result};
+
+/// Removes a question from the unanswered list, once an autofill answer exists for it.
+pub async fn delete_unanswered_question(pool: &SqlitePool, key: &str) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!("DELETE FROM unanswered_question WHERE input_key = ?", key).execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Delete(format!(
+            "Failed to delete unanswered question for key {}: {}",
+            key, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Removes a stored autofill answer, returning whether there was one to remove.
+pub async fn delete_autofill(pool: &SqlitePool, key: &str) -> Result<bool> {
+    retry_busy(|| sqlx::query!("DELETE FROM autofill WHERE input_key = ?", key).execute(pool))
+        .await
+        .map_err(|e| {
+            Error::DatabaseError(DatabaseError::Delete(format!(
+                "Failed to delete autofill for key {}: {}",
+                key, e
+            )))
+        })
+        .map(|res| res.rows_affected() > 0)
+}
+
+/// Clears the rejections recorded for a job, before its filters are checked again.
+pub async fn delete_job_rejections(pool: &SqlitePool, job_id: i64) -> Result<()> {
+    retry_busy(|| sqlx::query!("DELETE FROM job_rejection WHERE job_fk = ?", job_id).execute(pool))
+        .await
+        .map_err(|e| {
+            Error::DatabaseError(DatabaseError::Delete(format!(
+                "Failed to delete rejections for job_id {}: {}",
+                job_id, e
+            )))
+        })
+        .map(|_| ())
+}
+
+/// Removes a stored job along with everything recorded against it (rejections, description,
+/// dry runs, status history), returning whether there was a job to remove.
+pub async fn delete_job_by_id(pool: &SqlitePool, job_id: i64) -> Result<bool> {
+    let mut tx = pool.begin().await.map_err(|e| delete_error(job_id, e))?;
+    let deleted = delete_job_rows(&mut tx, job_id).await?;
+    tx.commit().await.map_err(|e| delete_error(job_id, e))?;
+    Ok(deleted)
+}
+
+/// Removes every stored job matching the query (its order is ignored) the same way as
+/// `delete_job_by_id`, all or nothing. Returns how many jobs were removed.
+pub async fn delete_jobs(pool: &SqlitePool, query: &JobQuery) -> Result<u64> {
+    let job_ids: Vec<i64> = find_jobs(pool, query, Page::ALL)
+        .await?
+        .into_iter()
+        .map(|job| job.id)
+        .collect();
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        Error::DatabaseError(DatabaseError::Delete(format!("Failed to delete jobs: {}", e)))
+    })?;
+
+    let mut deleted = 0;
+    for job_id in job_ids {
+        if delete_job_rows(&mut tx, job_id).await? {
+            deleted += 1;
+        }
+    }
+
+    tx.commit().await.map_err(|e| {
+        Error::DatabaseError(DatabaseError::Delete(format!("Failed to delete jobs: {}", e)))
+    })?;
+    Ok(deleted)
+}
+
+/// Deletes the job's row and the rows referencing it, children first.
+async fn delete_job_rows(tx: &mut Transaction<'_, Sqlite>, job_id: i64) -> Result<bool> {
+    sqlx::query!("DELETE FROM job_rejection WHERE job_fk = ?", job_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| delete_error(job_id, e))?;
+
+    sqlx::query!("DELETE FROM job_description WHERE job_fk = ?", job_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| delete_error(job_id, e))?;
+
+    sqlx::query!("DELETE FROM dry_run_submission WHERE job_fk = ?", job_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| delete_error(job_id, e))?;
+
+    sqlx::query!("DELETE FROM job_status_history WHERE job_fk = ?", job_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| delete_error(job_id, e))?;
+
+    // Runs only point at the job as how far they got, they're kept
+    sqlx::query!("UPDATE run_progress SET last_job_fk = NULL WHERE last_job_fk = ?", job_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| delete_error(job_id, e))?;
+
+    sqlx::query!("DELETE FROM jobs WHERE id = ?", job_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| delete_error(job_id, e))
+        .map(|res| res.rows_affected() > 0)
+}
+
+fn delete_error(job_id: i64, e: sqlx::Error) -> Error {
+    Error::DatabaseError(DatabaseError::Delete(format!(
+        "Failed to delete job_id {}: {}",
+        job_id, e
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::{store_job, test_pool};
+    use crate::db::{
+        JobDescriptionData,
+        StatusType,
+        create_dry_run_submission,
+        create_job_description,
+        create_job_rejection,
+        create_run_progress,
+        get_job_by_id,
+        get_job_status_history,
+        update_run_progress,
+    };
+    use crate::providers::ProviderKind;
+
+    /// How many rows of `table` point at the job.
+    async fn rows(pool: &SqlitePool, table: &str, job_id: i64) -> i64 {
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM {} WHERE job_fk = ?", table))
+            .bind(job_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    /// A job with something recorded in every table that references it.
+    async fn job_with_records(pool: &SqlitePool, company: &str) -> i64 {
+        let job_id = store_job(pool, ProviderKind::LinkedIn, "Rust Engineer", company, &[]).await;
+        create_job_rejection(pool, job_id, "company_blocked", company)
+            .await
+            .unwrap();
+        create_job_description(pool, job_id, &JobDescriptionData::default())
+            .await
+            .unwrap();
+        create_dry_run_submission(pool, job_id, "[]", None)
+            .await
+            .unwrap();
+        job_id
+    }
+
+    const CHILD_TABLES: [&str; 4] = [
+        "job_rejection",
+        "job_description",
+        "dry_run_submission",
+        "job_status_history",
+    ];
+
+    #[tokio::test]
+    async fn delete_job_removes_its_records() {
+        let pool = test_pool().await;
+        let deleted = job_with_records(&pool, "Acme").await;
+        let kept = job_with_records(&pool, "Globex").await;
+
+        let run_id = create_run_progress(&pool, ProviderKind::LinkedIn, "{}")
+            .await
+            .unwrap();
+        update_run_progress(&pool, run_id, 1, 2, Some(deleted))
+            .await
+            .unwrap();
+
+        for table in CHILD_TABLES {
+            assert_eq!(rows(&pool, table, deleted).await, 1, "{}", table);
+        }
+
+        assert!(delete_job_by_id(&pool, deleted).await.unwrap());
+        assert!(!delete_job_by_id(&pool, deleted).await.unwrap());
+
+        assert!(get_job_by_id(&pool, deleted).await.unwrap().is_none());
+        for table in CHILD_TABLES {
+            assert_eq!(rows(&pool, table, deleted).await, 0, "{}", table);
+            assert_eq!(rows(&pool, table, kept).await, 1, "{}", table);
+        }
+
+        // The run is kept, it just no longer points at the job
+        let last_job: Option<i64> =
+            sqlx::query_scalar("SELECT last_job_fk FROM run_progress WHERE id = ?")
+                .bind(run_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(last_job, None);
+    }
+
+    #[tokio::test]
+    async fn delete_jobs_by_query() {
+        let pool = test_pool().await;
+        let acme = job_with_records(&pool, "Acme").await;
+        let acme_corp = job_with_records(&pool, "ACME Corp").await;
+        let globex = job_with_records(&pool, "Globex").await;
+
+        let query = JobQuery {
+            company: Some("acme".to_string()),
+            ..JobQuery::default()
+        };
+        assert_eq!(delete_jobs(&pool, &query).await.unwrap(), 2);
+
+        for job_id in [acme, acme_corp] {
+            assert!(get_job_by_id(&pool, job_id).await.unwrap().is_none());
+            assert!(
+                get_job_status_history(&pool, job_id)
+                    .await
+                    .unwrap()
+                    .is_empty()
+            );
+        }
+        assert!(get_job_by_id(&pool, globex).await.unwrap().is_some());
+
+        // Nothing left to match
+        assert_eq!(delete_jobs(&pool, &query).await.unwrap(), 0);
+        assert_eq!(
+            delete_jobs(&pool, &JobQuery::with_status(StatusType::Failed))
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -1,88 +1,413 @@
 mod create;
 mod data;
+mod dates;
 mod delete;
 mod read;
+mod retry;
+#[cfg(test)]
+pub(crate) mod testing;
 mod update;
 
+use std::str::FromStr;
 use std::sync::Arc;
 
 use fantoccini::Client;
 use fantoccini::elements::Element;
-use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::migrate::MigrateError;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 use sqlx::{SqlitePool, migrate};
+use tokio::time::Duration;
 
 use crate::config::DatabaseConfig;
-pub use crate::db::create::{create_autofill, create_job};
-use crate::db::data::JobCardData;
-pub use crate::db::read::get_autofill;
-pub use crate::db::update::{update_autofill, update_job_status};
-use crate::prelude::Result;
-use crate::providers::{Provider, SelectorKind};
+pub use crate::db::create::{
+    create_autofill,
+    create_dry_run_submission,
+    create_job,
+    create_job_description,
+    create_job_rejection,
+    create_run_progress,
+    create_unanswered_question,
+};
+pub use crate::db::data::{
+    AutofillEntry,
+    DryRunRecord,
+    EmploymentType,
+    JobCardData,
+    JobDescriptionData,
+    JobDescriptionRecord,
+    JobOrder,
+    JobQuery,
+    JobRecord,
+    JobRejection,
+    Page,
+    RunProgress,
+    StatusChange,
+    StatusCount,
+    StatusType,
+    UnansweredQuestion,
+    WorkType,
+};
+pub use crate::db::delete::{
+    delete_autofill,
+    delete_job_by_id,
+    delete_job_rejections,
+    delete_jobs,
+    delete_unanswered_question,
+};
+pub use crate::db::read::{
+    count_applications_since,
+    count_jobs,
+    count_jobs_by_status,
+    find_jobs,
+    get_all_autofill,
+    get_all_jobs,
+    get_autofill,
+    get_dry_run_submissions,
+    get_job_by_id,
+    get_job_description,
+    get_job_id_by_provider_key,
+    get_job_rejections,
+    get_job_status,
+    get_job_status_history,
+    get_jobs_by_company,
+    get_jobs_by_provider,
+    get_jobs_by_status,
+    get_jobs_posted_between,
+    get_pending_jobs,
+    get_unanswered_questions,
+    get_unfinished_run,
+    search_job_descriptions,
+};
+pub use crate::db::update::{
+    update_autofill,
+    update_job_card,
+    update_job_details,
+    update_job_failure_reason,
+    update_job_skip_reason,
+    update_job_status,
+    update_run_finished,
+    update_run_progress,
+};
+use crate::error::{DatabaseError, JobCardError};
+use crate::prelude::*;
+use crate::providers::{Provider, ProviderKind, SelectorKind};
+use crate::selectors::{JobListingSelectors, SelectorField};
 
-// pub use crate::db::read::{get_job_by_id, get_jobs_by_status, get_all_jobs};
-// pub use crate::db::delete::delete_job_by_id;
-
-pub(crate) async fn setup_db(db_config: DatabaseConfig) -> SqlitePool {
+/// Connects to the database and brings it up to date with the migrations.
+///
+/// A migration that fails is reported by its version (the `migrations/` file to look at)
+/// and description.
+pub(crate) async fn setup_db(db_config: DatabaseConfig) -> Result<SqlitePool> {
     let db_uri = db_config.database_uri.clone(); // clone before move occurs
+    let busy_timeout = Duration::from_secs(db_config.busy_timeout_secs);
 
+    let connect_options = SqliteConnectOptions::from_str(&db_uri)
+        .map_err(|e| connect_error(&db_uri, e))?
+        .busy_timeout(busy_timeout);
     let sqlite_options: SqlitePoolOptions = db_config.into();
 
     let pool = sqlite_options
-        .connect(db_uri.as_str())
+        .connect_with(connect_options)
         .await
-        .expect("Failed to create SQLite connection pool");
+        .map_err(|e| connect_error(&db_uri, e))?;
 
-    migrate!("./migrations")
-        .run(&pool)
-        .await
-        .expect("Failed to run database migrations");
+    let migrator = migrate!("./migrations");
+    migrator.run(&pool).await.map_err(|e| {
+        let reason = match &e {
+            MigrateError::ExecuteMigration(_, version)
+            | MigrateError::VersionMissing(version)
+            | MigrateError::VersionMismatch(version)
+            | MigrateError::VersionNotPresent(version)
+            | MigrateError::VersionTooOld(version, _)
+            | MigrateError::VersionTooNew(version, _)
+            | MigrateError::Dirty(version) => {
+                match migrator.iter().find(|m| m.version == *version) {
+                    Some(migration) => format!("{} ({})", e, migration.description),
+                    None => e.to_string(),
+                }
+            }
+            _ => e.to_string(),
+        };
+        Error::DatabaseError(DatabaseError::Migration(reason))
+    })?;
+
+    Ok(pool)
+}
 
-    pool
+fn connect_error(db_uri: &str, e: sqlx::Error) -> Error {
+    Error::DatabaseError(DatabaseError::Connect(format!("Failed to open {}: {}", db_uri, e)))
 }
 
 // TODO: [refactor] : Probably better suited for the selectors/ module tbh?
 
-async fn extract_job_card(
-    //
-    client: &Client,
+/// Reads the visible text of a single job card element and parses it into a `JobCardData`.
+///
+/// When the card carries the provider's own job id (`JobListingSelectors::job_id_attribute`)
+/// that is used as the `provider_job_id` (and for the `job_url`),
+/// otherwise the fingerprint from the card text is kept.
+pub(crate) async fn extract_job_card(
     element: &Element,
+    provider: &(dyn Provider + Send + Sync),
+    selectors: &JobListingSelectors,
     idx: usize,
 ) -> Result<JobCardData> {
-    let card_title = element
-        .text()
-        .await
-        .map_err(|e| {
-            crate::prelude::Error::Generic(format!(
-                "Failed to extract card title text for job card idx {}: {}",
-                idx, e
-            ))
-        })?
-        .replace('·', "")
-        .trim()
-        .to_string();
-    // let job_title = element.
-
-    todo!()
-}
+    let card_text = element.text().await.map_err(|e| {
+        JobCardError::ElementText {
+            idx,
+            reason: e.to_string(),
+        }
+    })?;
+
+    let mut card = match provider.name() {
+        ProviderKind::LinkedIn => JobCardData::from_card_text(idx, &card_text)?,
+        ProviderKind::Seek => JobCardData::from_seek_card_text(idx, &card_text)?,
+    };
+
+    match element.attr(selectors.job_id_attribute).await {
+        Ok(Some(job_id)) if !job_id.trim().is_empty() => {
+            card.provider_job_id = job_id.trim().to_string();
+            card.job_url = Some(provider.job_url(&card.provider_job_id));
+        }
+        Ok(_) => {
+            debug!(
+                "Job card idx {} has no '{}' attribute, using fingerprint {}",
+                idx, selectors.job_id_attribute, card.provider_job_id
+            );
+        }
+        Err(e) => {
+            warn!(
+                "Failed to read '{}' attribute on job card idx {}: {}",
+                selectors.job_id_attribute, idx, e
+            );
+        }
+    }
 
-// TODO: [same_fn_1] : See associated comment
+    Ok(card)
+}
 
-async fn get_job_cards(
+/// Finds every job card on the current page (via the `job_card` fallback chain)
+/// and extracts each of them with the same kind of selectors the cards were found by.
+///
+/// Cards that fail to parse are logged and skipped, so one odd card
+/// doesn't throw away the rest of the page.
+/// Each card is returned alongside its element so it can be opened later on.
+pub(crate) async fn get_job_cards(
     client: &Client,
     provider: Arc<dyn Provider + Send + Sync>,
-    selector_kind: SelectorKind,
-) -> Vec<JobCardData> {
-    //
-    let job_card_selectors = provider.get_job_listing_selectors(selector_kind);
+) -> Result<Vec<(Element, JobCardData)>> {
     let job_cards = provider
-        .with_elements(client, selector_kind, job_card_selectors.job_card)
-        .await
-        .unwrap_or_default();
-    let mut cards = vec![];
+        .find_all_field(client, SelectorField::job_listing("job_card"))
+        .await?;
+    let job_card_selectors = provider.get_job_listing_selectors(job_cards.kind());
+    let job_cards = job_cards.into_inner();
+
+    let mut cards = Vec::with_capacity(job_cards.len());
+
+    for (idx, elem) in job_cards.into_iter().enumerate() {
+        match extract_job_card(&elem, provider.as_ref(), &job_card_selectors, idx).await {
+            Ok(card) => cards.push((elem, card)),
+            Err(e) => warn!("Skipping job card: {}", e),
+        }
+    }
+
+    Ok(cards)
+}
+
+/// Outcome of `save_job_card`, carrying the row id of the job either way.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JobUpsert {
+    Inserted(i64),
+    Updated(i64),
+}
+
+impl JobUpsert {
+    pub fn job_id(&self) -> i64 {
+        match self {
+            JobUpsert::Inserted(id) | JobUpsert::Updated(id) => *id,
+        }
+    }
+}
+
+/// Writes a scanned card into the `jobs` table.
+///
+/// Jobs are keyed by provider + `provider_job_id`, a card we've already seen
+/// has its details refreshed instead of being inserted a second time.
+/// A card stored under its fingerprint before (its job id wasn't readable then)
+/// is found by that, and the row takes on the provider's job id.
+pub(crate) async fn save_job_card(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    card: &JobCardData,
+) -> Result<JobUpsert> {
+    let mut existing = get_job_id_by_provider_key(pool, provider, &card.provider_job_id).await?;
 
-    for (idx, elem) in job_cards.iter().enumerate() {
-        cards.push(extract_job_card(client, elem, idx).await.unwrap());
+    let fingerprint = card.fingerprint();
+    if existing.is_none() && card.provider_job_id != fingerprint {
+        existing = get_job_id_by_provider_key(pool, provider, &fingerprint).await?;
+        if let Some(job_id) = existing {
+            debug!(
+                "Job {} was stored as {}, it's {} {} now",
+                job_id, fingerprint, provider, card.provider_job_id
+            );
+        }
     }
 
-    cards
+    match existing {
+        Some(job_id) => {
+            update_job_card(pool, job_id, card).await?;
+            Ok(JobUpsert::Updated(job_id))
+        }
+        None => {
+            create_job(pool, provider, card)
+                .await
+                .map(JobUpsert::Inserted)
+        }
+    }
+}
+
+/// Marks a job as failed with the error that stopped it, so one bad job (ie: a write that
+/// failed part way through) is left for a look later on while the run moves on to the next.
+///
+/// Only logs when the job can't be marked either, the run carries on regardless.
+pub(crate) async fn mark_job_failed(pool: &SqlitePool, job_id: i64, error: &Error) {
+    let reason = error.to_string();
+
+    let marked = match update_job_failure_reason(pool, job_id, Some(&reason)).await {
+        Ok(()) => update_job_status(pool, job_id, StatusType::Failed, Some(&reason)).await,
+        Err(e) => Err(e),
+    };
+
+    if let Err(e) = marked {
+        error!("Failed to mark job {} as failed: {}", job_id, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fantoccini::ClientBuilder;
+
+    use super::*;
+    use crate::db::testing::test_pool;
+    use crate::providers::get_provider;
+
+    fn linkedin_card(job_title: &str, job_id: Option<&str>) -> JobCardData {
+        let text = format!("{0}\n{0}\nAcme Pty Ltd\nSydney, NSW (Hybrid)\n1 week ago", job_title);
+        let mut card = JobCardData::from_card_text(0, &text).unwrap();
+        if let Some(job_id) = job_id {
+            card.provider_job_id = job_id.to_string();
+            card.job_url = Some(format!("https://www.linkedin.com/jobs/view/{}/", job_id));
+        }
+        card
+    }
+
+    async fn stored_key(pool: &SqlitePool, job_id: i64) -> (String, Option<String>) {
+        sqlx::query_as("SELECT provider_job_id, job_url FROM jobs WHERE id = ?")
+            .bind(job_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn fingerprinted_job_takes_on_the_provider_id() {
+        let pool = test_pool().await;
+        let linkedin = ProviderKind::LinkedIn;
+
+        // First seen without its job id
+        let fingerprinted = linkedin_card("Rust Engineer", None);
+        let job_id = save_job_card(&pool, linkedin, &fingerprinted)
+            .await
+            .unwrap();
+        let JobUpsert::Inserted(job_id) = job_id else {
+            panic!("Expected a new job, got {:?}", job_id);
+        };
+        assert_eq!(stored_key(&pool, job_id).await, (fingerprinted.fingerprint(), None));
+        update_job_status(&pool, job_id, StatusType::Skipped, Some("filtered"))
+            .await
+            .unwrap();
+
+        // Then with it, the same row is updated in place
+        let card = linkedin_card("Rust Engineer", Some("4012345678"));
+        for case in ["fingerprint stored", "real id stored"] {
+            let upsert = save_job_card(&pool, linkedin, &card).await.unwrap();
+            assert_eq!(upsert, JobUpsert::Updated(job_id), "{}", case);
+        }
+        assert_eq!(
+            stored_key(&pool, job_id).await,
+            (
+                "4012345678".to_string(),
+                Some("https://www.linkedin.com/jobs/view/4012345678/".to_string())
+            )
+        );
+        assert_eq!(get_job_status(&pool, job_id).await.unwrap(), Some(StatusType::Skipped));
+
+        // Other jobs, and the same job on another provider, are still their own rows
+        let other = linkedin_card("Go Engineer", Some("4099999999"));
+        assert!(matches!(
+            save_job_card(&pool, linkedin, &other).await.unwrap(),
+            JobUpsert::Inserted(id) if id != job_id
+        ));
+        assert!(matches!(
+            save_job_card(&pool, ProviderKind::Seek, &fingerprinted).await.unwrap(),
+            JobUpsert::Inserted(id) if id != job_id
+        ));
+
+        let jobs: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM jobs")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(jobs, 3);
+    }
+
+    /// Reads `tests/fixtures/job_cards/linkedin_card.html` through a real browser, as the
+    /// element -> text step is the browser's own rendering of the card and can't be faked.
+    ///
+    /// Needs a WebDriver server on `WEBDRIVER_URL` (default `http://localhost:4444`),
+    /// ie: `geckodriver --port 4444` then `cargo test -- --ignored`.
+    #[tokio::test]
+    #[ignore = "needs a WebDriver server"]
+    async fn job_card_from_html_fixture() {
+        let url =
+            std::env::var("WEBDRIVER_URL").unwrap_or_else(|_| "http://localhost:4444".to_string());
+        let capabilities = serde_json::json!({
+            "moz:firefoxOptions": { "args": ["-headless"] },
+            "goog:chromeOptions": { "args": ["--headless=new"] },
+        });
+        let client = ClientBuilder::native()
+            .capabilities(capabilities.as_object().cloned().unwrap_or_default())
+            .connect(&url)
+            .await
+            .unwrap_or_else(|e| panic!("No WebDriver server at {}: {}", url, e));
+
+        let fixture = format!(
+            "file://{}/tests/fixtures/job_cards/linkedin_card.html",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let cards = match client.goto(&fixture).await {
+            Ok(()) => {
+                let provider = get_provider(ProviderKind::LinkedIn).expect("linkedin provider");
+                get_job_cards(&client, provider).await
+            }
+            Err(e) => Err(Error::Generic(format!("Failed to open {}: {}", fixture, e))),
+        };
+        client.close().await.ok();
+        let cards = cards.unwrap();
+
+        assert_eq!(cards.len(), 1);
+        let (_, card) = &cards[0];
+        assert_eq!(card.provider_job_id, "4012345678");
+        assert_eq!(card.job_url.as_deref(), Some("https://www.linkedin.com/jobs/view/4012345678/"));
+        assert_eq!(card.card_title, "Senior Rust Engineer (Verified job)");
+        assert_eq!(card.job_title, "Senior Rust Engineer");
+        assert_eq!(card.company_name, "Acme Pty Ltd");
+        assert_eq!(card.location.state, "Sydney, NSW");
+        assert_eq!(card.location.country, "");
+        assert_eq!(card.work_type, WorkType::Hybrid);
+        assert_eq!(card.salary.as_deref(), Some("$150,000 – $170,000 per year"));
+        assert_eq!(card.is_actively_reviewing, Some(true));
+        assert!(card.already_viewed);
+        assert!(card.has_easy_apply);
+        assert_eq!(card.posted_date.full_date, "January 12, 2026, 9:15 AM");
+        assert_eq!(card.posted_date.relative, "2 weeks ago");
+    }
 }
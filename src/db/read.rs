@@ -1,12 +1,740 @@
 use sqlx::SqlitePool;
+use sqlx::types::chrono::{DateTime, Utc};
 
+use crate::db::data::{
+    AutofillEntry,
+    DryRunRecord,
+    JobDescriptionRecord,
+    JobOrder,
+    JobQuery,
+    JobRecord,
+    JobRejection,
+    Page,
+    RunProgress,
+    StatusChange,
+    StatusCount,
+    StatusType,
+    UnansweredQuestion,
+};
 use crate::error::DatabaseError;
 use crate::prelude::{Error, Result};
+use crate::providers::ProviderKind;
 
-pub async fn get_autofill(pool: &SqlitePool, key: &str) -> Option<String> {
+/// The stored answer for a form field, `None` if there isn't one.
+pub async fn get_autofill(pool: &SqlitePool, key: &str) -> Result<Option<String>> {
     sqlx::query!("SELECT data FROM autofill where input_key = ?", key)
         .fetch_optional(pool)
         .await
-        .ok()
-        .and_then(|row| row.map(|r| r.data))
+        .map_err(|e| {
+            Error::DatabaseError(DatabaseError::Read(format!(
+                "Failed to read autofill data for key {}: {}",
+                key, e
+            )))
+        })
+        .map(|row| row.map(|r| r.data))
+}
+
+/// Looks up the row id of a job we've already stored, keyed by provider + the provider's job id.
+pub async fn get_job_id_by_provider_key(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    provider_job_id: &str,
+) -> Result<Option<i64>> {
+    let provider: &str = provider.into();
+    sqlx::query!(
+        r#"
+        SELECT id AS "id!" FROM jobs
+        WHERE provider = ? AND provider_job_id = ?
+        "#,
+        provider,
+        provider_job_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to look up job {} for provider {}: {}",
+            provider_job_id, provider, e
+        )))
+    })
+    .map(|row| row.map(|r| r.id))
+}
+
+/// Current status of a stored job, `None` if there's no job with that id.
+pub async fn get_job_status(pool: &SqlitePool, job_id: i64) -> Result<Option<StatusType>> {
+    sqlx::query!(
+        r#"
+        SELECT status_type.type AS status
+        FROM jobs
+        JOIN status_type ON status_type.id = jobs.status_type_fk
+        WHERE jobs.id = ?
+        "#,
+        job_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read job status for job_id {}: {}",
+            job_id, e
+        )))
+    })
+    .and_then(|row| {
+        row.map(|r| r.status.parse::<StatusType>())
+            .transpose()
+            .map_err(Error::DatabaseError)
+    })
+}
+
+/// All questions still waiting on an answer, most frequently seen first.
+pub async fn get_unanswered_questions(pool: &SqlitePool) -> Result<Vec<UnansweredQuestion>> {
+    sqlx::query_as!(
+        UnansweredQuestion,
+        r#"
+        SELECT input_key, label, field_kind, options, times_seen
+        FROM unanswered_question
+        ORDER BY times_seen DESC, last_seen DESC
+        "#
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read unanswered questions: {}",
+            e
+        )))
+    })
+}
+
+/// Every stored autofill answer, ordered by key.
+pub async fn get_all_autofill(pool: &SqlitePool) -> Result<Vec<AutofillEntry>> {
+    sqlx::query_as!(
+        AutofillEntry,
+        r#"
+        SELECT input_key, data
+        FROM autofill
+        ORDER BY input_key
+        "#
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!("Failed to read autofill data: {}", e)))
+    })
+}
+
+/// A single stored job, `None` if there's no job with that id.
+pub async fn get_job_by_id(pool: &SqlitePool, job_id: i64) -> Result<Option<JobRecord>> {
+    find_jobs(pool, &JobQuery::with_id(job_id), Page::first(1))
+        .await
+        .map(|jobs| jobs.into_iter().next())
+}
+
+/// Stored jobs matching the query, a page at a time.
+pub async fn find_jobs(pool: &SqlitePool, query: &JobQuery, page: Page) -> Result<Vec<JobRecord>> {
+    let status: Option<&str> = query.status.map(Into::into);
+    let provider: Option<&str> = query.provider.map(Into::into);
+    let by_posted = query.order == JobOrder::Posted;
+    let oldest_first = query.order == JobOrder::Oldest;
+    sqlx::query_as!(
+        JobRecord,
+        r#"
+        SELECT
+            jobs.id AS "id!",
+            jobs.provider,
+            jobs.provider_job_id,
+            jobs.job_title,
+            jobs.company_name,
+            jobs.state,
+            jobs.country,
+            work_type.type AS work_type,
+            jobs.has_easy_apply,
+            jobs.full_date,
+            jobs.relative_date,
+            jobs.posted_at AS "posted_at: DateTime<Utc>",
+            jobs.scraped_at AS "scraped_at: DateTime<Utc>",
+            status_type.type AS status,
+            jobs.skip_reason,
+            jobs.applied_date AS "applied_date: String",
+            jobs.job_url,
+            jobs.salary,
+            employment_type.type AS "employment_type?",
+            jobs.failure_reason,
+            jobs.discovered_at AS "discovered_at: DateTime<Utc>",
+            jobs.updated_at AS "updated_at: DateTime<Utc>"
+        FROM jobs
+        JOIN work_type ON work_type.id = jobs.work_type_fk
+        JOIN status_type ON status_type.id = jobs.status_type_fk
+        LEFT JOIN employment_type ON employment_type.id = jobs.employment_type_fk
+        WHERE (?1 IS NULL OR jobs.id = ?1)
+            AND (?2 IS NULL OR status_type.type = ?2)
+            AND (?3 IS NULL OR jobs.provider = ?3)
+            AND (?4 IS NULL OR instr(lower(jobs.company_name), lower(?4)) > 0)
+            AND (?5 IS NULL OR julianday(jobs.posted_at) >= julianday(?5))
+            AND (?6 IS NULL OR julianday(jobs.posted_at) < julianday(?6))
+            AND (?7 IS NULL OR EXISTS (
+                SELECT 1 FROM job_description
+                WHERE job_description.job_fk = jobs.id
+                    AND instr(lower(job_description.description_text), lower(?7)) > 0
+            ))
+            AND (NOT ?8 OR jobs.skip_reason IS NULL)
+        ORDER BY
+            CASE WHEN ?9 THEN julianday(jobs.posted_at) END DESC NULLS LAST,
+            CASE WHEN ?10 THEN jobs.id END ASC,
+            jobs.id DESC
+        LIMIT ?11 OFFSET ?12
+        "#,
+        query.id,
+        status,
+        provider,
+        query.company,
+        query.posted_after,
+        query.posted_before,
+        query.description,
+        query.not_skipped,
+        by_posted,
+        oldest_first,
+        page.limit,
+        page.offset
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| Error::DatabaseError(DatabaseError::Read(format!("Failed to list jobs: {}", e))))
+}
+
+/// How many stored jobs match the query, ignoring its order.
+pub async fn count_jobs(pool: &SqlitePool, query: &JobQuery) -> Result<i64> {
+    let status: Option<&str> = query.status.map(Into::into);
+    let provider: Option<&str> = query.provider.map(Into::into);
+    sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "count!: i64"
+        FROM jobs
+        JOIN status_type ON status_type.id = jobs.status_type_fk
+        WHERE (?1 IS NULL OR jobs.id = ?1)
+            AND (?2 IS NULL OR status_type.type = ?2)
+            AND (?3 IS NULL OR jobs.provider = ?3)
+            AND (?4 IS NULL OR instr(lower(jobs.company_name), lower(?4)) > 0)
+            AND (?5 IS NULL OR julianday(jobs.posted_at) >= julianday(?5))
+            AND (?6 IS NULL OR julianday(jobs.posted_at) < julianday(?6))
+            AND (?7 IS NULL OR EXISTS (
+                SELECT 1 FROM job_description
+                WHERE job_description.job_fk = jobs.id
+                    AND instr(lower(job_description.description_text), lower(?7)) > 0
+            ))
+            AND (NOT ?8 OR jobs.skip_reason IS NULL)
+        "#,
+        query.id,
+        status,
+        provider,
+        query.company,
+        query.posted_after,
+        query.posted_before,
+        query.description,
+        query.not_skipped
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| Error::DatabaseError(DatabaseError::Read(format!("Failed to count jobs: {}", e))))
+    .map(|row| row.count)
+}
+
+/// How many stored jobs are in each status, every status included even when it has none.
+pub async fn count_jobs_by_status(pool: &SqlitePool) -> Result<Vec<StatusCount>> {
+    sqlx::query_as!(
+        StatusCount,
+        r#"
+        SELECT status_type.type AS status, COUNT(jobs.id) AS "count!: i64"
+        FROM status_type
+        LEFT JOIN jobs ON jobs.status_type_fk = status_type.id
+        GROUP BY status_type.id
+        ORDER BY status_type.id
+        "#
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!("Failed to count jobs by status: {}", e)))
+    })
+}
+
+/// Stored jobs in the given status, newest first.
+pub async fn get_jobs_by_status(
+    pool: &SqlitePool,
+    status: StatusType,
+    page: Page,
+) -> Result<Vec<JobRecord>> {
+    find_jobs(pool, &JobQuery::with_status(status), page).await
+}
+
+/// Stored jobs found on the given provider, newest first.
+pub async fn get_jobs_by_provider(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    page: Page,
+) -> Result<Vec<JobRecord>> {
+    let query = JobQuery {
+        provider: Some(provider),
+        ..JobQuery::default()
+    };
+    find_jobs(pool, &query, page).await
+}
+
+/// Stored jobs at companies whose name contains `company` (case insensitive), newest first.
+pub async fn get_jobs_by_company(
+    pool: &SqlitePool,
+    company: &str,
+    page: Page,
+) -> Result<Vec<JobRecord>> {
+    let query = JobQuery {
+        company: Some(company.to_string()),
+        ..JobQuery::default()
+    };
+    find_jobs(pool, &query, page).await
+}
+
+/// Stored jobs posted in `[after, before)`, most recently posted first.
+pub async fn get_jobs_posted_between(
+    pool: &SqlitePool,
+    after: DateTime<Utc>,
+    before: DateTime<Utc>,
+    page: Page,
+) -> Result<Vec<JobRecord>> {
+    let query = JobQuery {
+        posted_after: Some(after),
+        posted_before: Some(before),
+        order: JobOrder::Posted,
+        ..JobQuery::default()
+    };
+    find_jobs(pool, &query, page).await
+}
+
+/// Every stored job, newest first.
+pub async fn get_all_jobs(pool: &SqlitePool, page: Page) -> Result<Vec<JobRecord>> {
+    find_jobs(pool, &JobQuery::default(), page).await
+}
+
+/// Jobs for the provider that are still waiting to be applied to,
+/// ie: found by a `scan` and not skipped during validation. Oldest first.
+pub async fn get_pending_jobs(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    limit: i64,
+) -> Result<Vec<JobRecord>> {
+    let query = JobQuery {
+        provider: Some(provider),
+        not_skipped: true,
+        order: JobOrder::Oldest,
+        ..JobQuery::with_status(StatusType::Pending)
+    };
+    find_jobs(pool, &query, Page::first(limit)).await
+}
+
+/// Every dry run recorded for the job, latest first.
+pub async fn get_dry_run_submissions(pool: &SqlitePool, job_id: i64) -> Result<Vec<DryRunRecord>> {
+    sqlx::query_as!(
+        DryRunRecord,
+        r#"
+        SELECT id AS "id!", answers, resume_path, created_at AS "created_at: String"
+        FROM dry_run_submission
+        WHERE job_fk = ?
+        ORDER BY id DESC
+        "#,
+        job_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read dry run submissions for job_id {}: {}",
+            job_id, e
+        )))
+    })
+}
+
+/// The filter rules the job failed the last time it was checked.
+pub async fn get_job_rejections(pool: &SqlitePool, job_id: i64) -> Result<Vec<JobRejection>> {
+    sqlx::query_as!(
+        JobRejection,
+        r#"
+        SELECT rule, detail
+        FROM job_rejection
+        WHERE job_fk = ?
+        ORDER BY id
+        "#,
+        job_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read rejections for job_id {}: {}",
+            job_id, e
+        )))
+    })
+}
+
+/// Every status the job went through, oldest first.
+pub async fn get_job_status_history(pool: &SqlitePool, job_id: i64) -> Result<Vec<StatusChange>> {
+    sqlx::query_as!(
+        StatusChange,
+        r#"
+        SELECT from_status.type AS "from_status?",
+            to_status.type AS to_status,
+            job_status_history.reason,
+            job_status_history.changed_at AS "changed_at: DateTime<Utc>"
+        FROM job_status_history
+        LEFT JOIN status_type AS from_status ON from_status.id = job_status_history.from_status_fk
+        JOIN status_type AS to_status ON to_status.id = job_status_history.to_status_fk
+        WHERE job_status_history.job_fk = ?
+        ORDER BY job_status_history.id
+        "#,
+        job_id
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read status history for job_id {}: {}",
+            job_id, e
+        )))
+    })
+}
+
+/// The job's stored description, `None` if it hasn't been scraped yet.
+pub async fn get_job_description(
+    pool: &SqlitePool,
+    job_id: i64,
+) -> Result<Option<JobDescriptionRecord>> {
+    sqlx::query_as!(
+        JobDescriptionRecord,
+        r#"
+        SELECT
+            job_fk AS "job_id!",
+            description_text,
+            description_html,
+            salary,
+            seniority_level,
+            employment_type,
+            scraped_at AS "scraped_at: String"
+        FROM job_description
+        WHERE job_fk = ?
+        "#,
+        job_id
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read job description for job_id {}: {}",
+            job_id, e
+        )))
+    })
+}
+
+/// Jobs whose stored description mentions `text` (case insensitive), newest first.
+pub async fn search_job_descriptions(
+    pool: &SqlitePool,
+    text: &str,
+    limit: i64,
+) -> Result<Vec<JobRecord>> {
+    let query = JobQuery {
+        description: Some(text.to_string()),
+        ..JobQuery::default()
+    };
+    find_jobs(pool, &query, Page::first(limit)).await
+}
+
+/// The provider's most recent run that didn't get to the end of its search, if any.
+pub async fn get_unfinished_run(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+) -> Result<Option<RunProgress>> {
+    let provider: &str = provider.into();
+    sqlx::query_as!(
+        RunProgress,
+        r#"
+        SELECT id AS "id!", provider, search_params, pages_done, jobs_done,
+            last_job_fk AS last_job_id,
+            started_at AS "started_at: DateTime<Utc>",
+            updated_at AS "updated_at: DateTime<Utc>"
+        FROM run_progress
+        WHERE provider = ? AND finished_at IS NULL
+        ORDER BY id DESC
+        LIMIT 1
+        "#,
+        provider
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!(
+            "Failed to read the last unfinished {} run: {}",
+            provider, e
+        )))
+    })
+}
+
+/// How many applications were sent since `since`, on `provider` when given.
+/// Counted from the jobs moving to `submitted` in `db.job_status_history`.
+pub async fn count_applications_since(
+    pool: &SqlitePool,
+    provider: Option<ProviderKind>,
+    since: DateTime<Utc>,
+) -> Result<i64> {
+    let provider: Option<&str> = provider.map(Into::into);
+    let submitted: &str = StatusType::Submitted.into();
+
+    sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "count!: i64"
+        FROM job_status_history
+        JOIN jobs ON jobs.id = job_status_history.job_fk
+        JOIN status_type ON status_type.id = job_status_history.to_status_fk
+        WHERE status_type.type = ?1
+            AND julianday(job_status_history.changed_at) >= julianday(?2)
+            AND (?3 IS NULL OR jobs.provider = ?3)
+        "#,
+        submitted,
+        since,
+        provider
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Read(format!("Failed to count applications: {}", e)))
+    })
+    .map(|row| row.count)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::db::testing::{store_job, test_pool};
+    use crate::db::{
+        JobDescriptionData,
+        create_job_description,
+        update_job_skip_reason,
+        update_job_status,
+    };
+
+    /// Ids of the jobs `seed` stores, oldest first.
+    struct Seeded {
+        rust:   i64,
+        go:     i64,
+        seek:   i64,
+        java:   i64,
+        failed: i64,
+    }
+
+    async fn seed(pool: &SqlitePool) -> Seeded {
+        let linkedin = ProviderKind::LinkedIn;
+        let seeded = Seeded {
+            rust:   store_job(pool, linkedin, "Rust Engineer", "Acme Pty Ltd", &["3 days ago"])
+                .await,
+            go:     store_job(pool, linkedin, "Go Developer", "Globex", &["10 days ago"]).await,
+            seek:   store_job(pool, ProviderKind::Seek, "Rust Developer", "ACME Corp", &[]).await,
+            java:   store_job(pool, linkedin, "Java Developer", "Initech", &["1 day ago"]).await,
+            failed: store_job(pool, linkedin, "C++ Developer", "Hooli", &["2 weeks ago"]).await,
+        };
+
+        update_job_skip_reason(pool, seeded.java, Some("no_easy_apply"))
+            .await
+            .unwrap();
+        update_job_status(pool, seeded.failed, StatusType::InProgress, None)
+            .await
+            .unwrap();
+        update_job_status(pool, seeded.failed, StatusType::Failed, Some("form rejected"))
+            .await
+            .unwrap();
+
+        let description = JobDescriptionData {
+            text: "Async Rust with Tokio".to_string(),
+            ..JobDescriptionData::default()
+        };
+        create_job_description(pool, seeded.rust, &description)
+            .await
+            .unwrap();
+
+        seeded
+    }
+
+    async fn ids(pool: &SqlitePool, query: &JobQuery, page: Page) -> Vec<i64> {
+        find_jobs(pool, query, page)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.id)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn find_jobs_filters() {
+        let pool = test_pool().await;
+        let s = seed(&pool).await;
+        let now = Utc::now();
+
+        let cases = [
+            ("everything", JobQuery::default(), vec![s.failed, s.java, s.seek, s.go, s.rust]),
+            ("by id", JobQuery::with_id(s.go), vec![s.go]),
+            ("unknown id", JobQuery::with_id(-1), vec![]),
+            ("failed", JobQuery::with_status(StatusType::Failed), vec![s.failed]),
+            (
+                "pending",
+                JobQuery::with_status(StatusType::Pending),
+                vec![s.java, s.seek, s.go, s.rust],
+            ),
+            (
+                "provider",
+                JobQuery {
+                    provider: Some(ProviderKind::Seek),
+                    ..JobQuery::default()
+                },
+                vec![s.seek],
+            ),
+            (
+                "company, case insensitive",
+                JobQuery {
+                    company: Some("acme".to_string()),
+                    ..JobQuery::default()
+                },
+                vec![s.seek, s.rust],
+            ),
+            (
+                "posted in the last week",
+                JobQuery {
+                    posted_after: Some(now - Duration::days(7)),
+                    ..JobQuery::default()
+                },
+                vec![s.java, s.rust],
+            ),
+            (
+                "posted over a week ago",
+                JobQuery {
+                    posted_before: Some(now - Duration::days(7)),
+                    ..JobQuery::default()
+                },
+                vec![s.failed, s.go],
+            ),
+            (
+                "description",
+                JobQuery {
+                    description: Some("TOKIO".to_string()),
+                    ..JobQuery::default()
+                },
+                vec![s.rust],
+            ),
+            (
+                "not skipped",
+                JobQuery {
+                    not_skipped: true,
+                    ..JobQuery::with_status(StatusType::Pending)
+                },
+                vec![s.seek, s.go, s.rust],
+            ),
+            (
+                "every filter at once",
+                JobQuery {
+                    provider: Some(ProviderKind::LinkedIn),
+                    company: Some("acme".to_string()),
+                    posted_after: Some(now - Duration::days(7)),
+                    description: Some("rust".to_string()),
+                    not_skipped: true,
+                    ..JobQuery::with_status(StatusType::Pending)
+                },
+                vec![s.rust],
+            ),
+        ];
+
+        for (name, query, expected) in cases {
+            assert_eq!(ids(&pool, &query, Page::ALL).await, expected, "{}", name);
+            assert_eq!(count_jobs(&pool, &query).await.unwrap(), expected.len() as i64, "{}", name);
+        }
+    }
+
+    #[tokio::test]
+    async fn find_jobs_order() {
+        let pool = test_pool().await;
+        let s = seed(&pool).await;
+
+        let oldest = JobQuery {
+            order: JobOrder::Oldest,
+            ..JobQuery::default()
+        };
+        assert_eq!(ids(&pool, &oldest, Page::ALL).await, [s.rust, s.go, s.seek, s.java, s.failed]);
+
+        // Jobs without a posting date go last
+        let posted = JobQuery {
+            order: JobOrder::Posted,
+            ..JobQuery::default()
+        };
+        assert_eq!(ids(&pool, &posted, Page::ALL).await, [s.java, s.rust, s.go, s.failed, s.seek]);
+    }
+
+    #[tokio::test]
+    async fn find_jobs_paging() {
+        let pool = test_pool().await;
+        let s = seed(&pool).await;
+        let query = JobQuery::default();
+
+        let first = Page::first(2);
+        assert_eq!(ids(&pool, &query, first).await, [s.failed, s.java]);
+        assert_eq!(ids(&pool, &query, first.next()).await, [s.seek, s.go]);
+        assert_eq!(ids(&pool, &query, first.next().next()).await, [s.rust]);
+        assert!(
+            ids(&pool, &query, first.next().next().next())
+                .await
+                .is_empty()
+        );
+
+        // Paging doesn't change the count
+        assert_eq!(count_jobs(&pool, &query).await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn single_job_lookups() {
+        let pool = test_pool().await;
+        let s = seed(&pool).await;
+
+        let job = get_job_by_id(&pool, s.failed).await.unwrap().unwrap();
+        assert_eq!(job.job_title, "C++ Developer");
+        assert_eq!(job.status, "failed");
+        assert_eq!(job.work_type, "remote");
+        assert_eq!(get_job_status(&pool, s.failed).await.unwrap(), Some(StatusType::Failed));
+        assert!(get_job_by_id(&pool, -1).await.unwrap().is_none());
+
+        let pending: Vec<i64> = get_pending_jobs(&pool, ProviderKind::LinkedIn, 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|job| job.id)
+            .collect();
+        assert_eq!(pending, [s.rust, s.go]);
+
+        let found = search_job_descriptions(&pool, "async rust", 10)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].id, s.rust);
+    }
+
+    #[tokio::test]
+    async fn counts_by_status() {
+        let pool = test_pool().await;
+        seed(&pool).await;
+
+        let counts = count_jobs_by_status(&pool).await.unwrap();
+        let count = |status: &str| {
+            counts
+                .iter()
+                .find(|count| count.status == status)
+                .map(|count| count.count)
+        };
+
+        assert_eq!(count("pending"), Some(4));
+        assert_eq!(count("failed"), Some(1));
+        assert_eq!(count("submitted"), Some(0));
+    }
 }
@@ -0,0 +1,219 @@
+use std::future::Future;
+
+use tokio::time::Duration;
+
+/// How many times a write is retried after SQLite reports the database as busy/locked.
+const BUSY_RETRIES: u32 = 5;
+
+/// Wait before the first retry, doubled for every retry after that.
+const BUSY_BACKOFF_MILLIS: u64 = 100;
+
+/// `SQLITE_BUSY` / `SQLITE_LOCKED`, the primary result codes under the extended ones
+/// (`SQLITE_BUSY_SNAPSHOT`, `SQLITE_LOCKED_SHAREDCACHE`, ...).
+const SQLITE_BUSY: i32 = 5;
+const SQLITE_LOCKED: i32 = 6;
+
+/// Whether the error is SQLite giving up on a lock another connection (or process) holds,
+/// which is worth trying again once that has been released.
+pub(crate) fn is_busy(error: &sqlx::Error) -> bool {
+    match error {
+        sqlx::Error::PoolTimedOut => true,
+        sqlx::Error::Database(db_error) => {
+            db_error
+                .code()
+                .and_then(|code| code.parse::<i32>().ok())
+                .is_some_and(|code| matches!(code & 0xff, SQLITE_BUSY | SQLITE_LOCKED))
+        }
+        _ => false,
+    }
+}
+
+/// Runs a write, retrying it with a growing backoff while the database is busy/locked.
+///
+/// The connection's `busy_timeout` already waits on the lock, this covers the writes
+/// that still lose out (ie: a long running `jobs delete` next to a run).
+/// Any other error is returned straight away.
+pub(crate) async fn retry_busy<T, F, Fut>(write: F) -> std::result::Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, sqlx::Error>>,
+{
+    retry_busy_with(BUSY_RETRIES, BUSY_BACKOFF_MILLIS, write).await
+}
+
+/// `retry_busy` with its own retry count and first backoff.
+async fn retry_busy_with<T, F, Fut>(
+    retries: u32,
+    backoff_millis: u64,
+    mut write: F,
+) -> std::result::Result<T, sqlx::Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = std::result::Result<T, sqlx::Error>>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match write().await {
+            Err(e) if attempt < retries && is_busy(&e) => {
+                let backoff = Duration::from_millis(backoff_millis << attempt);
+                attempt += 1;
+                tracing::warn!(
+                    "Database is busy ({}), retrying write {}/{} in {:?}",
+                    e,
+                    attempt,
+                    retries,
+                    backoff
+                );
+                tokio::time::sleep(backoff).await;
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::cell::Cell;
+
+    use sqlx::error::{DatabaseError, ErrorKind};
+
+    use super::*;
+    use crate::db::testing::test_pool;
+
+    /// A database error with just a result code, as SQLite reports it.
+    #[derive(Debug)]
+    struct CodeError(i32);
+
+    impl std::fmt::Display for CodeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "sqlite error code {}", self.0)
+        }
+    }
+
+    impl std::error::Error for CodeError {}
+
+    impl DatabaseError for CodeError {
+        fn message(&self) -> &str {
+            "sqlite error"
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Owned(self.0.to_string()))
+        }
+
+        fn as_error(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn std::error::Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn std::error::Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    fn code_error(code: i32) -> sqlx::Error {
+        sqlx::Error::Database(Box::new(CodeError(code)))
+    }
+
+    #[test]
+    fn busy_errors() {
+        let cases = [
+            (code_error(5), true),     // SQLITE_BUSY
+            (code_error(6), true),     // SQLITE_LOCKED
+            (code_error(517), true),   // SQLITE_BUSY_SNAPSHOT
+            (code_error(261), true),   // SQLITE_BUSY_RECOVERY
+            (code_error(262), true),   // SQLITE_LOCKED_SHAREDCACHE
+            (code_error(1), false),    // SQLITE_ERROR
+            (code_error(8), false),    // SQLITE_READONLY
+            (code_error(19), false),   // SQLITE_CONSTRAINT
+            (code_error(2067), false), // SQLITE_CONSTRAINT_UNIQUE
+            (sqlx::Error::PoolTimedOut, true),
+            (sqlx::Error::RowNotFound, false),
+            (sqlx::Error::PoolClosed, false),
+        ];
+
+        for (error, busy) in cases {
+            assert_eq!(is_busy(&error), busy, "{:?}", error);
+        }
+    }
+
+    #[tokio::test]
+    async fn real_errors_are_not_busy() {
+        let pool = test_pool().await;
+        let error = sqlx::query("SELECT * FROM no_such_table")
+            .execute(&pool)
+            .await
+            .unwrap_err();
+
+        assert!(!is_busy(&error), "{:?}", error);
+    }
+
+    /// A write that fails with `error` the first `failures` times it runs.
+    fn flaky(
+        calls: &Cell<u32>,
+        failures: u32,
+        error: fn() -> sqlx::Error,
+    ) -> impl FnMut() -> std::future::Ready<std::result::Result<u32, sqlx::Error>> {
+        move || {
+            calls.set(calls.get() + 1);
+            std::future::ready(if calls.get() <= failures {
+                Err(error())
+            } else {
+                Ok(calls.get())
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_while_busy_then_gives_up() {
+        let busy = || code_error(5);
+        // retries, failures, succeeded on call, total calls
+        let cases = [
+            (3, 0, Some(1), 1),
+            (3, 2, Some(3), 3),
+            (3, 3, Some(4), 4),
+            (3, 4, None, 4),
+            (0, 1, None, 1),
+        ];
+
+        for (retries, failures, succeeded, total) in cases {
+            let calls = Cell::new(0);
+            let result = retry_busy_with(retries, 1, flaky(&calls, failures, busy)).await;
+
+            match (result, succeeded) {
+                (Ok(call), Some(expected)) => assert_eq!(call, expected),
+                (Err(e), None) => assert!(is_busy(&e), "{:?}", e),
+                (result, _) => panic!("{} failures, {} retries: {:?}", failures, retries, result),
+            }
+            assert_eq!(calls.get(), total, "{} failures, {} retries", failures, retries);
+        }
+    }
+
+    #[tokio::test]
+    async fn other_errors_are_not_retried() {
+        let calls = Cell::new(0);
+        let result = retry_busy_with(3, 1, flaky(&calls, 2, || sqlx::Error::RowNotFound)).await;
+
+        assert!(matches!(result, Err(sqlx::Error::RowNotFound)));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn retry_busy_uses_the_default_backoff() {
+        let calls = Cell::new(0);
+        let started = tokio::time::Instant::now();
+        let result = retry_busy(flaky(&calls, 1, || sqlx::Error::PoolTimedOut)).await;
+
+        assert_eq!(result.unwrap(), 2);
+        assert!(started.elapsed() >= Duration::from_millis(BUSY_BACKOFF_MILLIS));
+    }
+}
@@ -0,0 +1,43 @@
+//! Helpers for tests that need a database.
+
+use sqlx::sqlite::SqlitePoolOptions;
+use sqlx::{SqlitePool, migrate};
+
+use crate::db::{JobCardData, save_job_card};
+use crate::providers::ProviderKind;
+
+/// An empty, migrated in-memory database.
+pub(crate) async fn test_pool() -> SqlitePool {
+    // Every connection to `:memory:` is a database of its own
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    migrate!("./migrations").run(&pool).await.unwrap();
+    pool
+}
+
+/// Stores a pending LinkedIn style job card, `extra` being the card lines after the
+/// location (ie: the posting date). Returns the job's id.
+pub(crate) async fn store_job(
+    pool: &SqlitePool,
+    provider: ProviderKind,
+    job_title: &str,
+    company: &str,
+    extra: &[&str],
+) -> i64 {
+    let text = [
+        job_title,
+        job_title,
+        company,
+        "Sydney, New South Wales, Australia (Remote)",
+    ]
+    .iter()
+    .chain(extra)
+    .copied()
+    .collect::<Vec<_>>()
+    .join("\n");
+    let card = JobCardData::from_card_text(0, &text).unwrap();
+    save_job_card(pool, provider, &card).await.unwrap().job_id()
+}
@@ -1,29 +1,281 @@
-use sqlx::SqlitePool;
+use sqlx::{Sqlite, SqlitePool, Transaction};
 
-use crate::db::data::StatusType;
+use crate::db::data::{EmploymentType, JobCardData, StatusType};
+use crate::db::retry::retry_busy;
 use crate::error::DatabaseError;
 use crate::prelude::{Error, Result};
 
+/// Moves a job to `new_status`, recording the change (and `reason`) in `db.job_status_history`.
+///
+/// Fails with `DatabaseError::StatusTransition` when the job's current status can't
+/// move on to `new_status`, see `StatusType::can_transition_to`.
+/// Setting the status the job is already in is a no-op.
 pub async fn update_job_status(
     pool: &SqlitePool,
     job_id: i64,
     new_status: StatusType,
+    reason: Option<&str>,
 ) -> Result<()> {
+    let update_error = |e| {
+        Error::DatabaseError(DatabaseError::Update(format!(
+            "Failed to update job status for job_id {}: {}",
+            job_id, e
+        )))
+    };
+
+    match retry_busy(|| write_job_status(pool, job_id, new_status, reason))
+        .await
+        .map_err(update_error)?
+    {
+        StatusWrite::Written | StatusWrite::Unchanged => Ok(()),
+        StatusWrite::Illegal(from) => {
+            Err(Error::DatabaseError(DatabaseError::StatusTransition {
+                job_id,
+                from,
+                to: new_status,
+            }))
+        }
+        StatusWrite::NoSuchJob => {
+            Err(Error::DatabaseError(DatabaseError::Update(format!("No job with id {}", job_id))))
+        }
+        StatusWrite::Unreadable(e) => Err(Error::DatabaseError(e)),
+    }
+}
+
+/// What `write_job_status` found, the status is only written for `Written`.
+enum StatusWrite {
+    Written,
+    Unchanged,
+    Illegal(StatusType),
+    NoSuchJob,
+    /// The job's current status isn't one we know of.
+    Unreadable(DatabaseError),
+}
+
+/// The status check, update and history row in one transaction,
+/// so a concurrent writer can't slip a status change in between.
+async fn write_job_status(
+    pool: &SqlitePool,
+    job_id: i64,
+    new_status: StatusType,
+    reason: Option<&str>,
+) -> sqlx::Result<StatusWrite> {
+    let mut tx = pool.begin().await?;
+
+    let current = sqlx::query!(
+        r#"
+        SELECT status_type.type AS status
+        FROM jobs
+        JOIN status_type ON status_type.id = jobs.status_type_fk
+        WHERE jobs.id = ?
+        "#,
+        job_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let current = match current.map(|row| row.status.parse::<StatusType>()) {
+        Some(Ok(current)) => current,
+        Some(Err(e)) => return Ok(StatusWrite::Unreadable(e)),
+        None => return Ok(StatusWrite::NoSuchJob),
+    };
+    if current == new_status {
+        return Ok(StatusWrite::Unchanged);
+    }
+    if !current.can_transition_to(new_status) {
+        return Ok(StatusWrite::Illegal(current));
+    }
+
     let status: &str = new_status.into();
     sqlx::query!(
         r#"
-        UPDATE jobs 
-        SET status_type_fk = ? 
+        UPDATE jobs
+        SET status_type_fk = (SELECT id FROM status_type WHERE type = ?),
+            updated_at = CURRENT_TIMESTAMP
         WHERE id = ?
         "#,
         status,
         job_id
     )
-    .execute(pool)
+    .execute(&mut *tx)
+    .await?;
+
+    let from: &str = current.into();
+    insert_status_change(&mut tx, job_id, Some(from), status, reason).await?;
+
+    tx.commit().await?;
+    Ok(StatusWrite::Written)
+}
+
+/// Appends a row to `db.job_status_history`, `from` is `None` for a newly discovered job.
+pub(crate) async fn insert_status_change(
+    tx: &mut Transaction<'_, Sqlite>,
+    job_id: i64,
+    from: Option<&str>,
+    to: &str,
+    reason: Option<&str>,
+) -> sqlx::Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO job_status_history ( job_fk, from_status_fk, to_status_fk, reason )
+        VALUES (?,
+            (SELECT id FROM status_type WHERE type = ?),
+            (SELECT id FROM status_type WHERE type = ?),
+            ?)
+        "#,
+        job_id,
+        from,
+        to,
+        reason
+    )
+    .execute(&mut **tx)
+    .await
+    .map(|_| ())
+}
+
+/// Records why a job was skipped during validation, `None` once it passes validation again.
+pub async fn update_job_skip_reason(
+    pool: &SqlitePool,
+    job_id: i64,
+    reason: Option<&str>,
+) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET skip_reason = ?, updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+            reason,
+            job_id
+        )
+        .execute(pool)
+    })
     .await
     .map_err(|e| {
         Error::DatabaseError(DatabaseError::Update(format!(
-            "Failed to update job status for job_id {}: {}",
+            "Failed to update skip reason for job_id {}: {}",
+            job_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Refreshes the card details of a job we've already stored, `provider_job_id` included
+/// (a fingerprinted job gets the provider's id once it's known).
+/// The status is left untouched, so a re-scan never resets a job's progress,
+/// and a card without a salary or job id doesn't clear the ones stored before.
+pub async fn update_job_card(pool: &SqlitePool, job_id: i64, data: &JobCardData) -> Result<()> {
+    let idx = data.idx as i64;
+    let work_type: &str = data.work_type.into();
+
+    let is_actively_reviewing = data.is_actively_reviewing.unwrap_or(false) as i64;
+    let already_viewed = data.already_viewed as i64;
+    let has_easy_apply = data.has_easy_apply as i64;
+
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET idx = ?, provider_job_id = ?, card_title = ?, job_title = ?, company_name = ?,
+                state = ?, country = ?,
+                work_type_fk = (SELECT id FROM work_type WHERE type = ?),
+                is_actively_reviewing = ?, already_viewed = ?, full_date = ?,
+                relative_date = ?, posted_at = ?, scraped_at = ?, has_easy_apply = ?,
+                job_url = COALESCE(?, job_url), salary = COALESCE(?, salary),
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+            idx,
+            data.provider_job_id,
+            data.card_title,
+            data.job_title,
+            data.company_name,
+            data.location.state,
+            data.location.country,
+            work_type,
+            is_actively_reviewing,
+            already_viewed,
+            data.posted_date.full_date,
+            data.posted_date.relative,
+            data.posted_date.posted_at,
+            data.posted_date.scraped_at,
+            has_easy_apply,
+            data.job_url,
+            data.salary,
+            job_id
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Update(format!(
+            "Failed to update job card for job_id {}: {}",
+            job_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Records why the last application attempt failed, `None` clearing it again.
+pub async fn update_job_failure_reason(
+    pool: &SqlitePool,
+    job_id: i64,
+    reason: Option<&str>,
+) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET failure_reason = ?, updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+            reason,
+            job_id
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Update(format!(
+            "Failed to update failure reason for job_id {}: {}",
+            job_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Fills in what the job's details pane said about its salary and employment type.
+/// Either being `None` keeps what's already stored.
+pub async fn update_job_details(
+    pool: &SqlitePool,
+    job_id: i64,
+    salary: Option<&str>,
+    employment_type: Option<EmploymentType>,
+) -> Result<()> {
+    let employment_type: Option<&str> = employment_type.map(Into::into);
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            UPDATE jobs
+            SET salary = COALESCE(?, salary),
+                employment_type_fk = COALESCE(
+                    (SELECT id FROM employment_type WHERE type = ?),
+                    employment_type_fk
+                ),
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+            salary,
+            employment_type,
+            job_id
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Update(format!(
+            "Failed to update job details for job_id {}: {}",
             job_id, e
         )))
     })
@@ -31,15 +283,17 @@ pub async fn update_job_status(
 }
 
 pub async fn update_autofill(pool: &SqlitePool, key: &str, data: &str) -> Result<()> {
-    sqlx::query!(
-        r#"
-        INSERT OR REPLACE INTO autofill ( input_key, data )
-        VALUES (?, ?)
-        "#,
-        key,
-        data
-    )
-    .execute(pool)
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            INSERT OR REPLACE INTO autofill ( input_key, data )
+            VALUES (?, ?)
+            "#,
+            key,
+            data
+        )
+        .execute(pool)
+    })
     .await
     .map_err(|e| {
         Error::DatabaseError(DatabaseError::Update(format!(
@@ -49,3 +303,158 @@ pub async fn update_autofill(pool: &SqlitePool, key: &str, data: &str) -> Result
     })
     .map(|_| ())
 }
+
+/// Records how far the run has got through the search results.
+pub async fn update_run_progress(
+    pool: &SqlitePool,
+    run_id: i64,
+    pages_done: i64,
+    jobs_done: i64,
+    last_job_id: Option<i64>,
+) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            UPDATE run_progress
+            SET pages_done = ?, jobs_done = ?, last_job_fk = COALESCE(?, last_job_fk),
+                updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+            pages_done,
+            jobs_done,
+            last_job_id,
+            run_id
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Update(format!(
+            "Failed to update progress for run {}: {}",
+            run_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+/// Marks the run as finished, it won't be resumed anymore.
+pub async fn update_run_finished(pool: &SqlitePool, run_id: i64) -> Result<()> {
+    retry_busy(|| {
+        sqlx::query!(
+            r#"
+            UPDATE run_progress
+            SET finished_at = CURRENT_TIMESTAMP, updated_at = CURRENT_TIMESTAMP
+            WHERE id = ?
+            "#,
+            run_id
+        )
+        .execute(pool)
+    })
+    .await
+    .map_err(|e| {
+        Error::DatabaseError(DatabaseError::Update(format!(
+            "Failed to mark run {} as finished: {}",
+            run_id, e
+        )))
+    })
+    .map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::testing::{store_job, test_pool};
+    use crate::db::{get_job_status, get_job_status_history};
+    use crate::providers::ProviderKind;
+
+    async fn history(
+        pool: &SqlitePool,
+        job_id: i64,
+    ) -> Vec<(Option<String>, String, Option<String>)> {
+        get_job_status_history(pool, job_id)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|change| (change.from_status, change.to_status, change.reason))
+            .collect()
+    }
+
+    fn change(
+        from: Option<&str>,
+        to: &str,
+        reason: Option<&str>,
+    ) -> (Option<String>, String, Option<String>) {
+        (from.map(str::to_string), to.to_string(), reason.map(str::to_string))
+    }
+
+    #[tokio::test]
+    async fn status_changes_are_checked_and_recorded() {
+        let pool = test_pool().await;
+        let job_id = store_job(&pool, ProviderKind::LinkedIn, "Rust Engineer", "Acme", &[]).await;
+
+        update_job_status(&pool, job_id, StatusType::InProgress, None)
+            .await
+            .unwrap();
+        update_job_status(&pool, job_id, StatusType::Submitted, Some("sent"))
+            .await
+            .unwrap();
+        // Already there, nothing to record
+        update_job_status(&pool, job_id, StatusType::Submitted, Some("sent again"))
+            .await
+            .unwrap();
+
+        let err = update_job_status(&pool, job_id, StatusType::Pending, Some("re-scan"))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            Error::DatabaseError(DatabaseError::StatusTransition {
+                job_id: id,
+                from: StatusType::Submitted,
+                to: StatusType::Pending,
+            }) if id == job_id
+        ));
+
+        // The rejected change left the job and its history alone
+        assert_eq!(get_job_status(&pool, job_id).await.unwrap(), Some(StatusType::Submitted));
+        assert_eq!(
+            history(&pool, job_id).await,
+            [
+                change(None, "pending", Some("discovered")),
+                change(Some("pending"), "in_progress", None),
+                change(Some("in_progress"), "submitted", Some("sent")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn unknown_job_or_status() {
+        let pool = test_pool().await;
+
+        let err = update_job_status(&pool, -1, StatusType::Pending, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::DatabaseError(DatabaseError::Update(_))));
+
+        // A status from another build of the app
+        let job_id = store_job(&pool, ProviderKind::LinkedIn, "Rust Engineer", "Acme", &[]).await;
+        sqlx::query("INSERT INTO status_type (type) VALUES ('archived')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE jobs SET status_type_fk = (SELECT id FROM status_type WHERE type = 'archived')
+             WHERE id = ?",
+        )
+        .bind(job_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let err = update_job_status(&pool, job_id, StatusType::Pending, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, Error::DatabaseError(DatabaseError::Read(_))));
+        assert!(get_job_status(&pool, job_id).await.is_err());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::db::StatusType;
+
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("Generic error handler: {0}")]
@@ -22,6 +24,9 @@ pub enum Error {
     #[error("Tokio task join error")]
     TokioJoinError(#[from] tokio::task::JoinError),
 
+    #[error("Config error: {0}")]
+    ConfigError(String),
+
     #[error("Login failure: {0}")]
     LoginFailure(String),
 
@@ -31,6 +36,12 @@ pub enum Error {
     #[error("Database error: {0}")]
     DatabaseError(#[from] DatabaseError),
 
+    #[error("Job card error: {0}")]
+    JobCardError(#[from] JobCardError),
+
+    #[error("Selector pack error: {0}")]
+    SelectorPackError(#[from] SelectorPackError),
+
     // fantoccini::error::CmdError
     // FantocciniCmdError(#[from] fantoccini::error::CmdError),
     #[error("Fantoccini command error: {error}")]
@@ -52,4 +63,80 @@ pub enum DatabaseError {
 
     #[error("Delete error: {0}")]
     Delete(String),
+
+    #[error("Connection error: {0}")]
+    Connect(String),
+
+    #[error("Migration error: {0}")]
+    Migration(String),
+
+    #[error("Job {job_id} can't go from {from:?} to {to:?}")]
+    StatusTransition {
+        job_id: i64,
+        from:   StatusType,
+        to:     StatusType,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum JobCardError {
+    #[error("Failed to read text for job card idx {idx}: {reason}")]
+    ElementText { idx: usize, reason: String },
+
+    #[error("Missing field '{field}' on job card idx {idx}")]
+    MissingField { idx: usize, field: &'static str },
+
+    #[error("Invalid value for field '{field}' on job card idx {idx}: {value}")]
+    InvalidField {
+        idx:   usize,
+        field: &'static str,
+        value: String,
+    },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SelectorPackError {
+    #[error("Failed to read selector pack {path}: {reason}")]
+    Read { path: String, reason: String },
+
+    #[error("Failed to parse selector pack {path}: {reason}")]
+    Parse { path: String, reason: String },
+
+    #[error("Selector pack {path} has version {found}, expected {expected}")]
+    UnsupportedVersion {
+        path:     String,
+        found:    u32,
+        expected: u32,
+    },
+
+    #[error("Selector pack {path} is for provider '{found}', expected '{expected}'")]
+    ProviderMismatch {
+        path:     String,
+        found:    String,
+        expected: String,
+    },
+
+    #[error("Selector pack {path} is missing section [{section}]")]
+    MissingSection { path: String, section: String },
+
+    #[error("Selector pack {path} is missing [{section}] {field}")]
+    MissingField {
+        path:    String,
+        section: String,
+        field:   &'static str,
+    },
+
+    #[error("Selector pack {path} has an empty selector for [{section}] {field}")]
+    EmptyField {
+        path:    String,
+        section: String,
+        field:   String,
+    },
+
+    #[error("Selector pack {path} has unknown entry [{section}] {field}")]
+    UnknownField {
+        path:    String,
+        section: String,
+        field:   String,
+    },
 }
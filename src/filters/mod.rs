@@ -0,0 +1,3 @@
+mod rules;
+
+pub use crate::filters::rules::{FilterRule, FilterRules, Rejection};
@@ -0,0 +1,481 @@
+use std::str::FromStr;
+
+use regex::{Regex, RegexBuilder};
+
+use crate::config::FilterConfig;
+use crate::db::{JobCardData, WorkType};
+use crate::prelude::*;
+
+/// Which rule of `FilterConfig` rejected a job.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterRule {
+    TitleNotIncluded,
+    TitleExcluded,
+    CompanyBlocked,
+    WorkTypeNotAllowed,
+    LocationNotAllowed,
+    PostingTooOld,
+    NotActivelyReviewing,
+    MissingRequiredKeyword,
+    ForbiddenKeyword,
+    /// Description rules are configured, but the description couldn't be read.
+    DescriptionUnavailable,
+}
+
+impl From<FilterRule> for &str {
+    fn from(rule: FilterRule) -> Self {
+        match rule {
+            FilterRule::TitleNotIncluded => "title_not_included",
+            FilterRule::TitleExcluded => "title_excluded",
+            FilterRule::CompanyBlocked => "company_blocked",
+            FilterRule::WorkTypeNotAllowed => "work_type_not_allowed",
+            FilterRule::LocationNotAllowed => "location_not_allowed",
+            FilterRule::PostingTooOld => "posting_too_old",
+            FilterRule::NotActivelyReviewing => "not_actively_reviewing",
+            FilterRule::MissingRequiredKeyword => "missing_required_keyword",
+            FilterRule::ForbiddenKeyword => "forbidden_keyword",
+            FilterRule::DescriptionUnavailable => "description_unavailable",
+        }
+    }
+}
+
+/// A rule a job failed, along with what exactly didn't pass (the pattern, keyword, etc.)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    pub rule:   FilterRule,
+    pub detail: String,
+}
+
+impl Rejection {
+    fn new(rule: FilterRule, detail: impl Into<String>) -> Self {
+        Self {
+            rule,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// `FilterConfig`, compiled.
+///
+/// Card rules only need what's on the job card, description rules need the
+/// job description as well, so are checked once the job has been opened.
+#[derive(Debug, Clone, Default)]
+pub struct FilterRules {
+    include_titles:          Vec<Regex>,
+    exclude_titles:          Vec<Regex>,
+    blocked_companies:       Vec<String>,
+    required_keywords:       Vec<String>,
+    forbidden_keywords:      Vec<String>,
+    work_types:              Vec<WorkType>,
+    locations:               Vec<String>,
+    max_posting_age_days:    Option<u32>,
+    actively_reviewing_only: bool,
+}
+
+impl FilterRules {
+    /// Fails on a title pattern that isn't a valid regex, or an unknown work type.
+    pub fn new(config: &FilterConfig) -> Result<Self> {
+        let work_types = config
+            .work_types
+            .iter()
+            .map(|work_type| {
+                WorkType::from_str(work_type).map_err(|_| {
+                    Error::ConfigError(format!(
+                        "Unknown work type in filters.work_types: '{}' (on-site, remote, hybrid or unknown)",
+                        work_type
+                    ))
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(Self {
+            include_titles: compile_patterns("include_titles", &config.include_titles)?,
+            exclude_titles: compile_patterns("exclude_titles", &config.exclude_titles)?,
+            blocked_companies: lowercase(&config.blocked_companies),
+            required_keywords: lowercase(&config.required_keywords),
+            forbidden_keywords: lowercase(&config.forbidden_keywords),
+            work_types,
+            locations: lowercase(&config.locations),
+            max_posting_age_days: config.max_posting_age_days,
+            actively_reviewing_only: config.actively_reviewing_only,
+        })
+    }
+
+    /// Whether `check_description` has anything to check, ie: whether the description is needed.
+    pub fn has_description_rules(&self) -> bool {
+        !self.required_keywords.is_empty() || !self.forbidden_keywords.is_empty()
+    }
+
+    /// Checks the rules that only need the job card, returning every rule it fails.
+    pub fn check_card(&self, card: &JobCardData) -> Vec<Rejection> {
+        let mut rejections = vec![];
+
+        if !self.include_titles.is_empty()
+            && !self
+                .include_titles
+                .iter()
+                .any(|re| re.is_match(&card.job_title))
+        {
+            rejections.push(Rejection::new(FilterRule::TitleNotIncluded, card.job_title.clone()));
+        }
+
+        if let Some(re) = self
+            .exclude_titles
+            .iter()
+            .find(|re| re.is_match(&card.job_title))
+        {
+            rejections.push(Rejection::new(FilterRule::TitleExcluded, re.as_str()));
+        }
+
+        let company = card.company_name.trim().to_lowercase();
+        if self.blocked_companies.contains(&company) {
+            rejections.push(Rejection::new(FilterRule::CompanyBlocked, card.company_name.clone()));
+        }
+
+        if !self.work_types.is_empty() && !self.work_types.contains(&card.work_type) {
+            rejections
+                .push(Rejection::new(FilterRule::WorkTypeNotAllowed, <&str>::from(card.work_type)));
+        }
+
+        let location = format!("{}, {}", card.location.state, card.location.country).to_lowercase();
+        if !self.locations.is_empty()
+            && !self
+                .locations
+                .iter()
+                .any(|allowed| location.contains(allowed.as_str()))
+        {
+            rejections.push(Rejection::new(FilterRule::LocationNotAllowed, location));
+        }
+
+        if let Some(max_age) = self.max_posting_age_days {
+            match card.posted_date.age_days() {
+                Some(age) if age > max_age => {
+                    rejections.push(Rejection::new(
+                        FilterRule::PostingTooOld,
+                        format!("{} days old", age),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    debug!(
+                        "Can't tell how old '{}' is from '{}', not filtering on age.",
+                        card.job_title, card.posted_date.relative
+                    );
+                }
+            }
+        }
+
+        // Only cards that say they aren't, providers like Seek don't show it at all
+        if self.actively_reviewing_only && card.is_actively_reviewing == Some(false) {
+            rejections.push(Rejection::new(FilterRule::NotActivelyReviewing, ""));
+        }
+
+        rejections
+    }
+
+    /// Checks the keyword rules against the job description,
+    /// `None` being a description that couldn't be read.
+    pub fn check_description(&self, description: Option<&str>) -> Vec<Rejection> {
+        if !self.has_description_rules() {
+            return vec![];
+        }
+
+        let Some(description) = description else {
+            return vec![Rejection::new(FilterRule::DescriptionUnavailable, "")];
+        };
+        let description = description.to_lowercase();
+
+        let missing = self
+            .required_keywords
+            .iter()
+            .filter(|keyword| !description.contains(keyword.as_str()))
+            .map(|keyword| Rejection::new(FilterRule::MissingRequiredKeyword, keyword.clone()));
+        let forbidden = self
+            .forbidden_keywords
+            .iter()
+            .filter(|keyword| description.contains(keyword.as_str()))
+            .map(|keyword| Rejection::new(FilterRule::ForbiddenKeyword, keyword.clone()));
+
+        missing.chain(forbidden).collect()
+    }
+}
+
+fn compile_patterns(name: &str, patterns: &[String]) -> Result<Vec<Regex>> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            RegexBuilder::new(pattern)
+                .case_insensitive(true)
+                .build()
+                .map_err(|e| {
+                    Error::ConfigError(format!("Invalid regex in filters.{}: {}", name, e))
+                })
+        })
+        .collect()
+}
+
+fn lowercase(values: &[String]) -> Vec<String> {
+    values
+        .iter()
+        .map(|value| value.trim().to_lowercase())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A LinkedIn card with the given positional lines, followed by `extra` (date, markers, etc.)
+    fn card(job_title: &str, company: &str, location: &str, extra: &[&str]) -> JobCardData {
+        let text = [job_title, job_title, company, location]
+            .iter()
+            .chain(extra)
+            .copied()
+            .collect::<Vec<_>>()
+            .join("\n");
+        JobCardData::from_card_text(0, &text).unwrap()
+    }
+
+    fn rules(config: FilterConfig) -> FilterRules {
+        FilterRules::new(&config).unwrap()
+    }
+
+    fn rejected_by(rejections: &[Rejection]) -> Vec<FilterRule> {
+        rejections.iter().map(|rejection| rejection.rule).collect()
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    const LOCATION: &str = "Sydney, New South Wales, Australia (Remote)";
+
+    #[test]
+    fn include_and_exclude_titles() {
+        let rules = rules(FilterConfig {
+            include_titles: strings(&["rust", "^go(lang)? "]),
+            exclude_titles: strings(&["senior|lead"]),
+            ..FilterConfig::default()
+        });
+
+        let cases: [(&str, &[FilterRule]); 6] = [
+            ("Rust Engineer", &[]),
+            ("RUST developer", &[]),
+            ("Golang Developer", &[]),
+            ("Senior Rust Engineer", &[FilterRule::TitleExcluded]),
+            ("Java Developer", &[FilterRule::TitleNotIncluded]),
+            ("Lead Java Developer", &[FilterRule::TitleNotIncluded, FilterRule::TitleExcluded]),
+        ];
+
+        for (title, expected) in cases {
+            let rejections = rules.check_card(&card(title, "Ferris Labs", LOCATION, &[]));
+            assert_eq!(rejected_by(&rejections), expected, "{}", title);
+        }
+
+        let rejections =
+            rules.check_card(&card("Senior Rust Engineer", "Ferris Labs", LOCATION, &[]));
+        assert_eq!(rejections[0].detail, "senior|lead");
+    }
+
+    #[test]
+    fn blocked_companies_ignore_case() {
+        let rules = rules(FilterConfig {
+            blocked_companies: strings(&[" Acme Pty Ltd "]),
+            ..FilterConfig::default()
+        });
+
+        let cases: [(&str, &[FilterRule]); 4] = [
+            ("Acme Pty Ltd", &[FilterRule::CompanyBlocked]),
+            ("ACME PTY LTD", &[FilterRule::CompanyBlocked]),
+            ("acme pty ltd", &[FilterRule::CompanyBlocked]),
+            ("Acme", &[]),
+        ];
+
+        for (company, expected) in cases {
+            let rejections = rules.check_card(&card("Rust Engineer", company, LOCATION, &[]));
+            assert_eq!(rejected_by(&rejections), expected, "{}", company);
+        }
+    }
+
+    #[test]
+    fn work_type_allow_list() {
+        let rules = rules(FilterConfig {
+            work_types: strings(&["Remote", "hybrid"]),
+            ..FilterConfig::default()
+        });
+
+        let cases: [(&str, Option<&str>); 4] = [
+            ("Sydney, Australia (Remote)", None),
+            ("Sydney, Australia (Hybrid)", None),
+            ("Sydney, Australia (On-site)", Some("on-site")),
+            ("Sydney, Australia", Some("unknown")),
+        ];
+
+        for (location, rejected) in cases {
+            let rejections = rules.check_card(&card("Rust Engineer", "Ferris Labs", location, &[]));
+            match rejected {
+                Some(detail) => {
+                    assert_eq!(
+                        rejections,
+                        [Rejection::new(FilterRule::WorkTypeNotAllowed, detail)],
+                        "{}",
+                        location
+                    );
+                }
+                None => assert!(rejections.is_empty(), "{}", location),
+            }
+        }
+
+        let with_unknown = FilterRules::new(&FilterConfig {
+            work_types: strings(&["remote", "unknown"]),
+            ..FilterConfig::default()
+        })
+        .unwrap();
+        let card = card("Rust Engineer", "Ferris Labs", "Sydney, Australia", &[]);
+        assert!(with_unknown.check_card(&card).is_empty());
+    }
+
+    #[test]
+    fn location_allow_list() {
+        let rules = rules(FilterConfig {
+            locations: strings(&["sydney", "New Zealand"]),
+            ..FilterConfig::default()
+        });
+
+        let cases: [(&str, Option<&str>); 4] = [
+            ("Sydney, New South Wales, Australia (Remote)", None),
+            ("SYDNEY, Australia (Hybrid)", None),
+            ("Auckland, New Zealand (On-site)", None),
+            ("Melbourne, Victoria, Australia (Remote)", Some("melbourne, victoria, australia")),
+        ];
+
+        for (location, rejected) in cases {
+            let rejections = rules.check_card(&card("Rust Engineer", "Ferris Labs", location, &[]));
+            match rejected {
+                Some(detail) => {
+                    assert_eq!(
+                        rejections,
+                        [Rejection::new(FilterRule::LocationNotAllowed, detail)],
+                        "{}",
+                        location
+                    );
+                }
+                None => assert!(rejections.is_empty(), "{}", location),
+            }
+        }
+    }
+
+    #[test]
+    fn max_posting_age() {
+        let rules = rules(FilterConfig {
+            max_posting_age_days: Some(7),
+            ..FilterConfig::default()
+        });
+
+        let cases: [(&[&str], &[FilterRule]); 5] = [
+            (&["3 days ago"], &[]),
+            (&["1 week ago"], &[]),
+            (&["2 weeks ago"], &[FilterRule::PostingTooOld]),
+            (&["Posted on January 19, 2020, 10:43 AM"], &[FilterRule::PostingTooOld]),
+            // An unknown posting date isn't filtered on
+            (&["Promoted"], &[]),
+        ];
+
+        for (lines, expected) in cases {
+            let rejections =
+                rules.check_card(&card("Rust Engineer", "Ferris Labs", LOCATION, lines));
+            assert_eq!(rejected_by(&rejections), expected, "{:?}", lines);
+        }
+
+        let rejections =
+            rules.check_card(&card("Rust Engineer", "Ferris Labs", LOCATION, &["2 weeks ago"]));
+        assert_eq!(rejections[0].detail, "14 days old");
+    }
+
+    #[test]
+    fn actively_reviewing_only() {
+        let rules = rules(FilterConfig {
+            actively_reviewing_only: true,
+            ..FilterConfig::default()
+        });
+
+        let reviewing =
+            card("Rust Engineer", "Ferris Labs", LOCATION, &["Actively reviewing applicants"]);
+        let not_reviewing = card("Rust Engineer", "Ferris Labs", LOCATION, &[]);
+
+        assert!(rules.check_card(&reviewing).is_empty());
+        assert_eq!(
+            rejected_by(&rules.check_card(&not_reviewing)),
+            [FilterRule::NotActivelyReviewing]
+        );
+
+        // Seek cards don't say either way
+        let seek = JobCardData::from_seek_card_text(0, "Rust Engineer\nat Ferris Labs\nSydney NSW")
+            .unwrap();
+        assert_eq!(seek.is_actively_reviewing, None);
+        assert!(rules.check_card(&seek).is_empty());
+    }
+
+    #[test]
+    fn description_keywords() {
+        let rules = rules(FilterConfig {
+            required_keywords: strings(&["Rust", "tokio"]),
+            forbidden_keywords: strings(&["security clearance"]),
+            ..FilterConfig::default()
+        });
+        assert!(rules.has_description_rules());
+
+        let cases: [(Option<&str>, &[Rejection]); 4] = [
+            (Some("We write RUST with Tokio."), &[]),
+            (
+                Some("Rust, async"),
+                &[Rejection {
+                    rule:   FilterRule::MissingRequiredKeyword,
+                    detail: "tokio".to_string(),
+                }],
+            ),
+            (
+                Some("Rust and Tokio, Security Clearance required"),
+                &[Rejection {
+                    rule:   FilterRule::ForbiddenKeyword,
+                    detail: "security clearance".to_string(),
+                }],
+            ),
+            (
+                None,
+                &[Rejection {
+                    rule:   FilterRule::DescriptionUnavailable,
+                    detail: String::new(),
+                }],
+            ),
+        ];
+
+        for (description, expected) in cases {
+            assert_eq!(rules.check_description(description), expected, "{:?}", description);
+        }
+    }
+
+    #[test]
+    fn description_unavailable_only_without_description_rules() {
+        let rules = rules(FilterConfig::default());
+
+        assert!(!rules.has_description_rules());
+        assert!(rules.check_description(None).is_empty());
+    }
+
+    #[test]
+    fn invalid_config_is_rejected() {
+        let bad_regex = FilterConfig {
+            include_titles: strings(&["rust("]),
+            ..FilterConfig::default()
+        };
+        let bad_work_type = FilterConfig {
+            work_types: strings(&["in the office"]),
+            ..FilterConfig::default()
+        };
+
+        assert!(matches!(FilterRules::new(&bad_regex), Err(Error::ConfigError(_))));
+        assert!(matches!(FilterRules::new(&bad_work_type), Err(Error::ConfigError(_))));
+    }
+}
@@ -6,3 +6,31 @@ macro_rules! impl_validation_traits {
         impl $crate::prelude::Validate for $ty {}
     };
 }
+
+/// Implements `SelectorFields` for a selector struct, listing every field by name
+/// so it can be read from/overridden by a selector pack.
+#[macro_export]
+macro_rules! impl_selector_fields {
+    ($ty:ty { $($field:ident),+ $(,)? }) => {
+        impl $crate::selectors::SelectorFields for $ty {
+            const FIELDS: &'static [&'static str] = &[$(stringify!($field)),+];
+
+            fn get(&self, field: &str) -> Option<&'static str> {
+                match field {
+                    $(stringify!($field) => Some(self.$field),)+
+                    _ => None,
+                }
+            }
+
+            fn set(&mut self, field: &str, value: &'static str) -> bool {
+                match field {
+                    $(stringify!($field) => {
+                        self.$field = value;
+                        true
+                    })+
+                    _ => false,
+                }
+            }
+        }
+    };
+}
@@ -1,10 +1,14 @@
 #![allow(dead_code, unused_imports)]
 
+mod application;
+mod cli;
 mod config;
 mod constants;
 mod db;
 mod error;
+mod filters;
 mod macros;
+mod output;
 mod pre_fsm;
 mod prelude;
 mod providers;
@@ -16,6 +20,7 @@ use std::collections::HashMap;
 use std::io::Write as StdWrite;
 use std::sync::{Arc, Mutex};
 
+use clap::Parser as _;
 use fantoccini::actions::{
     InputSource,
     KeyAction,
@@ -34,33 +39,79 @@ use futures::StreamExt;
 use sqlx::types::chrono::{DateTime, Utc};
 use tokio::fs::write;
 use tokio::io::AsyncWriteExt;
+use tracing_subscriber::filter::{EnvFilter, LevelFilter};
 
 // pub use self::prelude::{Error, Result, W};
+use crate::cli::{Cli, Command, RunMode};
 use crate::config::{AppConfig, ProcessHandleExt, start_driver};
 use crate::pre_fsm::wait_millis;
 pub use crate::prelude::*;
 use crate::providers::{Provider, ProviderKind, SelectorKind, get_provider};
 use crate::state::{CloseClient, State};
-use crate::states::start_state_machine;
+use crate::states::{NavigateState, RunContext, States, start_state_machine};
 
+/// Log level until the config has been read (unless `--log-level` says otherwise).
 pub const GLOBAL_LOG_LEVEL: tracing::Level = tracing::Level::INFO;
 
-// Parsing for the JobCardData here ----
-
 #[tokio::main]
 async fn main() -> Result<()> {
-    let level = LevelWrapper::from(GLOBAL_LOG_LEVEL);
-    init_logger(&level).init();
+    let cli = Cli::parse();
+
+    let level = LevelWrapper::from(
+        cli.overrides
+            .log_level
+            .map_or(GLOBAL_LOG_LEVEL, tracing::Level::from),
+    );
+    let logger = init_logger(&level)
+        .with_env_filter(level_filter(level.level))
+        .with_filter_reloading();
+    let log_level_handle = logger.reload_handle();
+    logger.init();
 
     // TODO: [check] : impl. check logic (if server isn't running, check on PATH for it, run if
     // available. Otherwise download it (to local dir) and run it (via tokio's Command call).
 
     debug!("Logging initialized at level: {:?}", level);
+
+    let command = cli.command.unwrap_or(Command::Run { fresh: false });
+
+    // Config subcommands work on the file itself, loading it as usual would replace
+    // an invalid file with the defaults
+    if let Command::Config(config_command) = command {
+        return cli::config_command(config_command, &cli.overrides).await;
+    }
+
     info!("Starting application...");
 
-    let mut config = AppConfig::new().await;
-    dbg!(&config);
+    let mut config = AppConfig::load(cli.overrides.config.as_deref()).await?;
+    cli.overrides.apply(&mut config);
+    debug!("{:#?}", config);
+
+    let log_level = tracing::Level::from(config.logging.log_level);
+    if let Err(e) = log_level_handle.reload(level_filter(log_level)) {
+        warn!("Failed to switch to log level {}: {}", log_level, e);
+    }
+
+    // Selector packs have to be in place before a provider hands out any selectors
+    selectors::load_selector_packs(&config.selectors_dir())?;
+
+    match command {
+        Command::Jobs(jobs_command) => {
+            let pool = db::setup_db(config.database.clone()).await?;
+            cli::jobs_command(&pool, jobs_command, cli.overrides.provider).await
+        }
+        Command::Autofill(autofill_command) => {
+            let pool = db::setup_db(config.database.clone()).await?;
+            cli::autofill_command(&pool, autofill_command).await
+        }
+        Command::Config(_) => unreachable!("config subcommands are handled before loading"),
+        browser_command => run_browser_command(config, browser_command).await,
+    }
+}
 
+/// Starts the driver for the subcommands that need a browser,
+/// shutting it down again whichever way the command went.
+async fn run_browser_command(mut config: AppConfig, command: Command) -> Result<()> {
     let client = start_driver(&mut config.driver)
         .await
         .unwrap_or_else(|e| panic!("Failed to start driver: {}", e));
@@ -68,72 +119,34 @@ async fn main() -> Result<()> {
 
     let state = State::new(config, client);
 
-    let provider = get_provider(ProviderKind::from(state.config.login.provider.as_str())) // replace with config value later
-        .ok_or_else(|| {
-            panic!("Failed to get provider");
-        })
-        .expect("Provider not found");
-
-    info!("Using provider: {:#?}", provider.name().to_string());
-
-    // TODO: [refactor] : we need another layer of fn here to
-    // be able to propogate up to which will call shutdown_app on error.
-    // Doing it in main() itself is awkward as hell
-
-    let pre_fsm_result = pre_fsm::run_all_steps(
-        &state.client,
-        Arc::clone(&provider),
-        &state.config,
-        SelectorKind::Xpath,
-    )
-    .await;
-
-    match pre_fsm_result {
-        Ok(_) => info!("Pre-FSM steps completed successfully."),
-        Err(e) => {
-            error!("Pre-FSM process encountered an error: {}", e);
+    let (mode, resume) = match command {
+        Command::Scan { fresh } => (RunMode::Scan, !fresh),
+        Command::Apply { limit } => (RunMode::Apply { limit }, false),
+        Command::CheckSelectors { targets } => {
+            let result = cli::check_selectors_command(&state, &targets).await;
             shutdown_app(state).await?;
-            return Err(e);
+            return result;
         }
-    }
-
-    // TEST: we first test to see if we can find _all_ of them first.
-
-    let res =
-        pre_fsm::all_job_cards(&state.client, Arc::clone(&provider), SelectorKind::Xpath).await;
-
-    match res {
-        Ok(_) => info!("Job card extraction completed successfully."),
-        Err(e) => {
-            error!("Job card extraction encountered an error: {}", e);
-            shutdown_app(state).await?;
-            return Err(e);
+        Command::Run { fresh } => (RunMode::Run, !fresh),
+        _ => (RunMode::Run, true),
+    };
+
+    let result = match db::setup_db(state.config.database.clone()).await {
+        Ok(pool) => {
+            let result = cli::run_command(&state, pool, mode, resume).await;
+            selectors::log_selector_health();
+            result
         }
-    }
-
-    /////////////////////////
-
-    // We need to handle getting sent a sign-in link thing
-
-    // If login successful,
-    // We need to move to the relevant site section for
-    // job board/pages/browsing/etc.
-    // Set any relevant filters from config (TODO)
-    //
-    // then start the main FSM
-
-    // In state machine ->
-    // Search for job listings ->
-    // Identify ones that match criteria ->
-    // Select and click apply ->
-    //      go through application process FSM ->
-    //      hit submit ---->
-    //
-    // Next Job -> loop
+        Err(e) => Err(e),
+    };
 
     shutdown_app(state).await?;
+    result
+}
 
-    Ok(())
+/// A filter letting through everything at `level` and above, swappable once the config is read.
+fn level_filter(level: tracing::Level) -> EnvFilter {
+    EnvFilter::default().add_directive(LevelFilter::from_level(level).into())
 }
 
 /// Runs various destruction/cleanup tasks,
@@ -0,0 +1,3 @@
+mod report;
+
+pub use crate::output::report::{CsvReport, OutputReports, ReportRow, ReportStatus};
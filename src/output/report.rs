@@ -0,0 +1,421 @@
+use std::fs::OpenOptions;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use sqlx::types::chrono::Utc;
+
+use crate::application::{ApplicationOutcome, DryRunSubmission};
+use crate::config::OutputConfig;
+use crate::db::{JobCardData, JobRecord};
+use crate::prelude::*;
+use crate::providers::Provider;
+use crate::states::InvalidReason;
+
+/// Where a processed job ended up, the `status` column of the reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportStatus {
+    Submitted,
+    DryRun,
+    Skipped,
+    NeedsAnswer,
+    Failed,
+    Error,
+}
+
+impl ReportStatus {
+    /// Submitted and dry run jobs go in the success report, everything else in the failure one.
+    pub fn is_success(self) -> bool {
+        matches!(self, ReportStatus::Submitted | ReportStatus::DryRun)
+    }
+}
+
+/// The header row of both reports, `ReportRow`'s fields in order.
+const REPORT_COLUMNS: [&str; 11] = [
+    "provider",
+    "job_title",
+    "company",
+    "location",
+    "work_type",
+    "url",
+    "timestamp",
+    "status",
+    "failure_reason",
+    "answers",
+    "resume",
+];
+
+/// A single line of the success/failure CSVs.
+///
+/// The field order is the column order, keep it stable: new columns go at the end
+/// (and in `REPORT_COLUMNS`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ReportRow {
+    pub provider:       String,
+    pub job_title:      String,
+    pub company:        String,
+    pub location:       String,
+    pub work_type:      String,
+    pub url:            String,
+    /// RFC 3339, UTC.
+    pub timestamp:      String,
+    pub status:         ReportStatus,
+    pub failure_reason: String,
+    /// Dry runs only: the form's fields and values as JSON, as in `db.dry_run_submission`.
+    pub answers:        String,
+    /// Dry runs only: the resume that was uploaded, empty when the provider's was kept.
+    pub resume:         String,
+}
+
+impl ReportRow {
+    /// A row for a job card from the search results, the status is filled in with `with_*`.
+    pub fn for_card(provider: &dyn Provider, card: &JobCardData) -> Self {
+        Self::new(
+            provider,
+            &card.provider_job_id,
+            &card.job_title,
+            &card.company_name,
+            card.location.clone().into(),
+            <&str>::from(card.work_type),
+        )
+    }
+
+    /// A row for a job read back from the database.
+    pub fn for_record(provider: &dyn Provider, job: &JobRecord) -> Self {
+        Self::new(
+            provider,
+            &job.provider_job_id,
+            &job.job_title,
+            &job.company_name,
+            format!("{}, {}", job.state, job.country),
+            &job.work_type,
+        )
+    }
+
+    fn new(
+        provider: &dyn Provider,
+        provider_job_id: &str,
+        job_title: &str,
+        company: &str,
+        location: String,
+        work_type: &str,
+    ) -> Self {
+        // Fingerprinted cards have no page of their own to link to
+        let url = if provider_job_id.starts_with("fp-") || provider_job_id.starts_with("legacy-") {
+            String::new()
+        } else {
+            provider.job_url(provider_job_id)
+        };
+
+        Self {
+            provider: <&str>::from(provider.name()).to_string(),
+            job_title: job_title.to_string(),
+            company: company.to_string(),
+            location,
+            work_type: work_type.to_string(),
+            url,
+            timestamp: Utc::now().to_rfc3339(),
+            status: ReportStatus::Error,
+            failure_reason: String::new(),
+            answers: String::new(),
+            resume: String::new(),
+        }
+    }
+
+    pub fn with_status(mut self, status: ReportStatus, failure_reason: impl Into<String>) -> Self {
+        self.status = status;
+        self.failure_reason = failure_reason.into();
+        self
+    }
+
+    /// Fills in the status from how the application attempt went.
+    pub fn with_outcome(self, outcome: &Result<ApplicationOutcome>) -> Self {
+        match outcome {
+            Ok(ApplicationOutcome::Submitted) => self.with_status(ReportStatus::Submitted, ""),
+            Ok(ApplicationOutcome::DryRun(submission)) => {
+                self.with_status(ReportStatus::DryRun, "")
+                    .with_dry_run(submission)
+            }
+            Ok(ApplicationOutcome::NeedsAnswer(field)) => {
+                self.with_status(
+                    ReportStatus::NeedsAnswer,
+                    format!("No answer for '{}'", field.label),
+                )
+            }
+            Ok(ApplicationOutcome::Failed(reason)) => {
+                self.with_status(ReportStatus::Failed, reason.as_str())
+            }
+            Err(e) => self.with_status(ReportStatus::Error, e.to_string()),
+        }
+    }
+
+    /// Fills in what the dry run would have submitted.
+    pub fn with_dry_run(mut self, submission: &DryRunSubmission) -> Self {
+        self.answers = serde_json::to_string(&submission.answers).unwrap_or_else(|e| {
+            warn!("Failed to serialize dry run answers for the report: {}", e);
+            String::new()
+        });
+        self.resume = submission
+            .resume
+            .as_ref()
+            .map(|path| path.to_string_lossy().to_string())
+            .unwrap_or_default();
+        self
+    }
+
+    pub fn with_skip_reason(self, reason: InvalidReason) -> Self {
+        self.with_status(ReportStatus::Skipped, <&str>::from(reason))
+    }
+}
+
+/// An append-only CSV file, the header row is written when the file is created (or empty).
+///
+/// A file with a different header (ie: started by an older build, before the `answers` and
+/// `resume` columns) is moved aside to `<name>.<timestamp>.csv` and a new one started.
+#[derive(Debug, Clone)]
+pub struct CsvReport {
+    pub enabled: bool,
+    pub path:    PathBuf,
+}
+
+impl CsvReport {
+    pub fn new(enabled: bool, path: impl AsRef<Path>) -> Self {
+        Self {
+            enabled,
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    pub fn append(&self, row: &ReportRow) -> Result<()> {
+        if !self.enabled {
+            return Ok(());
+        }
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| self.error(e))?;
+        }
+        self.rotate_outdated()?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| self.error(e))?;
+        let needs_headers = file.metadata().map_err(|e| self.error(e))?.len() == 0;
+
+        let mut writer = csv::WriterBuilder::new()
+            .has_headers(needs_headers)
+            .from_writer(file);
+        writer.serialize(row).map_err(|e| self.error(e))?;
+        writer.flush().map_err(|e| self.error(e))
+    }
+
+    /// Moves the file aside when its header row isn't `REPORT_COLUMNS`,
+    /// so new rows don't end up under the wrong columns.
+    fn rotate_outdated(&self) -> Result<()> {
+        if !self.path.is_file() {
+            return Ok(());
+        }
+
+        let mut reader = csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(&self.path)
+            .map_err(|e| self.error(e))?;
+
+        let header = match reader.records().next() {
+            None => return Ok(()),
+            Some(header) => header.map_err(|e| self.error(e))?,
+        };
+        if header.iter().eq(REPORT_COLUMNS) {
+            return Ok(());
+        }
+
+        let rotated = self.rotated_path();
+        warn!(
+            "Report {} has different columns than this version writes, moving it to {}",
+            self.path.display(),
+            rotated.display()
+        );
+        std::fs::rename(&self.path, &rotated).map_err(|e| self.error(e))
+    }
+
+    /// `output/failures.csv` -> `output/failures.20261018-093000.csv`
+    fn rotated_path(&self) -> PathBuf {
+        let stem = self
+            .path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let mut name = format!("{}.{}", stem, Utc::now().format("%Y%m%d-%H%M%S"));
+        if let Some(ext) = self.path.extension() {
+            name = format!("{}.{}", name, ext.to_string_lossy());
+        }
+        self.path.with_file_name(name)
+    }
+
+    fn error(&self, e: impl std::fmt::Display) -> Error {
+        Error::Generic(format!("Failed to write report {}: {}", self.path.display(), e))
+    }
+}
+
+/// The success and failure reports from `OutputConfig`.
+#[derive(Debug, Clone)]
+pub struct OutputReports {
+    pub success: CsvReport,
+    pub failure: CsvReport,
+}
+
+impl From<&OutputConfig> for OutputReports {
+    fn from(config: &OutputConfig) -> Self {
+        Self {
+            success: CsvReport::new(config.success.enabled, &config.success.file_path),
+            failure: CsvReport::new(config.failure.enabled, &config.failure.file_path),
+        }
+    }
+}
+
+impl OutputReports {
+    /// Appends the row to the report matching its status.
+    /// A report that can't be written is logged, it shouldn't stop the run.
+    pub fn record(&self, row: &ReportRow) {
+        let report = if row.status.is_success() {
+            &self.success
+        } else {
+            &self.failure
+        };
+
+        if let Err(e) = report.append(row) {
+            warn!("{}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(job_title: &str, status: ReportStatus) -> ReportRow {
+        ReportRow {
+            provider: "linkedin".to_string(),
+            job_title: job_title.to_string(),
+            company: "Acme, Inc".to_string(),
+            location: "Sydney, NSW".to_string(),
+            work_type: "hybrid".to_string(),
+            url: "https://www.linkedin.com/jobs/view/1/".to_string(),
+            timestamp: "2026-01-19T10:43:00+00:00".to_string(),
+            status,
+            failure_reason: String::new(),
+            answers: String::new(),
+            resume: String::new(),
+        }
+    }
+
+    /// A report path in its own, empty, directory under the system temp dir.
+    fn report_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("job_apply-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join("output").join("failures.csv")
+    }
+
+    fn read_rows(path: &Path) -> Vec<Vec<String>> {
+        csv::ReaderBuilder::new()
+            .has_headers(false)
+            .flexible(true)
+            .from_path(path)
+            .unwrap()
+            .records()
+            .map(|record| record.unwrap().iter().map(String::from).collect())
+            .collect()
+    }
+
+    #[test]
+    fn appends_under_a_single_header() {
+        let path = report_path("report-append");
+        let report = CsvReport::new(true, &path);
+
+        report
+            .append(&row("Rust Engineer", ReportStatus::Skipped))
+            .unwrap();
+        report
+            .append(
+                &row("Go Engineer", ReportStatus::Error)
+                    .with_status(ReportStatus::Failed, "No submit button"),
+            )
+            .unwrap();
+
+        let rows = read_rows(&path);
+        assert_eq!(rows.len(), 3);
+        // As documented in the README
+        assert_eq!(
+            rows[0].join(", "),
+            "provider, job_title, company, location, work_type, url, timestamp, status, failure_reason, answers, resume"
+        );
+        assert_eq!(rows[1][1], "Rust Engineer");
+        assert_eq!(rows[1][2], "Acme, Inc");
+        assert_eq!(rows[1][7], "skipped");
+        assert_eq!(rows[2][1], "Go Engineer");
+        assert_eq!(rows[2][7], "failed");
+        assert_eq!(rows[2][8], "No submit button");
+        assert!(rows.iter().all(|row| row.len() == REPORT_COLUMNS.len()));
+
+        let _ = std::fs::remove_dir_all(path.parent().unwrap().parent().unwrap());
+    }
+
+    #[test]
+    fn disabled_report_writes_nothing() {
+        let path = report_path("report-disabled");
+        let report = CsvReport::new(false, &path);
+
+        report
+            .append(&row("Rust Engineer", ReportStatus::Submitted))
+            .unwrap();
+
+        assert!(!path.exists());
+        assert!(!path.parent().unwrap().exists());
+    }
+
+    #[test]
+    fn older_report_is_moved_aside() {
+        let path = report_path("report-rotate");
+        let dir = path.parent().unwrap();
+        std::fs::create_dir_all(dir).unwrap();
+        let old = "provider,job_title,company,location,work_type,url,timestamp,status,failure_reason\n\
+                   linkedin,Old Job,Acme,Sydney,remote,,2026-01-01T00:00:00+00:00,failed,Timed out\n";
+        std::fs::write(&path, old).unwrap();
+
+        let report = CsvReport::new(true, &path);
+        report
+            .append(&row("Rust Engineer", ReportStatus::Skipped))
+            .unwrap();
+
+        let rows = read_rows(&path);
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].iter().eq(REPORT_COLUMNS));
+        assert_eq!(rows[1][1], "Rust Engineer");
+
+        // The old rows are kept, under their own header
+        let rotated: Vec<PathBuf> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|p| *p != path)
+            .collect();
+        assert_eq!(rotated.len(), 1);
+        let name = rotated[0]
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .to_string();
+        assert!(name.starts_with("failures.") && name.ends_with(".csv"), "{}", name);
+        assert_eq!(std::fs::read_to_string(&rotated[0]).unwrap(), old);
+
+        // A report with the current header is left alone
+        report
+            .append(&row("Go Engineer", ReportStatus::Skipped))
+            .unwrap();
+        assert_eq!(read_rows(&path).len(), 3);
+        assert_eq!(std::fs::read_dir(dir).unwrap().count(), 2);
+
+        let _ = std::fs::remove_dir_all(dir.parent().unwrap());
+    }
+}
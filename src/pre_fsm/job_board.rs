@@ -2,24 +2,45 @@ use std::sync::Arc;
 
 use fantoccini::{Client, Locator};
 
+use crate::config::SearchConfig;
 use crate::pre_fsm::wait_millis;
 pub use crate::prelude::*;
 use crate::providers::{Provider, ProviderKind, SelectorKind};
+use crate::selectors::{FieldMatch, SelectorField};
 use crate::states::ActionState;
 
 pub async fn job_board(
     client: &Client,
     provider: Arc<dyn Provider + Send + Sync>,
-    selector_kind: SelectorKind, // Css, Xpath, etc.
+    search_config: &SearchConfig,
 ) -> Result<()> {
     info!("Navigating to job board page for provider: {:?}", provider.name());
-    let selectors = provider.get_job_search_selectors(selector_kind);
+    let job_board_url = search_config
+        .job_board_url
+        .as_deref()
+        .unwrap_or(provider.job_board_url());
 
+    match provider.name() {
+        ProviderKind::LinkedIn => linkedin_job_board(client, provider, job_board_url).await?,
+        ProviderKind::Seek => seek_job_board(client, provider, job_board_url).await?,
+    }
+
+    info!("Navigated to job board page successfully.");
+
+    Ok(())
+}
+
+async fn linkedin_job_board(
+    client: &Client,
+    provider: Arc<dyn Provider + Send + Sync>,
+    job_board_url: &str,
+) -> Result<()> {
     wait_millis(1500).await;
 
     let jobs_element = provider
-        .with_element(client, selector_kind, selectors.jobs_button)
-        .await;
+        .find_field(client, SelectorField::job_search("jobs_button"))
+        .await
+        .map(FieldMatch::into_inner);
 
     info!("Found jobs button element, proceeding to click it.");
     let jobs_element = match jobs_element {
@@ -38,40 +59,49 @@ pub async fn job_board(
     wait_millis(200).await;
 
     info!("Verifying navigation to job board page.");
-    match provider.name() {
-        ProviderKind::LinkedIn => {
-            // LinkedIn has a different flow for job board navigation
-            info!("Using LinkedIn-specific job board navigation.");
-            let jobs_url_linkedin = "https://www.linkedin.com/jobs/";
-            if client
-                .current_url()
-                .await
-                .map_err(|e| Error::Generic(format!("Failed to get current URL: {}", e)))?
-                .as_str()
-                == jobs_url_linkedin
-            {
-                info!("Already on LinkedIn job board page.");
-                return Ok(());
-            }
-
-            warn!(
-                "LinkedIn job board navigation via button click may not have worked as expected. \
-                 Navigating directly to job board URL."
-            );
-
-            client.goto(jobs_url_linkedin).await.map_err(|e| {
-                Error::Generic(format!(
-                    "Failed to navigate to job board URL '{}': {}",
-                    jobs_url_linkedin, e
-                ))
-            })?;
-        }
-        ProviderKind::Seek => {
-            unimplemented!("Seek provider INITIAL job board navigation not implemented yet.")
-        }
+    // LinkedIn has a different flow for job board navigation
+    info!("Using LinkedIn-specific job board navigation.");
+    if client
+        .current_url()
+        .await
+        .map_err(|e| Error::Generic(format!("Failed to get current URL: {}", e)))?
+        .as_str()
+        == job_board_url
+    {
+        info!("Already on LinkedIn job board page.");
+        return Ok(());
     }
 
-    info!("Navigated to job board page successfully.");
+    warn!(
+        "LinkedIn job board navigation via button click may not have worked as expected. \
+         Navigating directly to job board URL."
+    );
+
+    goto_job_board(client, job_board_url).await
+}
+
+/// Seek's home page is the job search, so there's no button to click through,
+/// we go straight there (the login page lives on a different domain).
+async fn seek_job_board(
+    client: &Client,
+    provider: Arc<dyn Provider + Send + Sync>,
+    job_board_url: &str,
+) -> Result<()> {
+    info!("Using Seek-specific job board navigation.");
+    goto_job_board(client, job_board_url).await?;
+
+    provider
+        .find_field(client, SelectorField::job_search("search_input"))
+        .await
+        .map_err(|e| Error::Generic(format!("Seek job search didn't load: {}", e)))?;
+
+    Ok(())
+}
 
+async fn goto_job_board(client: &Client, job_board_url: &str) -> Result<()> {
+    client.goto(job_board_url).await.map_err(|e| {
+        Error::Generic(format!("Failed to navigate to job board URL '{}': {}", job_board_url, e))
+    })?;
+    wait_millis(1000).await;
     Ok(())
 }
@@ -1,67 +1,88 @@
 use std::sync::Arc;
 
 use fantoccini::Client;
+use fantoccini::elements::Element;
+use sqlx::SqlitePool;
 
-use crate::pre_fsm::wait_millis;
+use crate::db::{JobCardData, JobUpsert, get_job_cards, save_job_card};
+use crate::pre_fsm::{scroll_job_list, wait_millis};
 use crate::prelude::*;
 use crate::providers::{Provider, SelectorKind};
 
-// TODO: [same_fn_1] : See associated comment
+/// A job card that has been extracted from the page and stored in the database.
+#[derive(Debug, Clone)]
+pub struct ScannedJob {
+    pub job_id:  i64,
+    pub is_new:  bool,
+    pub card:    JobCardData,
+    pub element: Element,
+}
 
+/// Reads the job cards on the current results page and stores them,
+/// only the first `limit` of them when set (ie: what's left of `SearchConfig::max_jobs`).
 pub async fn all_job_cards(
     client: &Client,
     provider: Arc<dyn Provider + Send + Sync>,
-    selector_kind: SelectorKind, // Css, Xpath, etc.
-) -> Result<()> {
-    //
-
-    let job_card_selectors = provider.get_job_listing_selectors(selector_kind);
+    pool: &SqlitePool,
+    limit: Option<usize>,
+) -> Result<Vec<ScannedJob>> {
     wait_millis(1500).await;
+    scroll_job_list(client, Arc::clone(&provider)).await?;
 
-    let all_job_cards = provider
-        .with_elements(client, selector_kind, job_card_selectors.job_card)
-        .await;
-
-    all_job_cards
-        .map(|cards| {
-            info!("Found {} job cards on the page.", cards.len());
-
-            tokio::spawn(async move {
-                if !cards.is_empty() {
-                    // TODO: Move the collection & parsing into a struct ( JobCardData )
-                    for (i, card) in cards.iter().enumerate() {
-                        let tag_name = card.tag_name().await.unwrap_or_default();
-                        let card_text = card.text().await.unwrap_or_default();
-                        info!(
-                            "Job Card {}: Tag Name: {:?},\n Text Snippet: {}\n\n",
-                            i + 1,
-                            tag_name,
-                            card_text
-                        );
-
-                        // /// Output Example:
-                        // Job Card 1: Tag Name: "div",
-                        // Text Snippet: Full Stack Engineer (Verified job)
-                        // Full Stack Engineer
-                        // Nityo Infotech
-                        // New South Wales, Australia (Hybrid)
-                        // Actively reviewing applicants
-                        // Viewed
-                        //  ·
-                        // Posted on January 19, 2026, 10:43 AM
-                        // 1 week ago
-                        //  ·
-                        //  Easy Apply
-                    }
-                } else {
-                    warn!("No job cards found on the page.");
-                }
-            })
-        })
+    let mut cards = get_job_cards(client, Arc::clone(&provider))
+        .await
         .map_err(|e| {
             error!("Error finding job cards: {}", e);
             e
         })?;
 
-    Ok(())
+    if cards.is_empty() {
+        warn!("No job cards found on the page.");
+    } else {
+        info!("Extracted {} job cards from the page.", cards.len());
+    }
+
+    // Cards past the limit aren't stored, they'd be left pending without ever being looked at
+    if let Some(limit) = limit
+        && cards.len() > limit
+    {
+        info!("Only taking {} of them (search.max_jobs).", limit);
+        cards.truncate(limit);
+    }
+
+    let mut scanned = Vec::with_capacity(cards.len());
+
+    for (element, card) in cards {
+        debug!(
+            "Job Card {}: {} @ {} ({:?}) | Easy Apply: {}",
+            card.idx + 1,
+            card.job_title,
+            card.company_name,
+            card.work_type,
+            card.has_easy_apply
+        );
+
+        // A single failed write shouldn't throw away the rest of the page
+        match save_job_card(pool, provider.name(), &card).await {
+            Ok(upsert) => {
+                scanned.push(ScannedJob {
+                    job_id: upsert.job_id(),
+                    is_new: matches!(upsert, JobUpsert::Inserted(_)),
+                    card,
+                    element,
+                });
+            }
+            Err(e) => error!("Failed to store job card idx {}: {}", card.idx, e),
+        }
+    }
+
+    let new_jobs = scanned.iter().filter(|job| job.is_new).count();
+    info!(
+        "Stored {} job cards ({} new, {} already seen).",
+        scanned.len(),
+        new_jobs,
+        scanned.len() - new_jobs
+    );
+
+    Ok(scanned)
 }
@@ -0,0 +1,92 @@
+use fantoccini::Client;
+use sqlx::SqlitePool;
+
+use crate::db::{EmploymentType, JobDescriptionData, create_job_description, update_job_details};
+use crate::prelude::*;
+use crate::providers::Provider;
+use crate::selectors::SelectorField;
+
+/// Reads the description of the job that is currently open (details pane or the job's own page),
+/// along with its salary, seniority level and employment type when the page shows them.
+///
+/// Returns `None` when there's no description showing.
+pub async fn read_job_description(
+    client: &Client,
+    provider: &(dyn Provider + Send + Sync),
+) -> Result<Option<JobDescriptionData>> {
+    let Some(description) = provider
+        .find_optional_field(client, SelectorField::job_description("job_description"))
+        .await?
+    else {
+        return Ok(None);
+    };
+    let description = description.into_inner();
+
+    let cmd_error = |e| Error::FantocciniCmdError { error: Box::new(e) };
+    let text = description.text().await.map_err(cmd_error)?;
+    let html = description.html(true).await.map_err(cmd_error)?;
+
+    Ok(Some(JobDescriptionData {
+        text:            text.trim().to_string(),
+        html:            html.trim().to_string(),
+        salary:          optional_text(client, provider, "salary").await,
+        seniority_level: optional_text(client, provider, "seniority_level").await,
+        employment_type: optional_text(client, provider, "employment_type").await,
+    }))
+}
+
+/// Reads and stores the description of the job that is currently open.
+///
+/// Only logs when that fails, a job without a readable description can still be applied to.
+pub async fn scrape_job_description(
+    client: &Client,
+    provider: &(dyn Provider + Send + Sync),
+    pool: &SqlitePool,
+    job_id: i64,
+) -> Option<JobDescriptionData> {
+    let description = match read_job_description(client, provider).await {
+        Ok(Some(description)) => description,
+        Ok(None) => {
+            debug!("No job description showing for job {}.", job_id);
+            return None;
+        }
+        Err(e) => {
+            warn!("Failed to read the job description for job {}: {}", job_id, e);
+            return None;
+        }
+    };
+
+    if let Err(e) = create_job_description(pool, job_id, &description).await {
+        warn!("Failed to store the job description for job {}: {}", job_id, e);
+    }
+
+    let employment_type = description
+        .employment_type
+        .as_deref()
+        .and_then(|employment_type| employment_type.parse::<EmploymentType>().ok());
+    if let Err(e) =
+        update_job_details(pool, job_id, description.salary.as_deref(), employment_type).await
+    {
+        warn!("Failed to store the salary/employment type for job {}: {}", job_id, e);
+    }
+
+    Some(description)
+}
+
+/// Trimmed text of an optional `job_description` field, `None` if it isn't showing or is blank.
+async fn optional_text(
+    client: &Client,
+    provider: &(dyn Provider + Send + Sync),
+    field: &'static str,
+) -> Option<String> {
+    let element = provider
+        .find_optional_field(client, SelectorField::job_description(field))
+        .await
+        .ok()
+        .flatten()?
+        .into_inner();
+
+    let text = element.text().await.ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
@@ -6,7 +6,7 @@ use crate::config::SearchConfig;
 use crate::pre_fsm::{ActionPacket, wait_millis};
 pub use crate::prelude::*;
 use crate::providers::{Provider, ProviderKind, SelectorKind};
-use crate::selectors::JobSearchSelectors;
+use crate::selectors::{JobSearchSelectors, SelectorField};
 use crate::states::ActionState;
 
 pub async fn keyword_search(
@@ -26,8 +26,9 @@ pub async fn keyword_search(
     wait_millis(300).await;
 
     let search_element = provider
-        .with_element(client, selector_kind, selectors.search_input)
-        .await?;
+        .find_field(client, SelectorField::job_search("search_input"))
+        .await?
+        .into_inner();
 
     info!("Entering keywords into search input field.");
 
@@ -36,15 +37,6 @@ pub async fn keyword_search(
         input:   search_config.keywords.clone().join(" "),
     };
     provider.with_action(search_action).await?;
-    let enter_key = ActionState::InputKey {
-        element: &search_element,
-        key:     fantoccini::key::Key::Enter,
-    };
-    provider.with_action(enter_key).await?;
-
-    info!("Submitting keyword search form.");
-
-    wait_millis(200).await;
 
     // apply filters
     //
@@ -57,12 +49,35 @@ pub async fn keyword_search(
     };
     match provider.name() {
         ProviderKind::LinkedIn => {
+            let enter_key = ActionState::InputKey {
+                element: &search_element,
+                key:     fantoccini::key::Key::Enter,
+            };
+            provider.with_action(enter_key).await?;
+
+            info!("Submitting keyword search form.");
+            wait_millis(200).await;
+
             linkedin_filters(&action_packet).await?;
             // linkedin_location(&action_packet).await;
         }
         ProviderKind::Seek => {
-            seek_filters().await;
-            seek_location().await;
+            // Seek's search takes the location alongside the keywords, so it goes in first
+            seek_location(&action_packet).await?;
+
+            info!("Submitting keyword search form.");
+            let search_button = provider
+                .find_field(client, SelectorField::job_search("search_button"))
+                .await?
+                .into_inner();
+            provider
+                .with_action(ActionState::Click {
+                    element: search_button,
+                })
+                .await?;
+            wait_millis(1500).await;
+
+            seek_filters(&action_packet).await?;
         }
     }
 
@@ -81,7 +96,7 @@ pub async fn linkedin_filters(
         provider,
         config_section: search_config,
         selector_kind,
-        selectors,
+        ..
     } = action_packet;
 
     info!("Applying LinkedIn keyword search filters for keywords: {:?}", search_config.keywords);
@@ -111,14 +126,108 @@ pub async fn linkedin_filters(
     Ok(())
 }
 
-pub async fn linkedin_location(action_packet: &ActionPacket<'_, SearchConfig, JobSearchSelectors>) {
+pub async fn linkedin_location(
+    _action_packet: &ActionPacket<'_, SearchConfig, JobSearchSelectors>,
+) {
     //
 }
 
-pub async fn seek_filters() {
-    unimplemented!("Seek provider keyword search filters not implemented yet.")
+/// Applies the filters set in the search config, via Seek's filter panel.
+pub async fn seek_filters(
+    action_packet: &ActionPacket<'_, SearchConfig, JobSearchSelectors>,
+) -> Result<()> {
+    let ActionPacket {
+        client,
+        provider,
+        config_section: search_config,
+        ..
+    } = action_packet;
+
+    let filters = [
+        (search_config.remote_only, "remote", SelectorField::job_search("remote_filter")),
+        (
+            search_config.posted_last_day,
+            "posted in the last day",
+            SelectorField::job_search("date_posted_filter"),
+        ),
+    ];
+
+    if !filters.iter().any(|(enabled, _, _)| *enabled) {
+        debug!("No Seek search filters enabled.");
+        return Ok(());
+    }
+
+    info!("Opening Seek search filters.");
+    let filter_button = provider
+        .find_field(client, SelectorField::job_search("filter_button"))
+        .await?
+        .into_inner();
+    provider
+        .with_action(ActionState::Click {
+            element: filter_button,
+        })
+        .await?;
+    wait_millis(500).await;
+
+    for (_, name, field) in filters.into_iter().filter(|(enabled, _, _)| *enabled) {
+        info!("Applying Seek '{}' filter.", name);
+        let filter = provider.find_field(client, field).await?.into_inner();
+
+        if filter.is_selected().await.unwrap_or(false) {
+            continue;
+        }
+        provider
+            .with_action(ActionState::Click { element: filter })
+            .await?;
+        // Seek reloads the results after every filter change
+        wait_millis(1500).await;
+    }
+
+    info!("Seek search filters applied successfully.");
+    Ok(())
 }
 
-pub async fn seek_location() {
-    unimplemented!("Seek provider keyword search location not implemented yet.")
+/// Fills Seek's "Where" box from `SearchConfig::location`, when set.
+pub async fn seek_location(
+    action_packet: &ActionPacket<'_, SearchConfig, JobSearchSelectors>,
+) -> Result<()> {
+    let ActionPacket {
+        client,
+        provider,
+        config_section: search_config,
+        ..
+    } = action_packet;
+
+    let Some(location) = &search_config.location else {
+        debug!("No location set in config, searching all locations.");
+        return Ok(());
+    };
+
+    info!("Entering location '{}' into search.", location);
+    let location_element = provider
+        .find_field(client, SelectorField::job_search("location_input"))
+        .await?
+        .into_inner();
+
+    location_element
+        .clear()
+        .await
+        .map_err(|e| Error::Generic(format!("Failed to clear location input: {}", e)))?;
+    provider
+        .with_action(ActionState::InputText {
+            element: &location_element,
+            input:   location.clone(),
+        })
+        .await?;
+
+    // Let the location suggestions settle, otherwise they can swallow the search click
+    wait_millis(500).await;
+    provider
+        .with_action(ActionState::InputKey {
+            element: &location_element,
+            key:     fantoccini::key::Key::Escape,
+        })
+        .await?;
+
+    Ok(())
 }
@@ -6,7 +6,7 @@ use crate::config::LoginConfig;
 use crate::pre_fsm::{ActionPacket, detect_login_failure, wait_millis};
 use crate::prelude::*;
 use crate::providers::{Provider, ProviderKind, SelectorKind};
-use crate::selectors::{self, LoginSelectors};
+use crate::selectors::{self, LoginSelectors, SelectorField};
 use crate::states::ActionState;
 
 pub async fn login(
@@ -54,8 +54,9 @@ pub async fn login(
 
     info!("Submitting login form");
     let login_button = provider
-        .with_element(client, selector_kind, selectors.submit_button)
-        .await?;
+        .find_field(client, SelectorField::login("submit_button"))
+        .await?
+        .into_inner();
 
     provider
         .with_action(ActionState::Click {
@@ -93,13 +94,13 @@ pub async fn username(action_packet: ActionPacket<'_, LoginConfig, LoginSelector
         client,
         provider,
         config_section: login_config,
-        selector_kind,
-        selectors,
+        ..
     } = action_packet;
 
     let username_input = provider
-        .with_element(client, selector_kind, selectors.username_field)
-        .await?;
+        .find_field(client, SelectorField::login("username_field"))
+        .await?
+        .into_inner();
 
     username_input.clear().await.map_err(|e| {
         Error::Generic(format!(
@@ -124,13 +125,13 @@ pub async fn password(action_packet: &ActionPacket<'_, LoginConfig, LoginSelecto
         client,
         provider,
         config_section: login_config,
-        selector_kind,
-        selectors,
+        ..
     } = action_packet;
 
     let password_input = provider
-        .with_element(client, *selector_kind, selectors.password_field)
-        .await?;
+        .find_field(client, SelectorField::login("password_field"))
+        .await?
+        .into_inner();
 
     password_input.clear().await.map_err(|e| {
         Error::Generic(format!(
@@ -1,8 +1,10 @@
 mod detect_login_failure;
 mod job_board;
 mod job_card;
+mod job_description;
 mod keyword_search;
 mod login_action;
+mod pagination;
 
 use std::sync::Arc;
 
@@ -11,9 +13,11 @@ use fantoccini::{Client, Locator};
 use crate::config::{AppConfig, LoginConfig};
 pub use crate::pre_fsm::detect_login_failure::detect_login_failure;
 pub use crate::pre_fsm::job_board::job_board;
-pub use crate::pre_fsm::job_card::all_job_cards;
+pub use crate::pre_fsm::job_card::{ScannedJob, all_job_cards};
+pub use crate::pre_fsm::job_description::{read_job_description, scrape_job_description};
 pub use crate::pre_fsm::keyword_search::keyword_search;
 pub use crate::pre_fsm::login_action::login;
+pub use crate::pre_fsm::pagination::{next_results_page, scroll_job_list};
 pub use crate::prelude::*;
 use crate::providers::{Provider, ProviderKind, SelectorKind};
 use crate::selectors::{self, LoginSelectors};
@@ -61,7 +65,7 @@ pub async fn run_all_steps(
         }
     }
 
-    let job_board_result = job_board(client, Arc::clone(&provider), selector_kind).await;
+    let job_board_result = job_board(client, Arc::clone(&provider), &config.search).await;
 
     match job_board_result {
         Ok(_) => info!("Navigated to job board successfully."),
@@ -88,7 +92,7 @@ pub async fn run_all_steps(
         // impl the search box selection & input here to filter
         // the job listings based on keywords from config
         let keyword_result =
-            keyword_search(client, Arc::clone(&provider), &config.search, SelectorKind::Xpath);
+            keyword_search(client, Arc::clone(&provider), &config.search, selector_kind);
         match keyword_result.await {
             Ok(_) => info!("Keyword search applied successfully."),
             Err(e) => {
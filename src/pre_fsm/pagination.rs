@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use fantoccini::Client;
+use fantoccini::elements::Element;
+
+use crate::pre_fsm::wait_millis;
+use crate::prelude::*;
+use crate::providers::{Provider, ProviderKind, SelectorKind};
+use crate::selectors::{FieldMatch, SelectorField};
+use crate::states::ActionState;
+
+/// Upper bound on scroll attempts when loading a lazy job list,
+/// in case the card count never settles.
+const MAX_SCROLL_ROUNDS: usize = 20;
+
+/// LinkedIn only renders the job cards as they're scrolled into view,
+/// so scroll the `job_list_container` until no new cards show up.
+///
+/// Other providers render the whole page up front, there's nothing to do for them.
+pub async fn scroll_job_list(
+    client: &Client,
+    provider: Arc<dyn Provider + Send + Sync>,
+) -> Result<()> {
+    if provider.name() != ProviderKind::LinkedIn {
+        return Ok(());
+    }
+
+    let Some(container) = provider
+        .find_optional_field(client, SelectorField::job_listing("job_list_container"))
+        .await?
+        .map(FieldMatch::into_inner)
+    else {
+        warn!("Job list container not found, reading the cards that are already loaded.");
+        return Ok(());
+    };
+
+    let mut card_count = 0;
+
+    for round in 1..=MAX_SCROLL_ROUNDS {
+        let cards = provider
+            .find_all_field(client, SelectorField::job_listing("job_card"))
+            .await?
+            .into_inner();
+
+        if round > 1 && cards.len() == card_count {
+            debug!("Job list fully loaded with {} cards after {} scrolls.", card_count, round - 1);
+            return Ok(());
+        }
+        card_count = cards.len();
+
+        // The container isn't always the element that actually scrolls,
+        // bringing the last card into view covers both cases
+        scroll_to_bottom(client, &container, cards.last()).await?;
+        wait_millis(750).await;
+    }
+
+    warn!(
+        "Job list still loading new cards after {} scrolls, continuing with {} cards.",
+        MAX_SCROLL_ROUNDS, card_count
+    );
+    Ok(())
+}
+
+async fn scroll_to_bottom(
+    client: &Client,
+    container: &Element,
+    last_card: Option<&Element>,
+) -> Result<()> {
+    let to_value = |element: &Element| {
+        serde_json::to_value(element)
+            .map_err(|e| Error::Generic(format!("Failed to serialize element: {}", e)))
+    };
+
+    let mut args = vec![to_value(container)?];
+    if let Some(card) = last_card {
+        args.push(to_value(card)?);
+    }
+
+    client
+        .execute(
+            "arguments[0].scrollTop = arguments[0].scrollHeight; \
+             if (arguments[1]) { arguments[1].scrollIntoView({block: 'end'}); }",
+            args,
+        )
+        .await
+        .map_err(|e| Error::Generic(format!("Failed to scroll job list: {}", e)))?;
+
+    Ok(())
+}
+
+/// Clicks the `next_page_button` of the search results.
+///
+/// Returns `false` when there is no next page (button missing or disabled).
+pub async fn next_results_page(
+    client: &Client,
+    provider: Arc<dyn Provider + Send + Sync>,
+) -> Result<bool> {
+    let Some(next_button) = provider
+        .find_optional_field(client, SelectorField::job_listing("next_page_button"))
+        .await?
+        .map(FieldMatch::into_inner)
+    else {
+        debug!("No next page button, this is the last page of results.");
+        return Ok(false);
+    };
+
+    let disabled = next_button.attr("disabled").await.ok().flatten().is_some()
+        || next_button
+            .attr("aria-disabled")
+            .await
+            .ok()
+            .flatten()
+            .is_some_and(|v| v == "true");
+    if disabled {
+        debug!("Next page button is disabled, this is the last page of results.");
+        return Ok(false);
+    }
+
+    provider
+        .with_action(ActionState::Click {
+            element: next_button,
+        })
+        .await?;
+    wait_millis(1500).await;
+
+    Ok(true)
+}
@@ -3,13 +3,11 @@ use fantoccini::{Client, Locator};
 use crate::prelude::{Error, Result};
 use crate::providers::{Provider, ProviderKind, SelectorKind};
 use crate::selectors::{
+    ApplicationSelectors,
     JobDescriptionSelectors,
     JobListingSelectors,
-    LINKEDIN_JOB_DESCRIPTION_SELECTORS,
-    LINKEDIN_JOB_LISTING_SELECTORS,
-    LINKEDIN_JOB_SEARCH_SELECTORS,
-    LINKEDIN_LOGIN_SELECTORS,
     LoginSelectors,
+    selector_pack,
 };
 
 #[derive(Debug, Clone)]
@@ -20,32 +18,35 @@ impl Provider for LinkedInProvider {
         ProviderKind::LinkedIn
     }
 
+    fn job_board_url(&self) -> &'static str {
+        "https://www.linkedin.com/jobs/"
+    }
+
+    fn job_url(&self, provider_job_id: &str) -> String {
+        format!("https://www.linkedin.com/jobs/view/{}/", provider_job_id)
+    }
+
     fn get_login_selectors(&self, kind: SelectorKind) -> LoginSelectors {
-        match kind {
-            SelectorKind::Xpath => LINKEDIN_LOGIN_SELECTORS.xpath,
-            SelectorKind::Css => LINKEDIN_LOGIN_SELECTORS.css,
-        }
+        selector_pack(self.name()).by_kind(kind).login.clone()
     }
 
     fn get_job_search_selectors(&self, kind: SelectorKind) -> crate::selectors::JobSearchSelectors {
-        match kind {
-            SelectorKind::Xpath => LINKEDIN_JOB_SEARCH_SELECTORS.xpath,
-            SelectorKind::Css => LINKEDIN_JOB_SEARCH_SELECTORS.css,
-        }
+        selector_pack(self.name()).by_kind(kind).job_search.clone()
     }
 
     fn get_job_listing_selectors(&self, kind: SelectorKind) -> JobListingSelectors {
-        match kind {
-            SelectorKind::Xpath => LINKEDIN_JOB_LISTING_SELECTORS.xpath,
-            SelectorKind::Css => LINKEDIN_JOB_LISTING_SELECTORS.css,
-        }
+        selector_pack(self.name()).by_kind(kind).job_listing.clone()
     }
 
     fn get_job_description_selectors(&self, kind: SelectorKind) -> JobDescriptionSelectors {
-        match kind {
-            SelectorKind::Xpath => LINKEDIN_JOB_DESCRIPTION_SELECTORS.xpath,
-            SelectorKind::Css => LINKEDIN_JOB_DESCRIPTION_SELECTORS.css,
-        }
+        selector_pack(self.name())
+            .by_kind(kind)
+            .job_description
+            .clone()
+    }
+
+    fn get_application_selectors(&self, kind: SelectorKind) -> ApplicationSelectors {
+        selector_pack(self.name()).by_kind(kind).application.clone()
     }
 }
 
@@ -1,12 +1,20 @@
 use std::fmt::{Debug, Display};
+use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::prelude::Result;
 use crate::selectors::{
+    ApplicationSelectors,
+    FieldMatch,
     JobDescriptionSelectors,
     JobListingSelectors,
     JobSearchSelectors,
     LoginSelectors,
+    LookupOutcome,
+    SelectorCandidate,
+    SelectorField,
+    record_lookup,
+    selector_pack,
 };
 use crate::states::ActionState;
 
@@ -18,6 +26,10 @@ use fantoccini::elements::Element;
 pub use linkedin::LinkedInProvider;
 pub use seek::SeekProvider;
 
+/// How long `Provider::find_field` keeps polling the chain for a required element.
+const FIELD_LOOKUP_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+const FIELD_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
 // #[async_trait::async_trait]
 // pub trait ByXpath {
 //     async fn by_xpath(client: &Client, selector: &str) -> Result<fantoccini::elements::Element>;
@@ -29,9 +41,16 @@ pub use seek::SeekProvider;
 // }
 
 #[async_trait::async_trait]
-pub trait Provider: Send + Sync {
+pub trait Provider: Debug + Send + Sync {
     fn name(&self) -> ProviderKind;
 
+    /// Where the job search lives, used when clicking through to it isn't possible/reliable.
+    /// Can be overridden by `SearchConfig::job_board_url` (ie: Seek NZ instead of Seek AU).
+    fn job_board_url(&self) -> &'static str;
+
+    /// The job's own page, from the provider's id for it (`JobCardData::provider_job_id`).
+    fn job_url(&self, provider_job_id: &str) -> String;
+
     /// Allows for attempting different selector strategies in a preferred order.
     fn preferred_selector_order(&self) -> Vec<SelectorKind> {
         vec![SelectorKind::Xpath, SelectorKind::Css]
@@ -64,6 +83,43 @@ pub trait Provider: Send + Sync {
         })
     }
 
+    /// Non-waiting lookup, for elements that may legitimately be absent from the page
+    /// (badges, indicators, etc.)
+    ///
+    /// Returns the first matching element that is currently displayed, if any.
+    async fn with_optional_element(
+        &self,
+        client: &Client,
+        kind: SelectorKind,
+        selector: &str,
+    ) -> Result<Option<Element>> {
+        for element in self.with_elements(client, kind, selector).await? {
+            if element.is_displayed().await.unwrap_or(false) {
+                return Ok(Some(element));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Finds all elements matching a *relative* selector, scoped to the given parent element.
+    async fn with_child_elements(
+        &self,
+        parent: &Element,
+        kind: SelectorKind,
+        selector: &str,
+    ) -> Result<Vec<Element>> {
+        let locator = match kind {
+            SelectorKind::Css => fantoccini::Locator::Css(selector),
+            SelectorKind::Xpath => fantoccini::Locator::XPath(selector),
+        };
+        parent.find_all(locator).await.map_err(|e| {
+            crate::prelude::Error::Generic(format!(
+                "Child elements not found for selector '{}': {}",
+                selector, e
+            ))
+        })
+    }
+
     async fn with_elements(
         &self,
         client: &Client,
@@ -82,6 +138,115 @@ pub trait Provider: Send + Sync {
         })
     }
 
+    /// The kind used for lookups that don't go through a fallback chain
+    /// (ie: selectors relative to an element found by one).
+    fn primary_selector_kind(&self) -> SelectorKind {
+        self.preferred_selector_order()
+            .first()
+            .copied()
+            .unwrap_or(SelectorKind::Xpath)
+    }
+
+    /// Every selector to try for the field, in order, see `SelectorPack::candidates`.
+    fn selector_chain(&self, field: SelectorField) -> Result<Vec<SelectorCandidate>> {
+        let chain = selector_pack(self.name()).candidates(field, &self.preferred_selector_order());
+        if chain.is_empty() {
+            return Err(crate::prelude::Error::Generic(format!(
+                "No selectors for unknown field {}",
+                field
+            )));
+        }
+        Ok(chain)
+    }
+
+    /// Waits for the field's element, trying each selector in its fallback chain.
+    ///
+    /// When several candidates match, the earliest in the chain wins.
+    async fn find_field(
+        &self,
+        client: &Client,
+        field: SelectorField,
+    ) -> Result<FieldMatch<Element>> {
+        let chain = self.selector_chain(field)?;
+        let deadline = tokio::time::Instant::now() + FIELD_LOOKUP_TIMEOUT;
+
+        loop {
+            for candidate in &chain {
+                if let Some(element) = probe(client, candidate).await?.into_iter().next() {
+                    record_lookup(self.name(), field, LookupOutcome::Matched(*candidate));
+                    return Ok(FieldMatch {
+                        value:     element,
+                        candidate: *candidate,
+                    });
+                }
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                break;
+            }
+            tokio::time::sleep(FIELD_POLL_INTERVAL).await;
+        }
+
+        record_lookup(self.name(), field, LookupOutcome::Missed);
+        Err(crate::prelude::Error::Generic(format!(
+            "Element not found for {}, none of its {} selectors matched",
+            field,
+            chain.len()
+        )))
+    }
+
+    /// Non-waiting `find_field`, for elements that may legitimately be absent from the page.
+    ///
+    /// Returns the first displayed element of the first candidate that has one.
+    async fn find_optional_field(
+        &self,
+        client: &Client,
+        field: SelectorField,
+    ) -> Result<Option<FieldMatch<Element>>> {
+        for candidate in self.selector_chain(field)? {
+            for element in probe(client, &candidate).await? {
+                if element.is_displayed().await.unwrap_or(false) {
+                    record_lookup(self.name(), field, LookupOutcome::Matched(candidate));
+                    return Ok(Some(FieldMatch {
+                        value: element,
+                        candidate,
+                    }));
+                }
+            }
+        }
+
+        record_lookup(self.name(), field, LookupOutcome::Absent);
+        Ok(None)
+    }
+
+    /// Every element matching the first candidate in the field's chain that matches anything.
+    ///
+    /// Comes back empty (with the preferred candidate) when nothing matches.
+    async fn find_all_field(
+        &self,
+        client: &Client,
+        field: SelectorField,
+    ) -> Result<FieldMatch<Vec<Element>>> {
+        let chain = self.selector_chain(field)?;
+
+        for candidate in &chain {
+            let elements = probe(client, candidate).await?;
+            if !elements.is_empty() {
+                record_lookup(self.name(), field, LookupOutcome::Matched(*candidate));
+                return Ok(FieldMatch {
+                    value:     elements,
+                    candidate: *candidate,
+                });
+            }
+        }
+
+        record_lookup(self.name(), field, LookupOutcome::Absent);
+        Ok(FieldMatch {
+            value:     vec![],
+            candidate: chain[0],
+        })
+    }
+
     async fn with_action<'a>(&self, action: ActionState<'a>) -> Result<()> {
         match action {
             ActionState::Click { element } => {
@@ -102,6 +267,13 @@ pub trait Provider: Send + Sync {
                     crate::prelude::Error::Generic(format!("Failed to send key '{:?}': {}", key, e))
                 })
             }
+            // Not a single browser action, it runs the whole application wizard
+            ActionState::Apply(_) => {
+                Err(crate::prelude::Error::Generic(
+                    "ActionState::Apply needs a run context, execute it as a state instead"
+                        .to_string(),
+                ))
+            }
         }
     }
 
@@ -115,18 +287,52 @@ pub trait Provider: Send + Sync {
 
     fn get_job_description_selectors(&self, kind: SelectorKind) -> JobDescriptionSelectors;
 
-    // TODO: [impl] : implement the other selectors needed to complete the flow
+    fn get_application_selectors(&self, kind: SelectorKind) -> ApplicationSelectors;
+}
 
-    // fn get_application_selectors(&self) -> application::ApplicationSelectors;
+/// Every element currently matching the candidate, no waiting.
+///
+/// A selector the browser rejects (ie: invalid syntax) counts as no match,
+/// so the rest of the chain still gets a go. Any other error (ie: the session or window
+/// is gone) is returned, no selector will match after it.
+async fn probe(client: &Client, candidate: &SelectorCandidate) -> Result<Vec<Element>> {
+    match client.find_all(candidate.locator()).await {
+        Ok(elements) => Ok(elements),
+        Err(e) if e.is_invalid_selector() || e.is_no_such_element() => {
+            tracing::debug!(
+                "Selector {:?} '{}' failed to run: {}",
+                candidate.kind,
+                candidate.selector,
+                e
+            );
+            Ok(vec![])
+        }
+        Err(e) => {
+            Err(crate::prelude::Error::Generic(format!(
+                "Failed to look up selector {:?} '{}': {}",
+                candidate.kind, candidate.selector, e
+            )))
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SelectorKind {
     Css,
     Xpath,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl From<SelectorKind> for &str {
+    /// The kind's name in a selector pack.
+    fn from(kind: SelectorKind) -> Self {
+        match kind {
+            SelectorKind::Css => "css",
+            SelectorKind::Xpath => "xpath",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ProviderKind {
     LinkedIn,
     Seek,
@@ -144,22 +350,26 @@ impl Display for ProviderKind {
     }
 }
 
-impl From<&str> for ProviderKind {
-    fn from(s: &str) -> Self {
-        match s.to_lowercase().as_str() {
-            "linkedin" => ProviderKind::LinkedIn,
-            "seek" => ProviderKind::Seek,
-            _ => unimplemented!("Unsupported provider kind: {}", s),
+impl From<ProviderKind> for &str {
+    /// The key used when storing the provider in the database.
+    fn from(kind: ProviderKind) -> Self {
+        match kind {
+            ProviderKind::LinkedIn => "linkedin",
+            ProviderKind::Seek => "seek",
+            // ... others
         }
     }
 }
 
-impl From<String> for ProviderKind {
-    fn from(s: String) -> Self {
+impl FromStr for ProviderKind {
+    type Err = String;
+
+    /// Case insensitive, ie: the `login.provider` config value or a stored job's provider.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         match s.to_lowercase().as_str() {
-            "linkedin" => ProviderKind::LinkedIn,
-            "seek" => ProviderKind::Seek,
-            _ => unimplemented!("Unsupported provider kind: {}", s),
+            "linkedin" => Ok(ProviderKind::LinkedIn),
+            "seek" => Ok(ProviderKind::Seek),
+            _ => Err(format!("unsupported provider '{}', expected linkedin or seek", s)),
         }
     }
 }
@@ -1,12 +1,10 @@
 use crate::providers::{Provider, ProviderKind, SelectorKind};
 use crate::selectors::{
+    ApplicationSelectors,
     JobDescriptionSelectors,
     JobListingSelectors,
     LoginSelectors,
-    SEEK_JOB_DESCRIPTION_SELECTORS,
-    SEEK_JOB_LISTING_SELECTORS,
-    SEEK_JOB_SEARCH_SELECTORS,
-    SEEK_LOGIN_SELECTORS,
+    selector_pack,
 };
 
 #[derive(Debug, Clone)]
@@ -17,32 +15,35 @@ impl Provider for SeekProvider {
         ProviderKind::Seek
     }
 
+    fn job_board_url(&self) -> &'static str {
+        "https://www.seek.com.au/"
+    }
+
+    fn job_url(&self, provider_job_id: &str) -> String {
+        format!("https://www.seek.com.au/job/{}", provider_job_id)
+    }
+
     fn get_login_selectors(&self, kind: SelectorKind) -> LoginSelectors {
-        match kind {
-            SelectorKind::Xpath => SEEK_LOGIN_SELECTORS.xpath,
-            SelectorKind::Css => SEEK_LOGIN_SELECTORS.css,
-        }
+        selector_pack(self.name()).by_kind(kind).login.clone()
     }
 
     fn get_job_search_selectors(&self, kind: SelectorKind) -> crate::selectors::JobSearchSelectors {
-        match kind {
-            SelectorKind::Xpath => SEEK_JOB_SEARCH_SELECTORS.xpath,
-            SelectorKind::Css => SEEK_JOB_SEARCH_SELECTORS.css,
-        }
+        selector_pack(self.name()).by_kind(kind).job_search.clone()
     }
 
     fn get_job_listing_selectors(&self, kind: SelectorKind) -> JobListingSelectors {
-        match kind {
-            SelectorKind::Xpath => SEEK_JOB_LISTING_SELECTORS.xpath,
-            SelectorKind::Css => SEEK_JOB_LISTING_SELECTORS.css,
-        }
+        selector_pack(self.name()).by_kind(kind).job_listing.clone()
     }
 
     fn get_job_description_selectors(&self, kind: SelectorKind) -> JobDescriptionSelectors {
-        match kind {
-            SelectorKind::Xpath => SEEK_JOB_DESCRIPTION_SELECTORS.xpath,
-            SelectorKind::Css => SEEK_JOB_DESCRIPTION_SELECTORS.css,
-        }
+        selector_pack(self.name())
+            .by_kind(kind)
+            .job_description
+            .clone()
+    }
+
+    fn get_application_selectors(&self, kind: SelectorKind) -> ApplicationSelectors {
+        selector_pack(self.name()).by_kind(kind).application.clone()
     }
 }
 
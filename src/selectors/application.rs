@@ -0,0 +1,136 @@
+pub use linkedin::LINKEDIN_APPLICATION_SELECTORS;
+pub use seek::SEEK_APPLICATION_SELECTORS;
+
+/// Selectors for the multi-step application modal/form.
+///
+/// The `*_input`, `field_label` and `select_option` selectors are relative,
+/// they're evaluated against a single `form_field` container, not the whole page.
+#[derive(Debug, Clone)]
+pub struct ApplicationSelectors {
+    pub modal:                  &'static str,
+    pub form_field:             &'static str,
+    pub field_label:            &'static str,
+    pub text_input:             &'static str,
+    pub number_input:           &'static str,
+    pub radio_input:            &'static str,
+    pub checkbox_input:         &'static str,
+    pub select_input:           &'static str,
+    pub select_option:          &'static str,
+    pub file_input:             &'static str,
+    pub follow_company_tickbox: &'static str,
+    pub field_error:            &'static str,
+    pub next_button:            &'static str,
+    pub review_button:          &'static str,
+    pub submit_button:          &'static str,
+    pub submitted_indicator:    &'static str,
+    pub dismiss_button:         &'static str,
+    pub discard_button:         &'static str,
+    // ... more fields as/if needed
+}
+
+pub struct ApplicationSelectorAgg {
+    pub css:   ApplicationSelectors,
+    pub xpath: ApplicationSelectors,
+}
+
+mod linkedin {
+    use crate::selectors::{ApplicationSelectorAgg, ApplicationSelectors};
+
+    const LINKEDIN_APPLICATION_XPATH_SELECTORS: ApplicationSelectors = ApplicationSelectors {
+        modal:                  "//div[contains(@class, 'jobs-easy-apply-modal')]",
+        form_field:             "//div[contains(@class, 'jobs-easy-apply-modal')]//div[contains(@class, 'fb-dash-form-element')]",
+        field_label:            ".//label | .//legend",
+        text_input:             ".//input[@type='text'] | .//textarea",
+        number_input:           ".//input[@type='number' or contains(@id, 'numeric')]",
+        radio_input:            ".//input[@type='radio']",
+        checkbox_input:         ".//input[@type='checkbox']",
+        select_input:           ".//select",
+        select_option:          ".//option",
+        file_input:             ".//input[@type='file']",
+        follow_company_tickbox: "//input[@id='follow-company-checkbox']",
+        field_error:            "//div[contains(@class, 'artdeco-inline-feedback--error')]",
+        next_button:            "//button[@aria-label='Continue to next step']",
+        review_button:          "//button[@aria-label='Review your application']",
+        submit_button:          "//button[@aria-label='Submit application']",
+        submitted_indicator:    "//h3[contains(., 'Your application was sent')]",
+        dismiss_button:         "//button[@aria-label='Dismiss']",
+        discard_button:         "//button[@data-control-name='discard_application_confirm_btn']",
+    };
+
+    const LINKEDIN_APPLICATION_CSS_SELECTORS: ApplicationSelectors = ApplicationSelectors {
+        modal:                  "div.jobs-easy-apply-modal",
+        form_field:             "div.jobs-easy-apply-modal div.fb-dash-form-element",
+        field_label:            "label, legend",
+        text_input:             "input[type='text'], textarea",
+        number_input:           "input[type='number'], input[id*='numeric']",
+        radio_input:            "input[type='radio']",
+        checkbox_input:         "input[type='checkbox']",
+        select_input:           "select",
+        select_option:          "option",
+        file_input:             "input[type='file']",
+        follow_company_tickbox: "input#follow-company-checkbox",
+        field_error:            "div.artdeco-inline-feedback--error",
+        next_button:            "button[aria-label='Continue to next step']",
+        review_button:          "button[aria-label='Review your application']",
+        submit_button:          "button[aria-label='Submit application']",
+        submitted_indicator:    "div.artdeco-modal h3.jpac-modal-header",
+        dismiss_button:         "button[aria-label='Dismiss']",
+        discard_button:         "button[data-control-name='discard_application_confirm_btn']",
+    };
+
+    pub const LINKEDIN_APPLICATION_SELECTORS: ApplicationSelectorAgg = ApplicationSelectorAgg {
+        css:   LINKEDIN_APPLICATION_CSS_SELECTORS,
+        xpath: LINKEDIN_APPLICATION_XPATH_SELECTORS,
+    };
+}
+
+mod seek {
+    use crate::selectors::{ApplicationSelectorAgg, ApplicationSelectors};
+
+    const SEEK_APPLICATION_XPATH_SELECTORS: ApplicationSelectors = ApplicationSelectors {
+        modal:                  "//form[@data-testid='apply-form']",
+        form_field:             "//form[@data-testid='apply-form']//fieldset",
+        field_label:            ".//label | .//legend",
+        text_input:             ".//input[@type='text'] | .//textarea",
+        number_input:           ".//input[@type='number']",
+        radio_input:            ".//input[@type='radio']",
+        checkbox_input:         ".//input[@type='checkbox']",
+        select_input:           ".//select",
+        select_option:          ".//option",
+        file_input:             ".//input[@type='file']",
+        follow_company_tickbox: "//input[@id='follow-company-checkbox']", // TODO: no idea what seek uses
+        field_error:            "//*[@id and contains(@id, 'message') and @role='alert']",
+        next_button:            "//button[@data-testid='continue-button']",
+        review_button:          "//button[@data-testid='review-button']",
+        submit_button:          "//button[@data-testid='review-submit-application']",
+        submitted_indicator:    "//h1[contains(., 'Application submitted')]",
+        dismiss_button:         "//button[@aria-label='Close']",
+        discard_button:         "//button[contains(., 'Discard')]",
+    };
+
+    const SEEK_APPLICATION_CSS_SELECTORS: ApplicationSelectors = ApplicationSelectors {
+        modal:                  "form[data-testid='apply-form']",
+        form_field:             "form[data-testid='apply-form'] fieldset",
+        field_label:            "label, legend",
+        text_input:             "input[type='text'], textarea",
+        number_input:           "input[type='number']",
+        radio_input:            "input[type='radio']",
+        checkbox_input:         "input[type='checkbox']",
+        select_input:           "select",
+        select_option:          "option",
+        file_input:             "input[type='file']",
+        follow_company_tickbox: "input#follow-company-checkbox", // TODO: no idea what seek uses
+        field_error:            "[role='alert'][id*='message']",
+        next_button:            "button[data-testid='continue-button']",
+        review_button:          "button[data-testid='review-button']",
+        submit_button:          "button[data-testid='review-submit-application']",
+        submitted_indicator:    "h1[data-testid='application-submitted']",
+        dismiss_button:         "button[aria-label='Close']",
+        discard_button:         "button[data-testid='discard-button']",
+    };
+
+    pub const SEEK_APPLICATION_SELECTORS: ApplicationSelectorAgg = ApplicationSelectorAgg {
+        css:   SEEK_APPLICATION_CSS_SELECTORS,
+        xpath: SEEK_APPLICATION_XPATH_SELECTORS,
+    };
+}
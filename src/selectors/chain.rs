@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+use crate::providers::SelectorKind;
+use crate::selectors::{
+    ApplicationSelectors,
+    JobDescriptionSelectors,
+    JobListingSelectors,
+    JobSearchSelectors,
+    LoginSelectors,
+    SelectorFields,
+};
+
+/// The selector structs a pack is made up of, one per stage of the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum SelectorSection {
+    Login,
+    JobSearch,
+    JobListing,
+    JobDescription,
+    Application,
+}
+
+impl SelectorSection {
+    pub const ALL: [SelectorSection; 5] = [
+        SelectorSection::Login,
+        SelectorSection::JobSearch,
+        SelectorSection::JobListing,
+        SelectorSection::JobDescription,
+        SelectorSection::Application,
+    ];
+
+    /// Every field name of the section's selector struct.
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            SelectorSection::Login => LoginSelectors::FIELDS,
+            SelectorSection::JobSearch => JobSearchSelectors::FIELDS,
+            SelectorSection::JobListing => JobListingSelectors::FIELDS,
+            SelectorSection::JobDescription => JobDescriptionSelectors::FIELDS,
+            SelectorSection::Application => ApplicationSelectors::FIELDS,
+        }
+    }
+}
+
+impl From<SelectorSection> for &str {
+    /// The section's name in a selector pack.
+    fn from(section: SelectorSection) -> Self {
+        match section {
+            SelectorSection::Login => "login",
+            SelectorSection::JobSearch => "job_search",
+            SelectorSection::JobListing => "job_listing",
+            SelectorSection::JobDescription => "job_description",
+            SelectorSection::Application => "application",
+        }
+    }
+}
+
+/// Names a single selector of a pack, independent of its kind,
+/// ie: `SelectorField::job_listing("job_card")`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SelectorField {
+    pub section: SelectorSection,
+    pub field:   &'static str,
+}
+
+impl SelectorField {
+    pub const fn login(field: &'static str) -> Self {
+        Self {
+            section: SelectorSection::Login,
+            field,
+        }
+    }
+
+    pub const fn job_search(field: &'static str) -> Self {
+        Self {
+            section: SelectorSection::JobSearch,
+            field,
+        }
+    }
+
+    pub const fn job_listing(field: &'static str) -> Self {
+        Self {
+            section: SelectorSection::JobListing,
+            field,
+        }
+    }
+
+    pub const fn job_description(field: &'static str) -> Self {
+        Self {
+            section: SelectorSection::JobDescription,
+            field,
+        }
+    }
+
+    pub const fn application(field: &'static str) -> Self {
+        Self {
+            section: SelectorSection::Application,
+            field,
+        }
+    }
+}
+
+impl Display for SelectorField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", <&str>::from(self.section), self.field)
+    }
+}
+
+/// One selector to try for a field, `rank` 0 being the preferred one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectorCandidate {
+    pub rank:     usize,
+    pub kind:     SelectorKind,
+    pub selector: &'static str,
+}
+
+impl SelectorCandidate {
+    pub fn locator(&self) -> fantoccini::Locator<'static> {
+        match self.kind {
+            SelectorKind::Css => fantoccini::Locator::Css(self.selector),
+            SelectorKind::Xpath => fantoccini::Locator::XPath(self.selector),
+        }
+    }
+
+    pub fn is_fallback(&self) -> bool {
+        self.rank > 0
+    }
+}
+
+/// The result of a fallback chain lookup, along with the candidate that matched.
+///
+/// Anything looked up relative to the match (ie: the fields of a job card)
+/// should use the matched `kind`, it isn't necessarily the preferred one.
+#[derive(Debug, Clone)]
+pub struct FieldMatch<T> {
+    pub value:     T,
+    pub candidate: SelectorCandidate,
+}
+
+impl<T> FieldMatch<T> {
+    pub fn kind(&self) -> SelectorKind {
+        self.candidate.kind
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::ProviderKind;
+    use crate::selectors::SelectorPack;
+
+    #[test]
+    fn field_names() {
+        let cases = [
+            (SelectorField::login("username_field"), "login.username_field"),
+            (SelectorField::job_search("search_input"), "job_search.search_input"),
+            (SelectorField::job_listing("job_card"), "job_listing.job_card"),
+            (SelectorField::job_description("salary"), "job_description.salary"),
+            (SelectorField::application("submit_button"), "application.submit_button"),
+        ];
+
+        for (field, expected) in cases {
+            assert_eq!(field.to_string(), expected);
+            assert!(field.section.fields().contains(&field.field), "{}", expected);
+        }
+    }
+
+    #[test]
+    fn every_section_field_has_a_compiled_selector() {
+        for provider in [ProviderKind::LinkedIn, ProviderKind::Seek] {
+            let pack = SelectorPack::compiled(provider);
+
+            for section in SelectorSection::ALL {
+                assert!(!section.fields().is_empty(), "{:?}", section);
+
+                for field in section.fields() {
+                    let field = SelectorField { section, field };
+                    for kind in [SelectorKind::Css, SelectorKind::Xpath] {
+                        assert!(
+                            pack.by_kind(kind).get(field).is_some(),
+                            "{} {:?} {}",
+                            provider,
+                            kind,
+                            field
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn candidates_rank_and_locator() {
+        let preferred = SelectorCandidate {
+            rank:     0,
+            kind:     SelectorKind::Css,
+            selector: "div.job-card-container",
+        };
+        let fallback = SelectorCandidate {
+            rank:     2,
+            kind:     SelectorKind::Xpath,
+            selector: "//div[@data-job-id]",
+        };
+
+        assert!(!preferred.is_fallback());
+        assert!(fallback.is_fallback());
+        assert_eq!(preferred.locator(), fantoccini::Locator::Css("div.job-card-container"));
+        assert_eq!(fallback.locator(), fantoccini::Locator::XPath("//div[@data-job-id]"));
+
+        let found = FieldMatch {
+            value:     3,
+            candidate: fallback,
+        };
+        assert_eq!(found.kind(), SelectorKind::Xpath);
+        assert_eq!(found.into_inner(), 3);
+    }
+}
@@ -0,0 +1,286 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use fantoccini::{Client, Locator};
+
+use crate::pre_fsm::wait_millis;
+use crate::prelude::*;
+use crate::providers::{Provider, ProviderKind};
+use crate::selectors::{SelectorCandidate, SelectorField, SelectorSection, selector_pack};
+
+/// Fields expected to match more than one element on a page,
+/// for these several matches isn't ambiguous.
+const MULTI_MATCH_FIELDS: [SelectorField; 11] = [
+    SelectorField::job_listing("job_card"),
+    SelectorField::job_listing("job_id_attribute"),
+    SelectorField::application("form_field"),
+    SelectorField::application("field_label"),
+    SelectorField::application("text_input"),
+    SelectorField::application("number_input"),
+    SelectorField::application("radio_input"),
+    SelectorField::application("checkbox_input"),
+    SelectorField::application("select_input"),
+    SelectorField::application("select_option"),
+    SelectorField::application("file_input"),
+];
+
+/// Fields holding an attribute name rather than a selector, checked as `[attribute]`.
+const ATTRIBUTE_FIELDS: [SelectorField; 1] = [SelectorField::job_listing("job_id_attribute")];
+
+/// A page to check selectors against.
+#[derive(Debug, Clone)]
+pub enum SnapshotTarget {
+    /// A saved HTML snapshot, loaded in the browser from disk.
+    File(PathBuf),
+    /// A live page. Nothing logs in first, so pages behind a login need a session already.
+    Url(String),
+}
+
+impl SnapshotTarget {
+    /// URLs (`http(s)://`, `file://`) are used as is, anything else is taken as a file path.
+    pub fn parse(arg: &str) -> Self {
+        if ["http://", "https://", "file://"]
+            .iter()
+            .any(|scheme| arg.starts_with(scheme))
+        {
+            SnapshotTarget::Url(arg.to_string())
+        } else {
+            SnapshotTarget::File(PathBuf::from(arg))
+        }
+    }
+
+    fn url(&self) -> Result<String> {
+        match self {
+            SnapshotTarget::File(path) => {
+                let path = path.canonicalize().map_err(|e| {
+                    Error::Generic(format!("Snapshot not found at {}: {}", path.display(), e))
+                })?;
+                Ok(format!("file://{}", path.display()))
+            }
+            SnapshotTarget::Url(url) => Ok(url.clone()),
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            SnapshotTarget::File(path) => path.display().to_string(),
+            SnapshotTarget::Url(url) => url.clone(),
+        }
+    }
+}
+
+/// How a candidate did across every snapshot.
+#[derive(Debug, Clone)]
+pub struct CandidateCheck {
+    pub candidate: SelectorCandidate,
+    /// Snapshots with the expected number of matches.
+    pub matched:   usize,
+    pub missing:   usize,
+    /// Snapshots where a single-element field matched several elements.
+    pub ambiguous: usize,
+    /// Snapshots where the browser rejected the selector.
+    pub invalid:   usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldCheck {
+    pub field:      SelectorField,
+    pub candidates: Vec<CandidateCheck>,
+    /// Snapshots where at least one candidate matched.
+    pub found_in:   usize,
+}
+
+impl FieldCheck {
+    fn has_invalid(&self) -> bool {
+        self.candidates.iter().any(|c| c.invalid > 0)
+    }
+
+    fn has_ambiguous(&self) -> bool {
+        self.candidates.iter().any(|c| c.ambiguous > 0)
+    }
+}
+
+/// The outcome of `check_selectors`, per field and per candidate selector.
+#[derive(Debug, Clone)]
+pub struct SelectorCheckReport {
+    pub provider:  ProviderKind,
+    pub snapshots: Vec<String>,
+    pub fields:    Vec<FieldCheck>,
+}
+
+impl SelectorCheckReport {
+    /// Fields none of whose selectors matched in any snapshot.
+    pub fn never_found(&self) -> impl Iterator<Item = &FieldCheck> {
+        self.fields.iter().filter(|field| field.found_in == 0)
+    }
+
+    /// Prints the report as a table, followed by the fields needing a look.
+    pub fn print(&self) {
+        println!("Selector check for {} over {} snapshot(s):", self.provider, self.snapshots.len());
+        for snapshot in &self.snapshots {
+            println!("  {}", snapshot);
+        }
+        println!();
+        println!(
+            "{:<36} {:<6} {:>4} {:>8} {:>8} {:>10} {:>8}  selector",
+            "field", "kind", "rank", "matched", "missing", "ambiguous", "invalid"
+        );
+
+        for field in &self.fields {
+            for check in &field.candidates {
+                println!(
+                    "{:<36} {:<6} {:>4} {:>8} {:>8} {:>10} {:>8}  {}",
+                    field.field.to_string(),
+                    <&str>::from(check.candidate.kind),
+                    check.candidate.rank,
+                    check.matched,
+                    check.missing,
+                    check.ambiguous,
+                    check.invalid,
+                    check.candidate.selector
+                );
+            }
+        }
+
+        let never_found: Vec<String> = self.never_found().map(|f| f.field.to_string()).collect();
+        let ambiguous: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|f| f.has_ambiguous())
+            .map(|f| f.field.to_string())
+            .collect();
+        let invalid: Vec<String> = self
+            .fields
+            .iter()
+            .filter(|f| f.has_invalid())
+            .map(|f| f.field.to_string())
+            .collect();
+
+        println!();
+        println!(
+            "{} fields checked: {} never matched, {} ambiguous, {} with invalid selectors.",
+            self.fields.len(),
+            never_found.len(),
+            ambiguous.len(),
+            invalid.len()
+        );
+        for (title, fields) in [
+            ("Never matched", never_found),
+            ("Ambiguous", ambiguous),
+            ("Invalid", invalid),
+        ] {
+            if !fields.is_empty() {
+                println!("{}: {}", title, fields.join(", "));
+            }
+        }
+    }
+}
+
+/// Loads each snapshot in the browser and evaluates every selector of the provider's
+/// active pack against it, alternatives and both kinds included.
+///
+/// A snapshot is a single page, so fields belonging to other pages are expected to be
+/// missing from it, check `FieldCheck::found_in` across a set of snapshots covering
+/// every page (login, search, results, application form).
+pub async fn check_selectors(
+    client: &Client,
+    provider: Arc<dyn Provider + Send + Sync>,
+    targets: &[SnapshotTarget],
+) -> Result<SelectorCheckReport> {
+    let pack = selector_pack(provider.name());
+    let order = provider.preferred_selector_order();
+
+    let mut fields: Vec<FieldCheck> = SelectorSection::ALL
+        .into_iter()
+        .flat_map(|section| {
+            section
+                .fields()
+                .iter()
+                .map(move |field| SelectorField { section, field })
+        })
+        .map(|field| {
+            FieldCheck {
+                field,
+                candidates: pack
+                    .candidates(field, &order)
+                    .into_iter()
+                    .map(|candidate| {
+                        CandidateCheck {
+                            candidate,
+                            matched: 0,
+                            missing: 0,
+                            ambiguous: 0,
+                            invalid: 0,
+                        }
+                    })
+                    .collect(),
+                found_in: 0,
+            }
+        })
+        .collect();
+
+    for target in targets {
+        let url = target.url()?;
+        info!("Checking selectors against {}", target.label());
+        client.goto(&url).await.map_err(|e| {
+            Error::Generic(format!("Failed to load snapshot '{}': {}", target.label(), e))
+        })?;
+        wait_millis(1000).await;
+
+        for field in &mut fields {
+            let multiple = MULTI_MATCH_FIELDS.contains(&field.field);
+            let mut found = false;
+
+            for check in &mut field.candidates {
+                match count_matches(client, field.field, &check.candidate).await {
+                    Some(0) => check.missing += 1,
+                    Some(1) => {
+                        check.matched += 1;
+                        found = true;
+                    }
+                    Some(_) if multiple => {
+                        check.matched += 1;
+                        found = true;
+                    }
+                    Some(_) => {
+                        check.ambiguous += 1;
+                        found = true;
+                    }
+                    None => check.invalid += 1,
+                }
+            }
+
+            if found {
+                field.found_in += 1;
+            }
+        }
+    }
+
+    Ok(SelectorCheckReport {
+        provider: provider.name(),
+        snapshots: targets.iter().map(SnapshotTarget::label).collect(),
+        fields,
+    })
+}
+
+/// How many elements the candidate matches on the current page, `None` if it isn't valid.
+async fn count_matches(
+    client: &Client,
+    field: SelectorField,
+    candidate: &SelectorCandidate,
+) -> Option<usize> {
+    let result = if ATTRIBUTE_FIELDS.contains(&field) {
+        let selector = format!("[{}]", candidate.selector);
+        client.find_all(Locator::Css(&selector)).await
+    } else {
+        client.find_all(candidate.locator()).await
+    };
+
+    match result {
+        Ok(elements) => Some(elements.len()),
+        Err(e) => {
+            debug!("{} {:?} '{}' is invalid: {}", field, candidate.kind, candidate.selector, e);
+            None
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use crate::prelude::*;
+use crate::providers::ProviderKind;
+use crate::selectors::{SelectorCandidate, SelectorField};
+
+/// How a single fallback chain lookup went.
+#[derive(Debug, Clone, Copy)]
+pub enum LookupOutcome {
+    Matched(SelectorCandidate),
+    /// An optional element (badge, indicator, etc.) wasn't on the page, not a failure as such.
+    Absent,
+    /// A required element couldn't be found by any candidate.
+    Missed,
+}
+
+/// Lookup tallies for one field over the run.
+#[derive(Debug, Clone, Default)]
+pub struct FieldHealth {
+    pub lookups:    usize,
+    /// Matched by the preferred candidate.
+    pub primary:    usize,
+    /// Matched, but only by a later candidate in the chain.
+    pub fallback:   usize,
+    pub absent:     usize,
+    pub missed:     usize,
+    pub last_match: Option<SelectorCandidate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HealthStatus {
+    Healthy,
+    /// The preferred selector has stopped matching, a fallback is carrying it.
+    Degraded,
+    /// Nothing in the chain matched at least once.
+    Broken,
+}
+
+impl FieldHealth {
+    pub fn status(&self) -> HealthStatus {
+        if self.missed > 0 {
+            HealthStatus::Broken
+        } else if self.fallback > 0 {
+            HealthStatus::Degraded
+        } else {
+            HealthStatus::Healthy
+        }
+    }
+}
+
+impl From<HealthStatus> for &str {
+    fn from(status: HealthStatus) -> Self {
+        match status {
+            HealthStatus::Healthy => "healthy",
+            HealthStatus::Degraded => "degraded",
+            HealthStatus::Broken => "broken",
+        }
+    }
+}
+
+type HealthMap = HashMap<(ProviderKind, SelectorField), FieldHealth>;
+
+static SELECTOR_HEALTH: OnceLock<Mutex<HealthMap>> = OnceLock::new();
+
+fn health_map() -> &'static Mutex<HealthMap> {
+    SELECTOR_HEALTH.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Records the outcome of a lookup, warning the first time a field has to fall back.
+pub fn record_lookup(provider: ProviderKind, field: SelectorField, outcome: LookupOutcome) {
+    let mut map = health_map().lock().unwrap_or_else(|e| e.into_inner());
+    let health = map.entry((provider, field)).or_default();
+    health.lookups += 1;
+
+    match outcome {
+        LookupOutcome::Matched(candidate) if candidate.is_fallback() => {
+            if health.fallback == 0 {
+                warn!(
+                    "Preferred selector for {} {} didn't match, fell back to {:?} #{}: '{}'",
+                    provider, field, candidate.kind, candidate.rank, candidate.selector
+                );
+            }
+            health.fallback += 1;
+            health.last_match = Some(candidate);
+        }
+        LookupOutcome::Matched(candidate) => {
+            health.primary += 1;
+            health.last_match = Some(candidate);
+        }
+        LookupOutcome::Absent => health.absent += 1,
+        LookupOutcome::Missed => health.missed += 1,
+    }
+
+    if let LookupOutcome::Matched(candidate) = outcome {
+        debug!("{} {} matched by {:?} '{}'", provider, field, candidate.kind, candidate.selector);
+    }
+}
+
+/// Every field looked up so far, worst first.
+pub fn selector_health() -> Vec<(ProviderKind, SelectorField, FieldHealth)> {
+    let map = health_map().lock().unwrap_or_else(|e| e.into_inner());
+    let mut report: Vec<_> = map
+        .iter()
+        .map(|((provider, field), health)| (*provider, *field, health.clone()))
+        .collect();
+
+    report.sort_by(|a, b| b.2.status().cmp(&a.2.status()).then_with(|| a.1.cmp(&b.1)));
+    report
+}
+
+/// Logs the selector health report, degraded/broken fields as warnings.
+pub fn log_selector_health() {
+    let report = selector_health();
+    if report.is_empty() {
+        return;
+    }
+
+    let unhealthy = report
+        .iter()
+        .filter(|(_, _, health)| health.status() != HealthStatus::Healthy)
+        .count();
+    info!("Selector health: {} fields looked up, {} need attention.", report.len(), unhealthy);
+
+    for (provider, field, health) in report {
+        let status = health.status();
+        let line = format!(
+            "  {} {} [{}]: {} lookups, {} primary, {} fallback, {} absent, {} missed",
+            provider,
+            field,
+            <&str>::from(status),
+            health.lookups,
+            health.primary,
+            health.fallback,
+            health.absent,
+            health.missed
+        );
+
+        match status {
+            HealthStatus::Healthy => debug!("{}", line),
+            HealthStatus::Degraded | HealthStatus::Broken => warn!("{}", line),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::SelectorKind;
+
+    // The health map is process wide, so every test sticks to its own made up fields.
+
+    fn candidate(rank: usize) -> SelectorCandidate {
+        SelectorCandidate {
+            rank,
+            kind: SelectorKind::Css,
+            selector: if rank == 0 {
+                "div.primary"
+            } else {
+                "div.fallback"
+            },
+        }
+    }
+
+    fn health(field: SelectorField) -> FieldHealth {
+        selector_health()
+            .into_iter()
+            .find(|(provider, f, _)| *provider == ProviderKind::Seek && *f == field)
+            .map(|(_, _, health)| health)
+            .expect("field was looked up")
+    }
+
+    #[test]
+    fn lookups_decide_the_status() {
+        let healthy = SelectorField::login("health_test_healthy");
+        let degraded = SelectorField::login("health_test_degraded");
+        let broken = SelectorField::login("health_test_broken");
+
+        let lookups = [
+            (healthy, LookupOutcome::Matched(candidate(0))),
+            (healthy, LookupOutcome::Absent),
+            (healthy, LookupOutcome::Matched(candidate(0))),
+            (degraded, LookupOutcome::Matched(candidate(0))),
+            (degraded, LookupOutcome::Matched(candidate(1))),
+            (degraded, LookupOutcome::Matched(candidate(1))),
+            (broken, LookupOutcome::Matched(candidate(1))),
+            (broken, LookupOutcome::Missed),
+        ];
+        for (field, outcome) in lookups {
+            record_lookup(ProviderKind::Seek, field, outcome);
+        }
+
+        let cases = [
+            // field, status, lookups, primary, fallback, absent, missed, last match rank
+            (healthy, HealthStatus::Healthy, 3, 2, 0, 1, 0, Some(0)),
+            (degraded, HealthStatus::Degraded, 3, 1, 2, 0, 0, Some(1)),
+            (broken, HealthStatus::Broken, 2, 0, 1, 0, 1, Some(1)),
+        ];
+
+        for (field, status, lookups, primary, fallback, absent, missed, last_rank) in cases {
+            let health = health(field);
+            assert_eq!(health.status(), status, "{}", field);
+            assert_eq!(health.lookups, lookups, "{}", field);
+            assert_eq!(health.primary, primary, "{}", field);
+            assert_eq!(health.fallback, fallback, "{}", field);
+            assert_eq!(health.absent, absent, "{}", field);
+            assert_eq!(health.missed, missed, "{}", field);
+            assert_eq!(health.last_match.map(|c| c.rank), last_rank, "{}", field);
+        }
+
+        // Worst first
+        let order: Vec<SelectorField> = selector_health()
+            .into_iter()
+            .map(|(_, field, _)| field)
+            .filter(|field| [healthy, degraded, broken].contains(field))
+            .collect();
+        assert_eq!(order, [broken, degraded, healthy]);
+    }
+
+    #[test]
+    fn absent_optional_elements_stay_healthy() {
+        let field = SelectorField::job_listing("health_test_absent");
+        record_lookup(ProviderKind::Seek, field, LookupOutcome::Absent);
+        record_lookup(ProviderKind::Seek, field, LookupOutcome::Absent);
+
+        let health = health(field);
+        assert_eq!(health.status(), HealthStatus::Healthy);
+        assert_eq!(health.absent, 2);
+        assert!(health.last_match.is_none());
+    }
+}
@@ -9,6 +9,9 @@ pub struct JobDescriptionSelectors {
     pub job_description: &'static str,
     pub apply_button:    &'static str,
     pub save_button:     &'static str,
+    pub salary:          &'static str,
+    pub seniority_level: &'static str,
+    pub employment_type: &'static str,
 }
 
 pub struct JobDescriptionSelectorAgg {
@@ -27,6 +30,9 @@ mod linkedin {
             job_description: "//div[contains(@class, 'description__text')]",
             apply_button:    "//button[contains(@class, 'apply-button')]",
             save_button:     "//button[contains(@class, 'save-button')]",
+            salary:          "//div[contains(@class, 'compensation__salary')]",
+            seniority_level: "//li[contains(@class, 'description__job-criteria-item')][h3[contains(., 'Seniority level')]]/span",
+            employment_type: "//li[contains(@class, 'description__job-criteria-item')][h3[contains(., 'Employment type')]]/span",
         };
 
     const LINKEDIN_JOB_DESCRIPTION_CSS_SELECTORS: JobDescriptionSelectors =
@@ -37,6 +43,9 @@ mod linkedin {
             job_description: "div.description__text",
             apply_button:    "button.apply-button",
             save_button:     "button.save-button",
+            salary:          "div.compensation__salary",
+            seniority_level: "li.description__job-criteria-item:nth-child(1) span.description__job-criteria-text",
+            employment_type: "li.description__job-criteria-item:nth-child(2) span.description__job-criteria-text",
         };
 
     pub const LINKEDIN_JOB_DESCRIPTION_SELECTORS: JobDescriptionSelectorAgg =
@@ -56,6 +65,9 @@ mod seek {
         job_description: "//div[contains(@class, 'job-description')]",
         apply_button:    "//button[contains(@class, 'apply-now-button')]",
         save_button:     "//button[contains(@class, 'save-job-button')]",
+        salary:          "//span[@data-automation='job-detail-salary']",
+        seniority_level: "//span[@data-automation='job-detail-seniority']",
+        employment_type: "//span[@data-automation='job-detail-work-type']",
     };
 
     const SEEK_JOB_DESCRIPTION_CSS_SELECTORS: JobDescriptionSelectors = JobDescriptionSelectors {
@@ -65,6 +77,9 @@ mod seek {
         job_description: "div.job-description",
         apply_button:    "button.apply-now-button",
         save_button:     "button.save-job-button",
+        salary:          "span[data-automation='job-detail-salary']",
+        seniority_level: "span[data-automation='job-detail-seniority']",
+        employment_type: "span[data-automation='job-detail-work-type']",
     };
 
     pub const SEEK_JOB_DESCRIPTION_SELECTORS: JobDescriptionSelectorAgg =
@@ -83,23 +83,23 @@ mod seek {
     use crate::selectors::job_search::{JobSearchSelectorAgg, JobSearchSelectors};
 
     const SEEK_JOB_SEARCH_XPATH_SELECTORS: JobSearchSelectors = JobSearchSelectors {
-        jobs_button:        "//a[@aria-label='Jobs']",
-        search_input:       "//input[@id='search-field']",
-        location_input:     "//input[@id='location-field']",
-        search_button:      "//button[@id='search-button']",
-        filter_button:      "//button[contains(@class, 'filter-button')]",
+        jobs_button:        "//a[@data-automation='job search']",
+        search_input:       "//input[@id='keywords-input']",
+        location_input:     "//input[@id='SearchBar__Where']",
+        search_button:      "//button[@id='searchButton']",
+        filter_button:      "//button[@data-automation='toggleMoreOptionsButton']",
         remote_filter:      "//label[contains(., 'Remote')]//input[@type='checkbox']",
-        date_posted_filter: "//label[contains(., 'Past 24 hours')]//input[@type='checkbox']",
+        date_posted_filter: "//label[contains(., 'Today')]//input[@type='radio']",
     };
 
     const SEEK_JOB_SEARCH_CSS_SELECTORS: JobSearchSelectors = JobSearchSelectors {
-        jobs_button:        "a[aria-label='Jobs']",
-        search_input:       "input#search-field",
-        location_input:     "input#location-field",
-        search_button:      "button#search-button",
-        filter_button:      "button.filter-button",
-        remote_filter:      "label:contains('Remote') input[type='checkbox']",
-        date_posted_filter: "label:contains('Past 24 hours') input[type='checkbox']",
+        jobs_button:        "a[data-automation='job search']",
+        search_input:       "input#keywords-input",
+        location_input:     "input#SearchBar__Where",
+        search_button:      "button#searchButton",
+        filter_button:      "button[data-automation='toggleMoreOptionsButton']",
+        remote_filter:      "input[type='checkbox'][value='remote']",
+        date_posted_filter: "input[type='radio'][value='1']",
     };
 
     pub const SEEK_JOB_SEARCH_SELECTORS: JobSearchSelectorAgg = JobSearchSelectorAgg {
@@ -5,6 +5,8 @@ pub use seek::SEEK_JOB_LISTING_SELECTORS;
 pub struct JobListingSelectors {
     pub job_list_container:   &'static str,
     pub job_card:             &'static str,
+    /// Attribute on the job card element holding the provider's own id for the job
+    pub job_id_attribute:     &'static str,
     pub easy_apply_button:    &'static str,
     pub applied_badge:        &'static str,
     pub job_closed_indicator: &'static str,
@@ -62,10 +64,11 @@ mod linkedin {
     // '_19c7c805 d72a15d2 _05785daf _16bd5bac _7112567e d7447142 _1d4b5610 _49ddd288 _11b82cd0 _96030a07 _1016a07e _838ce6e7 b334bf7e _6d9316ce'
 
     const LINKEDIN_JOB_LISTING_XPATH_SELECTORS: JobListingSelectors = JobListingSelectors {
-        job_list_container:   "*[@id='workspace']/div/div/div[1]/div",
+        job_list_container:   "//*[@id='workspace']/div/div/div[1]/div",
         // job_card:             "/html/body/div/div[2]/div[2]/div[2]/main/div/div/div[1]/div/div[1]/div",
         // job_card:             "//*[@id='workspace']/div/div/div[1]/div/div[1]/div",
         job_card:             "//div[contains(@class, '_19c7c805 d72a15d2 _05785daf _16bd5bac _7112567e d7447142 _1d4b5610 _49ddd288 _11b82cd0 _96030a07 _1016a07e _838ce6e7 b334bf7e _6d9316ce')]",
+        job_id_attribute:     "data-job-id",
         easy_apply_button:    "//button[contains(@class, 'easy-apply-button')]",
         applied_badge:        "//span[contains(@class, 'applied-badge')]",
         job_closed_indicator: "//span[contains(@class, 'job-closed-indicator')]",
@@ -76,6 +79,7 @@ mod linkedin {
     const LINKEDIN_JOB_LISTING_CSS_SELECTORS: JobListingSelectors = JobListingSelectors {
         job_list_container:   "div#workspace > div > div > div:nth-child(1) > div",
         job_card:             "div.job-card-container",
+        job_id_attribute:     "data-job-id",
         easy_apply_button:    "button.easy-apply-button",
         applied_badge:        "span.applied-badge",
         job_closed_indicator: "span.job-closed-indicator",
@@ -92,24 +96,29 @@ mod linkedin {
 mod seek {
     use crate::selectors::{JobListingSelectors, JogListingSelectorAgg};
 
+    // Seek has no "Easy Apply", its equivalent is "Quick apply", anything else sends you off
+    // to the employer's own site.
     const SEEK_JOB_LISTING_XPATH_SELECTORS: JobListingSelectors = JobListingSelectors {
-        job_list_container:   "//div[@id='job-listings-container']",
-        job_card:             "//div[contains(@class, 'job-card-container')]",
-        easy_apply_button:    "//button[contains(@class, 'easy-apply-button')]",
-        applied_badge:        "//span[contains(@class, 'applied-badge')]",
-        job_closed_indicator: "//span[contains(@class, 'job-closed-indicator')]",
-
-        next_page_button: "//button[@aria-label='Next']",
+        job_list_container:   "//div[@data-automation='searchResults']",
+        job_card:             "//article[@data-card-type='JobCard']",
+        job_id_attribute:     "data-job-id",
+        easy_apply_button:    "//a[@data-automation='job-detail-apply' and contains(., 'Quick apply')]",
+        applied_badge:        "//*[@data-automation='job-detail-applied-date']",
+        job_closed_indicator: "//*[@data-automation='expiredJobPage']",
+
+        next_page_button: "//a[@data-automation='page-next']",
     };
 
     const SEEK_JOB_LISTING_CSS_SELECTORS: JobListingSelectors = JobListingSelectors {
-        job_list_container:   "div#job-listings-container",
-        job_card:             "div.job-card-container",
-        easy_apply_button:    "button.easy-apply-button",
-        applied_badge:        "span.applied-badge",
-        job_closed_indicator: "span.job-closed-indicator",
-
-        next_page_button: "button[aria-label='Next']",
+        job_list_container:   "div[data-automation='searchResults']",
+        job_card:             "article[data-card-type='JobCard']",
+        job_id_attribute:     "data-job-id",
+        // NOTE: css can't match on text, this also matches the external "Apply" button
+        easy_apply_button:    "a[data-automation='job-detail-apply']",
+        applied_badge:        "[data-automation='job-detail-applied-date']",
+        job_closed_indicator: "[data-automation='expiredJobPage']",
+
+        next_page_button: "a[data-automation='page-next']",
     };
 
     pub const SEEK_JOB_LISTING_SELECTORS: JogListingSelectorAgg = JogListingSelectorAgg {
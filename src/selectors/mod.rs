@@ -1,8 +1,29 @@
+mod application;
+mod chain;
+mod check;
+mod health;
 mod job_desc;
 mod job_search;
 mod jobs_list;
 mod login;
+mod pack;
 
+pub use application::{
+    ApplicationSelectorAgg,
+    ApplicationSelectors,
+    LINKEDIN_APPLICATION_SELECTORS,
+    SEEK_APPLICATION_SELECTORS,
+};
+pub use chain::{FieldMatch, SelectorCandidate, SelectorField, SelectorSection};
+pub use check::{CandidateCheck, FieldCheck, SelectorCheckReport, SnapshotTarget, check_selectors};
+pub use health::{
+    FieldHealth,
+    HealthStatus,
+    LookupOutcome,
+    log_selector_health,
+    record_lookup,
+    selector_health,
+};
 pub use job_desc::{
     JobDescriptionSelectorAgg,
     JobDescriptionSelectors,
@@ -22,3 +43,13 @@ pub use jobs_list::{
     SEEK_JOB_LISTING_SELECTORS,
 };
 pub use login::{LINKEDIN_LOGIN_SELECTORS, LoginSelectorAgg, LoginSelectors, SEEK_LOGIN_SELECTORS};
+pub use pack::{
+    PackSource,
+    SELECTOR_PACK_VERSION,
+    SelectorFields,
+    SelectorPack,
+    SelectorSet,
+    load_selector_packs,
+    selector_pack,
+    selector_pack_path,
+};
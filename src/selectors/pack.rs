@@ -0,0 +1,891 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::SelectorPackError;
+use crate::prelude::*;
+use crate::providers::{ProviderKind, SelectorKind};
+use crate::selectors::{
+    ApplicationSelectors,
+    JobDescriptionSelectors,
+    JobListingSelectors,
+    JobSearchSelectors,
+    LINKEDIN_APPLICATION_SELECTORS,
+    LINKEDIN_JOB_DESCRIPTION_SELECTORS,
+    LINKEDIN_JOB_LISTING_SELECTORS,
+    LINKEDIN_JOB_SEARCH_SELECTORS,
+    LINKEDIN_LOGIN_SELECTORS,
+    LoginSelectors,
+    SEEK_APPLICATION_SELECTORS,
+    SEEK_JOB_DESCRIPTION_SELECTORS,
+    SEEK_JOB_LISTING_SELECTORS,
+    SEEK_JOB_SEARCH_SELECTORS,
+    SEEK_LOGIN_SELECTORS,
+    SelectorCandidate,
+    SelectorField,
+    SelectorSection,
+};
+
+/// The selector pack format version this build understands.
+pub const SELECTOR_PACK_VERSION: u32 = 1;
+
+/// Gives access to a selector struct's fields by name, see `impl_selector_fields!`.
+pub trait SelectorFields {
+    const FIELDS: &'static [&'static str];
+
+    fn get(&self, field: &str) -> Option<&'static str>;
+
+    /// Returns `false` when there's no such field.
+    fn set(&mut self, field: &str, value: &'static str) -> bool;
+}
+
+crate::impl_selector_fields!(LoginSelectors {
+    failed_attempt_indicator,
+    username_field,
+    password_field,
+    submit_button,
+});
+
+crate::impl_selector_fields!(JobSearchSelectors {
+    jobs_button,
+    search_input,
+    location_input,
+    search_button,
+    filter_button,
+    remote_filter,
+    date_posted_filter,
+});
+
+crate::impl_selector_fields!(JobListingSelectors {
+    job_list_container,
+    job_card,
+    job_id_attribute,
+    easy_apply_button,
+    applied_badge,
+    job_closed_indicator,
+    next_page_button,
+});
+
+crate::impl_selector_fields!(JobDescriptionSelectors {
+    job_title,
+    company_name,
+    location,
+    job_description,
+    apply_button,
+    save_button,
+    salary,
+    seniority_level,
+    employment_type,
+});
+
+crate::impl_selector_fields!(ApplicationSelectors {
+    modal,
+    form_field,
+    field_label,
+    text_input,
+    number_input,
+    radio_input,
+    checkbox_input,
+    select_input,
+    select_option,
+    file_input,
+    follow_company_tickbox,
+    field_error,
+    next_button,
+    review_button,
+    submit_button,
+    submitted_indicator,
+    dismiss_button,
+    discard_button,
+});
+
+/// Every selector struct for one provider, of a single `SelectorKind`.
+#[derive(Debug, Clone)]
+pub struct SelectorSet {
+    pub login:           LoginSelectors,
+    pub job_search:      JobSearchSelectors,
+    pub job_listing:     JobListingSelectors,
+    pub job_description: JobDescriptionSelectors,
+    pub application:     ApplicationSelectors,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackSource {
+    Compiled,
+    File(PathBuf),
+}
+
+/// All of a provider's selectors, CSS and XPath.
+///
+/// The compiled-in consts are the defaults, a pack file in the config directory's
+/// `selectors/` folder (`linkedin.toml`, `seek.json`, etc.) replaces them without a rebuild:
+///
+/// ```toml
+/// version = 1
+/// provider = "linkedin"
+///
+/// [xpath.login]
+/// failed_attempt_indicator = "//div[@id='error-for-username']"
+/// username_field = "//input[@id='username']"
+/// # ...
+///
+/// [css.login]
+/// # ...
+/// ```
+///
+/// Sections are `login`, `job_search`, `job_listing`, `job_description` and `application`,
+/// every field of every section must be present for both kinds.
+/// A field can also be given as an array, the first entry being its selector
+/// and the rest alternatives tried in order when it stops matching.
+#[derive(Debug, Clone)]
+pub struct SelectorPack {
+    pub provider:     ProviderKind,
+    pub version:      u32,
+    pub source:       PackSource,
+    pub css:          SelectorSet,
+    pub xpath:        SelectorSet,
+    /// Extra selectors to try per field when the pack's one doesn't match, see `candidates`.
+    pub alternatives: HashMap<(SelectorKind, SelectorField), Vec<&'static str>>,
+}
+
+/// On-disk layout of a pack, `kind -> section -> field -> selector(s)`.
+#[derive(Debug, Serialize, Deserialize)]
+struct RawSelectorPack {
+    version:  u32,
+    provider: String,
+    css:      BTreeMap<String, BTreeMap<String, RawSelector>>,
+    xpath:    BTreeMap<String, BTreeMap<String, RawSelector>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawSelector {
+    One(String),
+    /// The field's selector followed by its alternatives, in the order they're tried.
+    Many(Vec<String>),
+}
+
+impl RawSelector {
+    fn selectors(&self) -> Vec<&str> {
+        match self {
+            RawSelector::One(selector) => vec![selector.as_str()],
+            RawSelector::Many(selectors) => selectors.iter().map(String::as_str).collect(),
+        }
+    }
+}
+
+type RawSet = BTreeMap<String, BTreeMap<String, RawSelector>>;
+type Alternatives = HashMap<(SelectorKind, SelectorField), Vec<&'static str>>;
+
+impl SelectorSet {
+    pub fn get(&self, field: SelectorField) -> Option<&'static str> {
+        match field.section {
+            SelectorSection::Login => self.login.get(field.field),
+            SelectorSection::JobSearch => self.job_search.get(field.field),
+            SelectorSection::JobListing => self.job_listing.get(field.field),
+            SelectorSection::JobDescription => self.job_description.get(field.field),
+            SelectorSection::Application => self.application.get(field.field),
+        }
+    }
+}
+
+impl SelectorPack {
+    /// The selectors built into the binary.
+    pub fn compiled(provider: ProviderKind) -> Self {
+        let (css, xpath) = match provider {
+            ProviderKind::LinkedIn => {
+                (
+                    SelectorSet {
+                        login:           LINKEDIN_LOGIN_SELECTORS.css,
+                        job_search:      LINKEDIN_JOB_SEARCH_SELECTORS.css,
+                        job_listing:     LINKEDIN_JOB_LISTING_SELECTORS.css,
+                        job_description: LINKEDIN_JOB_DESCRIPTION_SELECTORS.css,
+                        application:     LINKEDIN_APPLICATION_SELECTORS.css,
+                    },
+                    SelectorSet {
+                        login:           LINKEDIN_LOGIN_SELECTORS.xpath,
+                        job_search:      LINKEDIN_JOB_SEARCH_SELECTORS.xpath,
+                        job_listing:     LINKEDIN_JOB_LISTING_SELECTORS.xpath,
+                        job_description: LINKEDIN_JOB_DESCRIPTION_SELECTORS.xpath,
+                        application:     LINKEDIN_APPLICATION_SELECTORS.xpath,
+                    },
+                )
+            }
+            ProviderKind::Seek => {
+                (
+                    SelectorSet {
+                        login:           SEEK_LOGIN_SELECTORS.css,
+                        job_search:      SEEK_JOB_SEARCH_SELECTORS.css,
+                        job_listing:     SEEK_JOB_LISTING_SELECTORS.css,
+                        job_description: SEEK_JOB_DESCRIPTION_SELECTORS.css,
+                        application:     SEEK_APPLICATION_SELECTORS.css,
+                    },
+                    SelectorSet {
+                        login:           SEEK_LOGIN_SELECTORS.xpath,
+                        job_search:      SEEK_JOB_SEARCH_SELECTORS.xpath,
+                        job_listing:     SEEK_JOB_LISTING_SELECTORS.xpath,
+                        job_description: SEEK_JOB_DESCRIPTION_SELECTORS.xpath,
+                        application:     SEEK_APPLICATION_SELECTORS.xpath,
+                    },
+                )
+            }
+        };
+
+        Self {
+            provider,
+            version: SELECTOR_PACK_VERSION,
+            source: PackSource::Compiled,
+            css,
+            xpath,
+            alternatives: HashMap::new(),
+        }
+    }
+
+    pub fn by_kind(&self, kind: SelectorKind) -> &SelectorSet {
+        match kind {
+            SelectorKind::Css => &self.css,
+            SelectorKind::Xpath => &self.xpath,
+        }
+    }
+
+    /// The fallback chain for a field: for each kind in `order`, its selector
+    /// followed by that kind's alternatives.
+    pub fn candidates(
+        &self,
+        field: SelectorField,
+        order: &[SelectorKind],
+    ) -> Vec<SelectorCandidate> {
+        let mut candidates: Vec<SelectorCandidate> = vec![];
+
+        for kind in order {
+            let primary = self.by_kind(*kind).get(field);
+            let alternatives = self
+                .alternatives
+                .get(&(*kind, field))
+                .into_iter()
+                .flatten()
+                .copied();
+
+            for selector in primary.into_iter().chain(alternatives) {
+                if !candidates
+                    .iter()
+                    .any(|c| c.kind == *kind && c.selector == selector)
+                {
+                    candidates.push(SelectorCandidate {
+                        rank: candidates.len(),
+                        kind: *kind,
+                        selector,
+                    });
+                }
+            }
+        }
+
+        candidates
+    }
+
+    /// Loads and validates a pack file, `.json` files are read as JSON, anything else as TOML.
+    pub fn from_file(path: &Path, provider: ProviderKind) -> Result<Self> {
+        let display = path.display().to_string();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            SelectorPackError::Read {
+                path:   display.clone(),
+                reason: e.to_string(),
+            }
+        })?;
+
+        let raw: RawSelectorPack = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+            _ => toml::from_str(&contents).map_err(|e| e.to_string()),
+        }
+        .map_err(|reason| {
+            SelectorPackError::Parse {
+                path: display.clone(),
+                reason,
+            }
+        })?;
+
+        let mut pack = Self::from_raw(raw, provider, &display)?;
+        pack.source = PackSource::File(path.to_path_buf());
+        Ok(pack)
+    }
+
+    fn from_raw(raw: RawSelectorPack, provider: ProviderKind, path: &str) -> Result<Self> {
+        if raw.version != SELECTOR_PACK_VERSION {
+            return Err(SelectorPackError::UnsupportedVersion {
+                path:     path.to_string(),
+                found:    raw.version,
+                expected: SELECTOR_PACK_VERSION,
+            }
+            .into());
+        }
+
+        let expected: &str = provider.into();
+        if !raw.provider.eq_ignore_ascii_case(expected) {
+            return Err(SelectorPackError::ProviderMismatch {
+                path:     path.to_string(),
+                found:    raw.provider,
+                expected: expected.to_string(),
+            }
+            .into());
+        }
+
+        // Start from the compiled pack, then every field gets replaced by the file's value
+        let mut pack = Self::compiled(provider);
+        fill_set(&mut pack.css, &raw.css, SelectorKind::Css, path, &mut pack.alternatives)?;
+        fill_set(&mut pack.xpath, &raw.xpath, SelectorKind::Xpath, path, &mut pack.alternatives)?;
+        pack.version = raw.version;
+
+        Ok(pack)
+    }
+
+    /// The pack in its on-disk TOML form, handy as a starting point for an override.
+    pub fn to_toml(&self) -> Result<String> {
+        let raw = RawSelectorPack {
+            version:  self.version,
+            provider: <&str>::from(self.provider).to_string(),
+            css:      raw_set(&self.css, SelectorKind::Css, &self.alternatives),
+            xpath:    raw_set(&self.xpath, SelectorKind::Xpath, &self.alternatives),
+        };
+
+        toml::to_string_pretty(&raw)
+            .map_err(|e| Error::Generic(format!("Failed to serialize selector pack: {}", e)))
+    }
+}
+
+fn fill_set(
+    set: &mut SelectorSet,
+    raw: &RawSet,
+    kind: SelectorKind,
+    path: &str,
+    alternatives: &mut Alternatives,
+) -> Result<()> {
+    let kind_name: &str = kind.into();
+    let sections = SelectorSection::ALL.map(<&str>::from);
+
+    if let Some(unknown) = raw
+        .keys()
+        .find(|section| !sections.contains(&section.as_str()))
+    {
+        return Err(SelectorPackError::UnknownField {
+            path:    path.to_string(),
+            section: format!("{}.{}", kind_name, unknown),
+            field:   String::new(),
+        }
+        .into());
+    }
+
+    let mut fill = FillContext {
+        raw,
+        kind,
+        path,
+        alternatives,
+    };
+    fill.section(&mut set.login, SelectorSection::Login)?;
+    fill.section(&mut set.job_search, SelectorSection::JobSearch)?;
+    fill.section(&mut set.job_listing, SelectorSection::JobListing)?;
+    fill.section(&mut set.job_description, SelectorSection::JobDescription)?;
+    fill.section(&mut set.application, SelectorSection::Application)?;
+    Ok(())
+}
+
+struct FillContext<'a> {
+    raw:          &'a RawSet,
+    kind:         SelectorKind,
+    path:         &'a str,
+    alternatives: &'a mut Alternatives,
+}
+
+impl FillContext<'_> {
+    fn section<T: SelectorFields>(
+        &mut self,
+        selectors: &mut T,
+        section: SelectorSection,
+    ) -> Result<()> {
+        let path = self.path;
+        let section_name = format!("{}.{}", <&str>::from(self.kind), <&str>::from(section));
+        let Some(values) = self.raw.get(<&str>::from(section)) else {
+            return Err(SelectorPackError::MissingSection {
+                path:    path.to_string(),
+                section: section_name,
+            }
+            .into());
+        };
+
+        if let Some(unknown) = values
+            .keys()
+            .find(|field| !T::FIELDS.contains(&field.as_str()))
+        {
+            return Err(SelectorPackError::UnknownField {
+                path:    path.to_string(),
+                section: section_name,
+                field:   unknown.clone(),
+            }
+            .into());
+        }
+
+        for field in T::FIELDS {
+            let value = values.get(*field).ok_or_else(|| {
+                SelectorPackError::MissingField {
+                    path: path.to_string(),
+                    section: section_name.clone(),
+                    field,
+                }
+            })?;
+
+            let values = value.selectors();
+            if values.is_empty() || values.iter().any(|v| v.trim().is_empty()) {
+                return Err(SelectorPackError::EmptyField {
+                    path:    path.to_string(),
+                    section: section_name,
+                    field:   field.to_string(),
+                }
+                .into());
+            }
+
+            // Packs are loaded once at startup and live for the whole run,
+            // leaking keeps the selector structs as plain `&'static str`s
+            let mut values = values
+                .into_iter()
+                .map(|v| &*Box::leak(v.to_string().into_boxed_str()));
+
+            if let Some(primary) = values.next() {
+                selectors.set(field, primary);
+            }
+
+            let rest: Vec<&'static str> = values.collect();
+            if !rest.is_empty() {
+                self.alternatives
+                    .insert((self.kind, SelectorField { section, field }), rest);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn raw_set(set: &SelectorSet, kind: SelectorKind, alternatives: &Alternatives) -> RawSet {
+    SelectorSection::ALL
+        .into_iter()
+        .map(|section| {
+            let values = section
+                .fields()
+                .iter()
+                .filter_map(|field| {
+                    let field = SelectorField { section, field };
+                    let primary = set.get(field)?.to_string();
+                    let value = match alternatives.get(&(kind, field)) {
+                        Some(rest) => {
+                            RawSelector::Many(
+                                std::iter::once(primary)
+                                    .chain(rest.iter().map(|s| s.to_string()))
+                                    .collect(),
+                            )
+                        }
+                        None => RawSelector::One(primary),
+                    };
+                    Some((field.field.to_string(), value))
+                })
+                .collect();
+
+            (<&str>::from(section).to_string(), values)
+        })
+        .collect()
+}
+
+static LOADED_PACKS: OnceLock<HashMap<ProviderKind, SelectorPack>> = OnceLock::new();
+static COMPILED_PACKS: OnceLock<HashMap<ProviderKind, SelectorPack>> = OnceLock::new();
+
+/// The active selector pack for the provider,
+/// the one loaded by `load_selector_packs` if any, otherwise the compiled-in one.
+pub fn selector_pack(provider: ProviderKind) -> &'static SelectorPack {
+    if let Some(pack) = LOADED_PACKS.get().and_then(|packs| packs.get(&provider)) {
+        return pack;
+    }
+
+    COMPILED_PACKS
+        .get_or_init(|| {
+            [ProviderKind::LinkedIn, ProviderKind::Seek]
+                .into_iter()
+                .map(|provider| (provider, SelectorPack::compiled(provider)))
+                .collect()
+        })
+        .get(&provider)
+        .expect("Every provider has a compiled selector pack")
+}
+
+/// The pack file for the provider in `dir`, if there is one (`<provider>.toml` or `<provider>.json`).
+pub fn selector_pack_path(dir: &Path, provider: ProviderKind) -> Option<PathBuf> {
+    let name: &str = provider.into();
+    ["toml", "json"]
+        .into_iter()
+        .map(|ext| dir.join(format!("{}.{}", name, ext)))
+        .find(|path| path.is_file())
+}
+
+/// Loads every provider's pack override from `dir`, replacing the compiled-in selectors.
+///
+/// Must be called before the first `selector_pack` lookup to take effect,
+/// an invalid pack fails the whole load rather than silently running on the defaults.
+pub fn load_selector_packs(dir: &Path) -> Result<()> {
+    let mut packs = HashMap::new();
+
+    for provider in [ProviderKind::LinkedIn, ProviderKind::Seek] {
+        let Some(path) = selector_pack_path(dir, provider) else {
+            debug!("No selector pack override for {} in {}", provider, dir.display());
+            continue;
+        };
+
+        let pack = SelectorPack::from_file(&path, provider)?;
+        info!("Loaded {} selector pack v{} from {}", provider, pack.version, path.display());
+        packs.insert(provider, pack);
+    }
+
+    if LOADED_PACKS.set(packs).is_err() {
+        warn!("Selector packs were already loaded, ignoring {}", dir.display());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROVIDERS: [ProviderKind; 2] = [ProviderKind::LinkedIn, ProviderKind::Seek];
+    const KINDS: [SelectorKind; 2] = [SelectorKind::Css, SelectorKind::Xpath];
+
+    /// The compiled pack in its on-disk form, to break in different ways.
+    fn raw(provider: ProviderKind) -> RawSelectorPack {
+        toml::from_str(&SelectorPack::compiled(provider).to_toml().unwrap()).unwrap()
+    }
+
+    fn pack_error(raw: RawSelectorPack) -> SelectorPackError {
+        match SelectorPack::from_raw(raw, ProviderKind::LinkedIn, "linkedin.toml") {
+            Err(Error::SelectorPackError(e)) => e,
+            other => panic!("Expected a selector pack error, got {:?}", other),
+        }
+    }
+
+    fn set(
+        raw: &mut RawSelectorPack,
+        kind: SelectorKind,
+        section: &str,
+        field: &str,
+        value: RawSelector,
+    ) {
+        let sections = match kind {
+            SelectorKind::Css => &mut raw.css,
+            SelectorKind::Xpath => &mut raw.xpath,
+        };
+        sections
+            .get_mut(section)
+            .unwrap()
+            .insert(field.to_string(), value);
+    }
+
+    fn many(selectors: &[&str]) -> RawSelector {
+        RawSelector::Many(selectors.iter().map(|s| s.to_string()).collect())
+    }
+
+    /// A fresh directory under the system temp dir, emptied first.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("job_apply-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn assert_same_selectors(pack: &SelectorPack, expected: &SelectorPack) {
+        for kind in KINDS {
+            for section in SelectorSection::ALL {
+                for field in section.fields() {
+                    let field = SelectorField { section, field };
+                    assert_eq!(
+                        pack.by_kind(kind).get(field),
+                        expected.by_kind(kind).get(field),
+                        "{:?} {:?}",
+                        kind,
+                        field
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn compiled_packs_round_trip() {
+        for provider in PROVIDERS {
+            let compiled = SelectorPack::compiled(provider);
+            let pack = SelectorPack::from_raw(raw(provider), provider, "pack.toml").unwrap();
+
+            assert_eq!(pack.provider, provider);
+            assert_eq!(pack.version, SELECTOR_PACK_VERSION);
+            assert!(pack.alternatives.is_empty(), "{}", provider);
+            assert_same_selectors(&pack, &compiled);
+            assert_eq!(pack.to_toml().unwrap(), compiled.to_toml().unwrap(), "{}", provider);
+        }
+    }
+
+    #[test]
+    fn missing_sections_and_fields() {
+        let mut missing_section = raw(ProviderKind::LinkedIn);
+        missing_section.xpath.remove("application");
+        assert!(matches!(
+            pack_error(missing_section),
+            SelectorPackError::MissingSection { section, .. } if section == "xpath.application"
+        ));
+
+        let mut missing_field = raw(ProviderKind::LinkedIn);
+        missing_field
+            .css
+            .get_mut("login")
+            .unwrap()
+            .remove("username_field");
+        assert!(matches!(
+            pack_error(missing_field),
+            SelectorPackError::MissingField { section, field: "username_field", .. }
+                if section == "css.login"
+        ));
+    }
+
+    #[test]
+    fn unknown_sections_and_fields() {
+        let mut unknown_field = raw(ProviderKind::LinkedIn);
+        set(
+            &mut unknown_field,
+            SelectorKind::Css,
+            "login",
+            "sso_button",
+            RawSelector::One("button.sso".to_string()),
+        );
+        assert!(matches!(
+            pack_error(unknown_field),
+            SelectorPackError::UnknownField { section, field, .. }
+                if section == "css.login" && field == "sso_button"
+        ));
+
+        let mut unknown_section = raw(ProviderKind::LinkedIn);
+        unknown_section
+            .xpath
+            .insert("profile".to_string(), BTreeMap::new());
+        assert!(matches!(
+            pack_error(unknown_section),
+            SelectorPackError::UnknownField { section, .. } if section == "xpath.profile"
+        ));
+    }
+
+    #[test]
+    fn empty_selectors() {
+        let cases = [
+            RawSelector::One(String::new()),
+            RawSelector::One("   ".to_string()),
+            many(&[]),
+            many(&["div.job-card-container", ""]),
+        ];
+
+        for value in cases {
+            let mut raw = raw(ProviderKind::LinkedIn);
+            set(&mut raw, SelectorKind::Css, "job_listing", "job_card", value.clone());
+            assert!(
+                matches!(
+                    pack_error(raw),
+                    SelectorPackError::EmptyField { section, field, .. }
+                        if section == "css.job_listing" && field == "job_card"
+                ),
+                "{:?}",
+                value
+            );
+        }
+    }
+
+    #[test]
+    fn version_and_provider_mismatch() {
+        let mut newer = raw(ProviderKind::LinkedIn);
+        newer.version = SELECTOR_PACK_VERSION + 1;
+        assert!(matches!(
+            pack_error(newer),
+            SelectorPackError::UnsupportedVersion { found, expected: SELECTOR_PACK_VERSION, .. }
+                if found == SELECTOR_PACK_VERSION + 1
+        ));
+
+        assert!(matches!(
+            pack_error(raw(ProviderKind::Seek)),
+            SelectorPackError::ProviderMismatch { found, expected, .. }
+                if found == "seek" && expected == "linkedin"
+        ));
+
+        // The provider name isn't case sensitive
+        let mut shouting = raw(ProviderKind::LinkedIn);
+        shouting.provider = "LINKEDIN".to_string();
+        assert!(SelectorPack::from_raw(shouting, ProviderKind::LinkedIn, "linkedin.toml").is_ok());
+    }
+
+    #[test]
+    fn alternatives_keep_their_order() {
+        let mut raw = raw(ProviderKind::LinkedIn);
+        set(
+            &mut raw,
+            SelectorKind::Css,
+            "job_listing",
+            "job_card",
+            many(&["li.job-card", "div.job-card-container", "div[data-job-id]"]),
+        );
+        let pack = SelectorPack::from_raw(raw, ProviderKind::LinkedIn, "linkedin.toml").unwrap();
+
+        let field = SelectorField::job_listing("job_card");
+        assert_eq!(pack.css.get(field), Some("li.job-card"));
+        assert_eq!(
+            pack.alternatives.get(&(SelectorKind::Css, field)),
+            Some(&vec!["div.job-card-container", "div[data-job-id]"])
+        );
+        assert_eq!(pack.alternatives.len(), 1);
+
+        // Written back out as the same array
+        let again = SelectorPack::from_raw(
+            toml::from_str(&pack.to_toml().unwrap()).unwrap(),
+            ProviderKind::LinkedIn,
+            "linkedin.toml",
+        )
+        .unwrap();
+        assert_eq!(again.alternatives, pack.alternatives);
+        assert_same_selectors(&again, &pack);
+    }
+
+    #[test]
+    fn candidates_follow_the_kind_order() {
+        type Expected = (SelectorKind, &'static str);
+
+        let mut raw = raw(ProviderKind::LinkedIn);
+        set(
+            &mut raw,
+            SelectorKind::Css,
+            "job_listing",
+            "job_card",
+            many(&["div.job-card-container", "li.job-card"]),
+        );
+        set(
+            &mut raw,
+            SelectorKind::Xpath,
+            "job_listing",
+            "job_card",
+            many(&["//li[@data-job-id]", "//div[@data-job-id]"]),
+        );
+        let pack = SelectorPack::from_raw(raw, ProviderKind::LinkedIn, "linkedin.toml").unwrap();
+        let field = SelectorField::job_listing("job_card");
+
+        let css_first: [Expected; 4] = [
+            (SelectorKind::Css, "div.job-card-container"),
+            (SelectorKind::Css, "li.job-card"),
+            (SelectorKind::Xpath, "//li[@data-job-id]"),
+            (SelectorKind::Xpath, "//div[@data-job-id]"),
+        ];
+        let xpath_first = [css_first[2], css_first[3], css_first[0], css_first[1]];
+        let cases: [(&[SelectorKind], &[Expected]); 3] = [
+            (&[SelectorKind::Css, SelectorKind::Xpath], &css_first),
+            (&[SelectorKind::Xpath, SelectorKind::Css], &xpath_first),
+            (&[SelectorKind::Css], &css_first[..2]),
+        ];
+
+        for (order, expected) in cases {
+            let candidates = pack.candidates(field, order);
+            let found: Vec<Expected> = candidates.iter().map(|c| (c.kind, c.selector)).collect();
+            assert_eq!(found, expected, "{:?}", order);
+
+            let ranks: Vec<usize> = candidates.iter().map(|c| c.rank).collect();
+            assert_eq!(ranks, (0..expected.len()).collect::<Vec<_>>(), "{:?}", order);
+        }
+    }
+
+    #[test]
+    fn candidates_skip_repeated_selectors() {
+        let mut raw = raw(ProviderKind::LinkedIn);
+        set(
+            &mut raw,
+            SelectorKind::Css,
+            "job_listing",
+            "job_card",
+            many(&["div.job-card", "li.job-card", "div.job-card", "li.job-card"]),
+        );
+        let pack = SelectorPack::from_raw(raw, ProviderKind::LinkedIn, "linkedin.toml").unwrap();
+
+        let candidates =
+            pack.candidates(SelectorField::job_listing("job_card"), &[SelectorKind::Css]);
+        let selectors: Vec<&str> = candidates.iter().map(|c| c.selector).collect();
+        assert_eq!(selectors, ["div.job-card", "li.job-card"]);
+        assert_eq!(candidates[1].rank, 1);
+
+        // Unknown fields have nothing to try
+        assert!(
+            pack.candidates(SelectorField::job_listing("nope"), &KINDS)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn json_and_toml_files() {
+        let dir = temp_dir("pack-files");
+        let raw = raw(ProviderKind::Seek);
+
+        let toml_path = dir.join("seek.toml");
+        std::fs::write(&toml_path, toml::to_string_pretty(&raw).unwrap()).unwrap();
+        let json_path = dir.join("seek.json");
+        std::fs::write(&json_path, serde_json::to_string_pretty(&raw).unwrap()).unwrap();
+
+        let compiled = SelectorPack::compiled(ProviderKind::Seek);
+        for path in [&toml_path, &json_path] {
+            let pack = SelectorPack::from_file(path, ProviderKind::Seek).unwrap();
+            assert_eq!(pack.source, PackSource::File(path.clone()));
+            assert_same_selectors(&pack, &compiled);
+        }
+
+        // The extension decides the format
+        let wrong_format = dir.join("wrong.json");
+        std::fs::copy(&toml_path, &wrong_format).unwrap();
+        let missing = dir.join("missing.toml");
+        let cases = [(&wrong_format, "parse"), (&missing, "read")];
+
+        for (path, expected) in cases {
+            let err = SelectorPack::from_file(path, ProviderKind::Seek).unwrap_err();
+            let found = match err {
+                Error::SelectorPackError(SelectorPackError::Parse { .. }) => "parse",
+                Error::SelectorPackError(SelectorPackError::Read { .. }) => "read",
+                other => panic!("Unexpected error for {}: {:?}", path.display(), other),
+            };
+            assert_eq!(found, expected, "{}", path.display());
+        }
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    /// The only test that loads packs, they're process wide once loaded.
+    #[test]
+    fn load_selector_packs_from_dir() {
+        let dir = temp_dir("pack-dir");
+        assert_eq!(selector_pack_path(&dir, ProviderKind::LinkedIn), None);
+
+        // A bad pack fails the whole load, before anything is replaced
+        let mut broken = raw(ProviderKind::LinkedIn);
+        broken.css.remove("login");
+        let path = dir.join("linkedin.toml");
+        std::fs::write(&path, toml::to_string_pretty(&broken).unwrap()).unwrap();
+        assert!(load_selector_packs(&dir).is_err());
+        assert_eq!(selector_pack(ProviderKind::LinkedIn).source, PackSource::Compiled);
+
+        // Same selectors as the compiled pack, so the other tests don't notice
+        std::fs::write(
+            &path,
+            SelectorPack::compiled(ProviderKind::LinkedIn)
+                .to_toml()
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(selector_pack_path(&dir, ProviderKind::LinkedIn), Some(path.clone()));
+        load_selector_packs(&dir).unwrap();
+
+        assert_eq!(selector_pack(ProviderKind::LinkedIn).source, PackSource::File(path));
+        assert_eq!(selector_pack(ProviderKind::Seek).source, PackSource::Compiled);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,7 +1,10 @@
 use fantoccini::elements::Element;
 
-use crate::prelude::Result;
-use crate::states::Transition;
+use crate::application::{ApplicationOutcome, check_quotas};
+use crate::db::mark_job_failed;
+use crate::output::ReportRow;
+use crate::prelude::*;
+use crate::states::{JobListingSearch, RunContext, SearchState, States, Transition};
 
 #[derive(Debug, Clone)]
 pub enum ActionState<'a> {
@@ -16,23 +19,91 @@ pub enum ActionState<'a> {
         element: &'a Element,
         key:     fantoccini::key::Key,
     },
+    /// Runs the application wizard for the job under the cursor.
+    Apply(Box<JobListingSearch>),
 }
 
 #[async_trait::async_trait]
 impl<'a> Transition for ActionState<'a> {
-    async fn execute(&self) -> Result<()> {
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>> {
         match self {
             ActionState::Click { element } => {
-                // Implement logic to click the element identified by locator
-                Ok(())
+                ctx.provider
+                    .with_action(ActionState::Click {
+                        element: element.clone(),
+                    })
+                    .await?;
+                Ok(ctx.next_queued())
             }
             ActionState::InputText { element, input } => {
-                // Implement logic to input text into the element identified by locator
-                Ok(())
+                ctx.provider
+                    .with_action(ActionState::InputText {
+                        element,
+                        input: input.clone(),
+                    })
+                    .await?;
+                Ok(ctx.next_queued())
             }
             ActionState::InputKey { element, key } => {
-                // Implement logic to input keys into the element identified by locator
-                Ok(())
+                ctx.provider
+                    .with_action(ActionState::InputKey { element, key: *key })
+                    .await?;
+                Ok(ctx.next_queued())
+            }
+            ActionState::Apply(search) => {
+                let Some(job) = search.current() else {
+                    return Ok(States::Search(SearchState::FindJobListings(search.clone())));
+                };
+
+                // The job stays pending, a later (or resumed) run can apply to it
+                if let Some(hit) = check_quotas(ctx).await? {
+                    let Some(mut pause) = hit.pause(&ctx.config.quotas) else {
+                        warn!("{}, stopping before job {}.", hit, job.job_id);
+                        return Ok(States::QuotaReached);
+                    };
+                    pause.resume =
+                        Some(Box::new(States::Action(ActionState::Apply(search.clone()))));
+                    return Ok(States::Paused(pause));
+                }
+
+                let outcome = ctx.wizard().apply(job).await;
+                ctx.reports.record(
+                    &ReportRow::for_card(ctx.provider.as_ref(), &job.card).with_outcome(&outcome),
+                );
+
+                match outcome {
+                    Ok(ApplicationOutcome::Submitted) => {
+                        info!("Applied to job {}.", job.job_id);
+                        ctx.counters.applied += 1;
+                    }
+                    Ok(ApplicationOutcome::DryRun(_)) => {
+                        info!("Dry run, would have applied to job {}.", job.job_id);
+                        ctx.counters.dry_runs += 1;
+                    }
+                    Ok(outcome) => {
+                        warn!("Did not apply to job {}: {:?}", job.job_id, outcome);
+                        ctx.counters.failed += 1;
+                    }
+                    Err(e) => {
+                        error!("Application for job {} encountered an error: {}", job.job_id, e);
+                        mark_job_failed(&ctx.pool, job.job_id, &e).await;
+                        ctx.counters.errors += 1;
+                    }
+                }
+
+                let mut search = search.clone().next_job();
+
+                // Providers that open the form in place (Seek) reload the results page on the
+                // way back, leaving the remaining card elements stale
+                if let Some(next) = search.current()
+                    && next.element.is_displayed().await.is_err()
+                {
+                    debug!("Job cards went stale after applying, re-reading the results page.");
+                    search.stale = true;
+                    search.scanned = false;
+                }
+
+                Ok(States::Search(SearchState::FindJobListings(search)))
             }
         }
     }
@@ -0,0 +1,131 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use fantoccini::Client;
+use fantoccini::elements::Element;
+use sqlx::SqlitePool;
+
+use crate::application::ApplicationWizard;
+use crate::config::AppConfig;
+use crate::db::update_run_progress;
+use crate::filters::FilterRules;
+use crate::output::OutputReports;
+use crate::prelude::*;
+use crate::providers::{Provider, SelectorKind};
+use crate::states::{JobListingSearch, States};
+
+/// Everything the states share for the duration of a run,
+/// handed to each `Transition::execute` in turn by the state machine.
+#[derive(Debug)]
+pub struct RunContext {
+    pub client:        Client,
+    pub provider:      Arc<dyn Provider + Send + Sync>,
+    pub config:        AppConfig,
+    pub pool:          SqlitePool,
+    pub selector_kind: SelectorKind,
+    pub counters:      RunCounters,
+    pub reports:       OutputReports,
+    pub filters:       Arc<FilterRules>,
+    /// Only collect and validate jobs, leaving valid ones pending instead of applying (`scan`).
+    pub scan_only:     bool,
+    /// The `db.run_progress` row the run's progress is saved to, `None` when it isn't tracked.
+    pub run_id:        Option<i64>,
+
+    /// Elements found by the last `SearchState::FindBy`/`FindAllBy`.
+    pub found:  Vec<Element>,
+    /// States to run after a single-step state (navigate, find, click, etc.) completes.
+    pub queued: VecDeque<States<'static>>,
+}
+
+/// Running totals for the job loop, reported in the run summary.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunCounters {
+    pub pages:          usize,
+    pub listings_found: usize,
+    pub validated:      usize,
+    pub skipped:        usize,
+    pub applied:        usize,
+    /// Applications filled up to Submit without submitting (`ApplicationConfig::dry_run`).
+    pub dry_runs:       usize,
+    pub failed:         usize,
+    pub errors:         usize,
+}
+
+impl RunContext {
+    pub fn new(
+        client: Client,
+        provider: Arc<dyn Provider + Send + Sync>,
+        config: AppConfig,
+        pool: SqlitePool,
+        selector_kind: SelectorKind,
+    ) -> Result<Self> {
+        let reports = OutputReports::from(&config.output);
+        let filters = Arc::new(FilterRules::new(&config.filters)?);
+        Ok(Self {
+            client,
+            provider,
+            config,
+            pool,
+            selector_kind,
+            counters: RunCounters::default(),
+            reports,
+            filters,
+            scan_only: false,
+            run_id: None,
+            found: vec![],
+            queued: VecDeque::new(),
+        })
+    }
+
+    /// `GeneralConfig::maximum_iterations`, unbounded when unset.
+    pub fn max_iterations(&self) -> usize {
+        self.config.general.maximum_iterations.unwrap_or(usize::MAX)
+    }
+
+    /// The next queued state, or `States::Completed` when there's nothing left to do.
+    pub fn next_queued(&mut self) -> States<'static> {
+        self.queued.pop_front().unwrap_or(States::Completed)
+    }
+
+    /// Saves how far through the search results the run is, so it can be resumed from there.
+    /// `page_done` when every listing on the current page has been handled.
+    ///
+    /// Only logs when that fails, losing track of the progress shouldn't stop the run.
+    pub async fn save_progress(&self, search: &JobListingSearch, page_done: bool) {
+        let Some(run_id) = self.run_id else {
+            return;
+        };
+
+        // Partway through a page only the pages before it are done
+        let (pages_done, jobs_done) = if page_done {
+            (search.pages_read, search.jobs_read)
+        } else {
+            (
+                search.pages_read.saturating_sub(1),
+                search.jobs_read.saturating_sub(search.total_found),
+            )
+        };
+
+        if let Err(e) = update_run_progress(
+            &self.pool,
+            run_id,
+            i64::from(pages_done),
+            jobs_done as i64,
+            search.last_handled,
+        )
+        .await
+        {
+            warn!("Failed to save the progress of run {}: {}", run_id, e);
+        }
+    }
+
+    pub fn wizard(&self) -> ApplicationWizard {
+        ApplicationWizard::new(
+            self.client.clone(),
+            Arc::clone(&self.provider),
+            self.pool.clone(),
+            &self.config,
+            self.selector_kind,
+        )
+    }
+}
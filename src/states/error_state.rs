@@ -1,20 +1,34 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use crate::prelude::*;
-use crate::states::Transition;
+use crate::states::{RunContext, States, Transition};
 
+#[derive(Clone)]
 pub struct ErrorState {
     pub message: String,
-    pub action:  Box<dyn Fn() + Send + Sync>,
+    pub action:  Arc<dyn Fn() + Send + Sync>,
+}
+
+impl ErrorState {
+    /// An error with nothing to run beyond logging it.
+    pub fn new<S: Into<String>>(message: S) -> Self {
+        Self {
+            message: message.into(),
+            action:  Arc::new(|| {}),
+        }
+    }
 }
 
 #[async_trait::async_trait]
 impl Transition for ErrorState {
-    async fn execute(&self) -> Result<()> {
+    async fn execute(&self, _ctx: &mut RunContext) -> Result<States<'static>> {
         error!("Error occurred: {}", self.message);
         (self.action)();
         // Implement additional error handling logic if needed
-        Ok(())
+
+        // Terminal, there's nowhere to go from here
+        Ok(States::Error(self.clone()))
     }
 
     async fn current_state(&self) -> &dyn Transition {
@@ -1,25 +1,36 @@
 mod action;
+mod context;
 mod error_state;
 mod navigate;
 mod paused;
 mod search;
 mod validate;
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use fantoccini::Locator;
 
 use crate::prelude::*;
 pub use crate::states::action::ActionState;
-use crate::states::error_state::ErrorState;
-use crate::states::navigate::NavigateState;
-use crate::states::paused::PausedState;
-use crate::states::search::{FindBy, JobListingSearch, SearchState};
-use crate::states::validate::{InvalidReason, JobValidation, ValidateState, ValidationResult};
+pub use crate::states::context::{RunContext, RunCounters};
+pub use crate::states::error_state::ErrorState;
+pub use crate::states::navigate::{ApplicationElements, NavigateState};
+pub use crate::states::paused::{PausedQuestion, PausedState, read_line};
+pub use crate::states::search::{FindBy, JobListingSearch, SearchState};
+pub use crate::states::validate::{
+    InvalidReason,
+    JobValidation,
+    ValidateState,
+    ValidationResult,
+    check_job_details,
+    record_rejections,
+};
 
 #[async_trait::async_trait]
 pub trait Transition {
-    async fn execute(&self) -> Result<()>;
+    /// Performs the state's work and returns the state to move to next.
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>>;
 
     async fn current_state(&self) -> &dyn Transition;
 }
@@ -38,21 +49,43 @@ pub enum States<'a> {
     /// Validating a job (Easy Apply available, not already applied, etc.)
     Validate(ValidateState),
 
+    /// Every job listing has been processed
+    Completed,
+
     /// The maximum number of iterations has been reached
     MaxIterationsReached,
 
+    /// An application quota is used up, see `QuotaConfig`
+    QuotaReached,
+
     /// Requires user intervention to continue
     Paused(PausedState),
 
     /// Something has gone catastrophically wrong
     /// from which we cannot simply ask the user to resume
-    Error, // This originall had (ErrorState) but it's not Clone so
+    Error(ErrorState),
+}
+
+impl From<&States<'_>> for &str {
+    fn from(state: &States<'_>) -> Self {
+        match state {
+            States::Search(_) => "search",
+            States::Navigate(_) => "navigate",
+            States::Action(_) => "action",
+            States::Validate(_) => "validate",
+            States::Completed => "completed",
+            States::MaxIterationsReached => "max_iterations_reached",
+            States::QuotaReached => "quota_reached",
+            States::Paused(_) => "paused",
+            States::Error(_) => "error",
+        }
+    }
 }
 
 #[async_trait::async_trait]
 impl Transition for States<'_> {
-    async fn execute(&self) -> Result<()> {
-        self.execute().await
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>> {
+        self.execute(ctx).await
     }
 
     async fn current_state(&self) -> &dyn Transition {
@@ -61,27 +94,29 @@ impl Transition for States<'_> {
 }
 
 impl States<'_> {
-    async fn execute(&self) -> Result<()> {
+    /// States the machine stops at, executing them again leads nowhere.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            States::Completed
+                | States::MaxIterationsReached
+                | States::QuotaReached
+                | States::Error(_)
+        )
+    }
+
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>> {
         let fut = Box::pin(async move {
             match self {
-                States::Search(state) => state.execute().await,
-                States::Navigate(state) => state.execute().await,
-                States::Action(state) => state.execute().await,
-                States::Validate(state) => state.execute().await,
-                States::MaxIterationsReached => {
-                    todo!()
-                }
-                States::Paused(state) => state.execute().await,
-                States::Error => {
-                    todo!()
-                    // let error_state = ErrorState {
-                    //     message: "An unrecoverable error has occurred.".to_string(),
-                    //     action:  Box::new(|| {
-                    //         error!("Executing error handling action.");
-                    //     }),
-                    // };
-                    // error_state.execute().await
-                }
+                States::Search(state) => state.execute(ctx).await,
+                States::Navigate(state) => state.execute(ctx).await,
+                States::Action(state) => state.execute(ctx).await,
+                States::Validate(state) => state.execute(ctx).await,
+                States::Completed => Ok(States::Completed),
+                States::MaxIterationsReached => Ok(States::MaxIterationsReached),
+                States::QuotaReached => Ok(States::QuotaReached),
+                States::Paused(state) => state.execute(ctx).await,
+                States::Error(state) => state.execute(ctx).await,
             }
         });
 
@@ -90,68 +125,124 @@ impl States<'_> {
 
     async fn current_state(&self) -> &dyn Transition {
         Box::pin(async move {
-            (match self {
-                States::Search(state) => state.current_state(),
-                States::Navigate(state) => state.current_state(),
-                States::Action(state) => state.current_state(),
-                States::Validate(state) => state.current_state(),
-                States::MaxIterationsReached => {
-                    todo!()
-                }
-                States::Paused(state) => state.current_state(),
-                States::Error => {
-                    todo!()
-                }
-            })
-            .await
+            match self {
+                States::Search(state) => state.current_state().await,
+                States::Navigate(state) => state.current_state().await,
+                States::Action(state) => state.current_state().await,
+                States::Validate(state) => state.current_state().await,
+                States::Paused(state) => state.current_state().await,
+                States::Error(state) => state.current_state().await,
+                States::Completed | States::MaxIterationsReached | States::QuotaReached => self,
+            }
         })
         .await
     }
 }
 
-// This funciton signature is open to changes as we work through early development states
-//
-pub async fn start_state_machine<S>(
-    starting_url: &S,
-    max_iterations: usize, // max_attempts: N, // later we will impl .some form of exponential backoff here
-) -> Result<()>
-where
-    S: AsRef<str> + Send + Sync + Clone + 'static,
-{
-    let starting_state = States::Navigate(NavigateState::ToUrl {
-        attempt: 0,
-        url:     starting_url.as_ref().to_string(),
-    });
-
-    starting_state.execute().await?;
-
-    for idx in 0..max_iterations {
-        let starting_state = starting_state.clone();
-
-        let handle = tokio::spawn(async move {
-            let _ = starting_state.execute().await;
-        })
-        .await;
+/// What a state machine run got through, logged once it stops.
+#[derive(Debug, Clone, Default)]
+pub struct RunSummary {
+    pub iterations:  usize,
+    /// How many times each state was entered, keyed by the state's name.
+    pub visits:      BTreeMap<&'static str, usize>,
+    pub counters:    RunCounters,
+    /// Name of the state the run stopped in.
+    pub final_state: &'static str,
+    pub error:       Option<String>,
+}
 
-        match handle {
-            Ok(_) => (),
-            Err(e) => {
-                // TODO: [state] : We need a way to programmatically update our
-                // state to say we found an error, so on the following loop iteration
-                // we can handle it
+impl RunSummary {
+    fn observe(&mut self, state: &States<'_>) {
+        let name: &'static str = state.into();
+        *self.visits.entry(name).or_default() += 1;
+        self.final_state = name;
 
-                error!("Task join error: {}", e);
-                // return Err(Error::from(e)); // for now we can crash/fail
-            }
+        if let States::Error(error) = state {
+            self.error = Some(error.message.clone());
+        }
+    }
+
+    /// Whether the run got to the end of its search, rather than stopping on an error or limit.
+    pub fn completed(&self) -> bool {
+        self.error.is_none() && self.final_state == <&str>::from(&States::Completed)
+    }
+
+    pub fn log(&self) {
+        let visits = self
+            .visits
+            .iter()
+            .map(|(name, count)| format!("{}: {}", name, count))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let c = &self.counters;
+
+        info!(
+            "State machine stopped in '{}' after {} iterations. \
+             Pages: {} | Listings: {} | Valid: {} | Skipped: {} | Applied: {} | Dry runs: {} | \
+             Not applied: {} | Errors: {}",
+            self.final_state,
+            self.iterations,
+            c.pages,
+            c.listings_found,
+            c.validated,
+            c.skipped,
+            c.applied,
+            c.dry_runs,
+            c.failed,
+            c.errors
+        );
+        debug!("States visited: {}", visits);
+        if let Some(error) = &self.error {
+            error!("State machine error: {}", error);
         }
+    }
+}
 
-        if idx + 1 >= max_iterations {
-            warn!("Maximum iterations ({}) reached, stopping state machine.", max_iterations);
+/// Runs the state machine from `starting_state`, executing each state and moving on to
+/// the one it returns, until a terminal state is reached.
+///
+/// Every executed state counts as one iteration (a job takes a few), once
+/// `GeneralConfig::maximum_iterations` have run the machine stops in `States::MaxIterationsReached`.
+/// There's no limit by default. An `Err` from a state ends the run in
+/// `States::Error`.
+pub async fn start_state_machine(
+    ctx: &mut RunContext,
+    starting_state: States<'static>,
+) -> RunSummary {
+    let max_iterations = ctx.max_iterations();
+    let mut summary = RunSummary::default();
+    let mut state = starting_state;
+
+    loop {
+        summary.observe(&state);
+
+        if state.is_terminal() {
             break;
         }
 
-        // tokio::try_join!(handle)?; // Wait for the state machine to complete
+        if summary.iterations >= max_iterations {
+            warn!("Maximum iterations ({}) reached, stopping state machine.", max_iterations);
+            state = States::MaxIterationsReached;
+            continue;
+        }
+
+        summary.iterations += 1;
+        trace!("Iteration {}: executing '{}' state", summary.iterations, <&str>::from(&state));
+
+        state = match state.execute(ctx).await {
+            Ok(next) => next,
+            Err(e) => {
+                ctx.counters.errors += 1;
+                States::Error(ErrorState::new(e.to_string()))
+            }
+        };
+    }
+
+    if let States::Error(error) = &state {
+        let _ = error.execute(ctx).await;
     }
 
-    Ok(())
+    summary.counters = ctx.counters;
+    summary.log();
+    summary
 }
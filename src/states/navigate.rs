@@ -1,34 +1,75 @@
 // use crate::portal::Portal;
-use crate::prelude::Result;
-use crate::states::Transition;
+use crate::pre_fsm::wait_millis;
+use crate::prelude::*;
+use crate::selectors::SelectorField;
+use crate::states::{JobListingSearch, RunContext, SearchState, States, Transition};
 
+/// How many times `NavigateState::ToUrl` tries a URL before giving up.
+const MAX_NAVIGATION_ATTEMPTS: u8 = 3;
+
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum NavigateState {
-    ToUrl { attempt: u8, url: String },
+    ToUrl {
+        attempt: u8,
+        url:     String,
+    },
     ToElement(ValidElements),
-    // ToJobSearch { portal: Portal, keywords: String },
+    /// Waits for the job search results, which the pre-FSM steps have already opened
+    /// (job board, keywords, filters), then hands over to the listing search.
+    ToJobSearch(Box<JobListingSearch>),
 }
 
 #[async_trait::async_trait]
 impl Transition for NavigateState {
-    async fn execute(&self) -> Result<()> {
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>> {
         match self {
-            NavigateState::ToUrl { attempt: _, url: _ } => {
-                // Implement logic to navigate to the specified URL
-                Ok(())
+            NavigateState::ToUrl { attempt, url } => {
+                match ctx.client.goto(url).await {
+                    Ok(()) => Ok(ctx.next_queued()),
+                    Err(e) if attempt + 1 < MAX_NAVIGATION_ATTEMPTS => {
+                        warn!("Failed to navigate to '{}' (attempt {}): {}", url, attempt + 1, e);
+                        wait_millis(1000).await;
+                        Ok(States::Navigate(NavigateState::ToUrl {
+                            attempt: attempt + 1,
+                            url:     url.clone(),
+                        }))
+                    }
+                    Err(e) => {
+                        Err(Error::Generic(format!(
+                            "Failed to navigate to '{}' after {} attempts: {}",
+                            url, MAX_NAVIGATION_ATTEMPTS, e
+                        )))
+                    }
+                }
+            }
+            NavigateState::ToElement(element) => {
+                let found = ctx
+                    .provider
+                    .find_field(&ctx.client, element.field()?)
+                    .await?
+                    .into_inner();
+
+                let arg = serde_json::to_value(&found).map_err(|e| {
+                    Error::Generic(format!("Failed to serialize element {:?}: {}", element, e))
+                })?;
+                ctx.client
+                    .execute("arguments[0].scrollIntoView({block: 'center'});", vec![arg])
+                    .await
+                    .map_err(|e| {
+                        Error::Generic(format!("Failed to scroll to element {:?}: {}", element, e))
+                    })?;
+
+                ctx.found = vec![found];
+                Ok(ctx.next_queued())
+            }
+            NavigateState::ToJobSearch(search) => {
+                ctx.provider
+                    .find_field(&ctx.client, SelectorField::job_listing("job_list_container"))
+                    .await?;
+
+                Ok(States::Search(SearchState::FindJobListings(search.clone())))
             }
-            NavigateState::ToElement(_element) => {
-                // Implement logic to navigate to the specified element
-                Ok(())
-            } // NavigateState::ToJobSearch { portal: _, keywords: _ } => {
-              // Navigate to portal's search URL, enter keywords, submit, wait for listings
-              // 1. client.goto(portal.search_url())
-              // 2. wait for search input element (portal.selectors().search_input)
-              // 3. clear input, type keywords
-              // 4. submit search (press Enter)
-              // 5. wait for job listings element (portal.selectors().job_listing)
-              //     Ok(())
-              // }
         }
     }
 
@@ -44,6 +85,40 @@ pub enum ValidElements {
     Application(ApplicationElements),
 }
 
+impl ValidElements {
+    /// The provider's selector field for the element, form inputs live inside a
+    /// form field container so they have no page-level selector.
+    fn field(&self) -> Result<SelectorField> {
+        let field = match self {
+            ValidElements::Login(LoginElements::LoginUsername) => {
+                SelectorField::login("username_field")
+            }
+            ValidElements::Login(LoginElements::LoginPassword) => {
+                SelectorField::login("password_field")
+            }
+            ValidElements::Job(JobElements::SearchInput) => {
+                SelectorField::job_search("search_input")
+            }
+            ValidElements::Job(JobElements::Listings) => {
+                SelectorField::job_listing("job_list_container")
+            }
+            ValidElements::Job(JobElements::Description(DescriptionElements::ApplyButton)) => {
+                SelectorField::job_listing("easy_apply_button")
+            }
+            ValidElements::Job(JobElements::Description(DescriptionElements::Submit)) => {
+                SelectorField::application("submit_button")
+            }
+            ValidElements::Application(element) => {
+                return Err(Error::Generic(format!(
+                    "No page-level selector for application element {:?}",
+                    element
+                )));
+            }
+        };
+        Ok(field)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum LoginElements {
     LoginUsername,
@@ -63,14 +138,29 @@ pub enum DescriptionElements {
     Submit,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ApplicationElements {
     BulletPoint,
     TextField,
     NumberField,
     TickBox,
+    Dropdown,
     ResumeUpload,
 
     // This gets special treatment due to having a config option to toggle it
     FollowCompanyTickBox,
 }
+
+impl From<ApplicationElements> for &str {
+    fn from(element: ApplicationElements) -> Self {
+        match element {
+            ApplicationElements::BulletPoint => "bullet_point",
+            ApplicationElements::TextField => "text",
+            ApplicationElements::NumberField => "number",
+            ApplicationElements::TickBox => "tick_box",
+            ApplicationElements::Dropdown => "dropdown",
+            ApplicationElements::ResumeUpload => "resume_upload",
+            ApplicationElements::FollowCompanyTickBox => "follow_company_tick_box",
+        }
+    }
+}
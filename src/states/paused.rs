@@ -1,20 +1,168 @@
+use std::io::Write as _;
+use std::sync::OnceLock;
+
+use tokio::sync::{Mutex, mpsc};
+use tokio::time::Duration;
+
+use crate::application::FormField;
 use crate::prelude::*;
-use crate::states::Transition;
+use crate::states::{ApplicationElements, ErrorState, RunContext, States, Transition};
 
 #[derive(Debug, Clone)]
 pub struct PausedState {
     pub reason:        String,
     pub duration_secs: u64,
     pub can_resume:    bool,
+    /// When set, the pause is waiting on the user to answer this form question.
+    pub question:      Option<PausedQuestion>,
+    /// The state to carry on with once the user resumes.
+    pub resume:        Option<Box<States<'static>>>,
+}
+
+/// Enough of a `FormField` to show the question to the user.
+#[derive(Debug, Clone)]
+pub struct PausedQuestion {
+    pub label:   String,
+    pub kind:    ApplicationElements,
+    pub options: Vec<String>,
+}
+
+impl From<&FormField> for PausedQuestion {
+    fn from(field: &FormField) -> Self {
+        Self {
+            label:   field.label.clone(),
+            kind:    field.kind,
+            options: field.options.clone(),
+        }
+    }
+}
+
+impl PausedState {
+    /// Pause used when the application form asks something we have no autofill answer for.
+    pub fn for_question(field: &FormField, timeout_secs: u64) -> Self {
+        Self {
+            reason:        format!("No autofill answer for '{}'", field.label),
+            duration_secs: timeout_secs,
+            can_resume:    true,
+            question:      Some(PausedQuestion::from(field)),
+            resume:        None,
+        }
+    }
+
+    /// Shows why we're paused (and the question, if any) then waits on the user.
+    ///
+    /// * With a question: returns the user's answer, or `None` if they left it empty
+    ///   (or `duration_secs` ran out, when non-zero).
+    /// * Without a question: waits `duration_secs`, or for Enter when that is zero.
+    pub async fn wait_for_user(&self) -> Result<Option<String>> {
+        warn!("Paused: {}", self.reason);
+
+        if !self.can_resume {
+            return Ok(None);
+        }
+
+        let Some(question) = &self.question else {
+            if self.duration_secs > 0 {
+                tokio::time::sleep(Duration::from_secs(self.duration_secs)).await;
+            } else {
+                read_line("Press Enter to resume...").await?;
+            }
+            return Ok(None);
+        };
+
+        println!();
+        println!("Question: {}", question.label);
+        match question.kind {
+            ApplicationElements::BulletPoint | ApplicationElements::Dropdown => {
+                for (idx, option) in question.options.iter().enumerate() {
+                    println!("  {}) {}", idx + 1, option);
+                }
+                println!("Enter an option number or its text.");
+            }
+            ApplicationElements::TickBox | ApplicationElements::FollowCompanyTickBox => {
+                println!("Tick this box? [y/n]");
+            }
+            ApplicationElements::NumberField => println!("Enter a number."),
+            ApplicationElements::TextField | ApplicationElements::ResumeUpload => {}
+        }
+
+        let timeout = Some(Duration::from_secs(self.duration_secs)).filter(|t| !t.is_zero());
+        let answer = read_line_within("Answer (empty to skip this job): ", timeout)
+            .await?
+            .unwrap_or_else(|| {
+                warn!("No answer within {} seconds, skipping.", self.duration_secs);
+                String::new()
+            });
+
+        Ok(Some(answer).filter(|a| !a.is_empty()))
+    }
+}
+
+/// Lines read from stdin, by a single task for the whole process.
+///
+/// A reader per prompt would lose whatever it had buffered past the line it returned,
+/// and one dropped by a timeout would be left blocked on stdin, taking the next line with it.
+static STDIN_LINES: OnceLock<Mutex<mpsc::UnboundedReceiver<std::io::Result<String>>>> =
+    OnceLock::new();
+
+fn stdin_lines() -> &'static Mutex<mpsc::UnboundedReceiver<std::io::Result<String>>> {
+    STDIN_LINES.get_or_init(|| {
+        let (tx, rx) = mpsc::unbounded_channel();
+        // A plain thread, tokio waits on its blocking reads before letting the process exit
+        std::thread::spawn(move || {
+            for line in std::io::stdin().lines() {
+                let failed = line.is_err();
+                if tx.send(line).is_err() || failed {
+                    break;
+                }
+            }
+            // The channel closing tells `read_line_within` that stdin has ended
+        });
+        Mutex::new(rx)
+    })
+}
+
+/// Prints the prompt and reads a single trimmed line from stdin.
+pub async fn read_line(prompt: &str) -> Result<String> {
+    Ok(read_line_within(prompt, None).await?.unwrap_or_default())
+}
+
+/// Prints the prompt and reads a single trimmed line from stdin,
+/// `None` if `timeout` runs out first. A line typed after that answers the next prompt.
+pub async fn read_line_within(prompt: &str, timeout: Option<Duration>) -> Result<Option<String>> {
+    print!("{}", prompt);
+    std::io::stdout()
+        .flush()
+        .map_err(|e| Error::Generic(format!("Failed to flush stdout: {}", e)))?;
+
+    let mut lines = stdin_lines().lock().await;
+    let line = match timeout {
+        Some(timeout) => {
+            match tokio::time::timeout(timeout, lines.recv()).await {
+                Ok(line) => line,
+                Err(_) => return Ok(None),
+            }
+        }
+        None => lines.recv().await,
+    };
+
+    match line {
+        Some(Ok(line)) => Ok(Some(line.trim().to_string())),
+        Some(Err(e)) => Err(Error::Generic(format!("Failed to read from stdin: {}", e))),
+        // stdin was closed, same as an empty line
+        None => Ok(Some(String::new())),
+    }
 }
 
 #[async_trait::async_trait]
 impl Transition for PausedState {
-    async fn execute(&self) -> Result<()> {
-        warn!("Paused for {} seconds due to reason: {}", self.duration_secs, self.reason);
-        // Implement logic to handle the paused state
-        // For example, log the reason and wait for the specified duration
-        Ok(())
+    async fn execute(&self, _ctx: &mut RunContext) -> Result<States<'static>> {
+        self.wait_for_user().await?;
+
+        match (&self.resume, self.can_resume) {
+            (Some(resume), true) => Ok(*resume.clone()),
+            _ => Ok(States::Error(ErrorState::new(format!("Unable to resume: {}", self.reason)))),
+        }
     }
 
     async fn current_state(&self) -> &dyn Transition {
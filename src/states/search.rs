@@ -1,34 +1,144 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
 use fantoccini::Locator;
 
+use crate::pre_fsm::{ScannedJob, all_job_cards, next_results_page};
 // use crate::portal::Portal;
-use crate::prelude::Result;
-use crate::states::Transition;
+use crate::prelude::*;
+use crate::states::{JobValidation, RunContext, States, Transition, ValidateState};
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum SearchState {
-    FindBy(FindBy),                    // Given a Locator, find the element
-    FindAllBy(FindBy),                 // Find multiple elements by Locator
-    FindJobListings(JobListingSearch), // Specialized job listing detection
+    FindBy(FindBy),                         // Given a Locator, find the element
+    FindAllBy(FindBy),                      // Find multiple elements by Locator
+    FindJobListings(Box<JobListingSearch>), // Specialized job listing detection
+    NextPage(Box<JobListingSearch>),        // Move on to the next page of search results
 }
 
 #[async_trait::async_trait]
 impl Transition for SearchState {
-    async fn execute(&self) -> Result<()> {
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>> {
         match self {
-            SearchState::FindBy(_find_by) => {
-                // Implement logic to find a single element by locator
-                Ok(())
+            SearchState::FindBy(find_by) => {
+                let element = ctx
+                    .client
+                    .wait()
+                    .for_element(find_by.locator)
+                    .await
+                    .map_err(|e| {
+                        Error::Generic(format!(
+                            "Element not found for locator {:?}: {}",
+                            find_by.locator, e
+                        ))
+                    })?;
+
+                ctx.found = vec![element];
+                Ok(ctx.next_queued())
             }
-            SearchState::FindAllBy(_find_by) => {
-                // Implement logic to find multiple elements by locator
-                Ok(())
+            SearchState::FindAllBy(find_by) => {
+                ctx.found = ctx.client.find_all(find_by.locator).await.map_err(|e| {
+                    Error::Generic(format!(
+                        "Elements not found for locator {:?}: {}",
+                        find_by.locator, e
+                    ))
+                })?;
+
+                debug!("Found {} elements for locator {:?}", ctx.found.len(), find_by.locator);
+                Ok(ctx.next_queued())
             }
-            SearchState::FindJobListings(_search) => {
-                // 1. Use portal.selectors().job_listing to find all job cards
-                // 2. Store element references for iteration
-                // 3. Return count of found listings (0 = end of list)
-                Ok(())
+            SearchState::FindJobListings(search) => {
+                let mut search = search.clone();
+
+                if !search.scanned && search.stale {
+                    // Same page as before, only the elements changed
+                    let mut jobs = all_job_cards(
+                        &ctx.client,
+                        Arc::clone(&ctx.provider),
+                        &ctx.pool,
+                        Some(search.total_found),
+                    )
+                    .await?;
+
+                    jobs.retain(|job| !search.handled.contains(&job.job_id));
+                    jobs.truncate(search.total_found.saturating_sub(search.handled.len()));
+
+                    search.current_index = 0;
+                    search.jobs = jobs;
+                    search.scanned = true;
+                    search.stale = false;
+                } else if !search.scanned {
+                    let remaining = ctx
+                        .config
+                        .search
+                        .max_jobs
+                        .map(|max_jobs| max_jobs.saturating_sub(search.jobs_read));
+                    let jobs =
+                        all_job_cards(&ctx.client, Arc::clone(&ctx.provider), &ctx.pool, remaining)
+                            .await?;
+
+                    search.pages_read += 1;
+                    search.jobs_read += jobs.len();
+                    search.total_found = jobs.len();
+                    search.current_index = 0;
+                    search.jobs = jobs;
+                    search.scanned = true;
+                    ctx.counters.pages += 1;
+                    ctx.counters.listings_found += search.total_found;
+                }
+
+                if search.last_handled.is_some() {
+                    ctx.save_progress(&search, false).await;
+                }
+
+                match search.current() {
+                    Some(job) => {
+                        debug!(
+                            "Next job listing {}/{} on page {}: {}",
+                            search.handled.len() + 1,
+                            search.total_found,
+                            search.pages_read,
+                            job.job_id
+                        );
+                        Ok(States::Validate(ValidateState::CheckJob(search)))
+                    }
+                    None => {
+                        info!(
+                            "All {} job listings on page {} have been processed.",
+                            search.total_found, search.pages_read
+                        );
+                        Ok(States::Search(SearchState::NextPage(search)))
+                    }
+                }
+            }
+            SearchState::NextPage(search) => {
+                ctx.save_progress(search, true).await;
+
+                let limits = &ctx.config.search;
+
+                if limits.max_jobs.is_some_and(|max| search.jobs_read >= max) {
+                    info!("Job limit ({} listings) reached, stopping search.", search.jobs_read);
+                    return Ok(States::Completed);
+                }
+                if limits.max_pages.is_some_and(|max| search.pages_read >= max) {
+                    info!("Page limit ({} pages) reached, stopping search.", search.pages_read);
+                    return Ok(States::Completed);
+                }
+
+                if !next_results_page(&ctx.client, Arc::clone(&ctx.provider)).await? {
+                    info!("No more pages of search results after page {}.", search.pages_read);
+                    return Ok(States::Completed);
+                }
+
+                let mut search = search.clone();
+                search.jobs.clear();
+                search.handled.clear();
+                search.scanned = false;
+                search.current_index = 0;
+                search.total_found = 0;
+
+                Ok(States::Search(SearchState::FindJobListings(search)))
             }
         }
     }
@@ -43,9 +153,57 @@ pub struct FindBy {
     pub locator: Locator<'static>,
 }
 
-#[derive(Debug, Clone)]
+/// The job loop's cursor, the cards scanned from the current results page and how far through them
+/// we are.
+///
+/// It's handed from state to state (Search -> Validate -> Action -> Search) as the run progresses,
+/// once the page is exhausted `SearchState::NextPage` moves it on to the next one.
+#[derive(Debug, Clone, Default)]
 pub struct JobListingSearch {
     // pub portal:        Portal,
+    pub jobs:          Vec<ScannedJob>,
+    /// Whether the current page of listings has been read into `jobs` yet.
+    pub scanned:       bool,
+    /// The page was reloaded and `jobs` hold stale elements, re-read it skipping `handled` jobs.
+    pub stale:         bool,
+    /// Jobs on the current page we're done with.
+    pub handled:       HashSet<i64>,
     pub current_index: usize,
+    /// Listings on the current page.
     pub total_found:   usize,
+    pub pages_read:    u32,
+    /// Listings read across every page so far.
+    pub jobs_read:     usize,
+    /// The last job we were done with, across every page.
+    pub last_handled:  Option<i64>,
+}
+
+impl JobListingSearch {
+    /// The job currently being worked on, `None` once every listing has been processed.
+    pub fn current(&self) -> Option<&ScannedJob> {
+        self.jobs.get(self.current_index)
+    }
+
+    /// Marks the current listing as handled and moves the cursor on to the next one.
+    pub fn next_job(mut self: Box<Self>) -> Box<Self> {
+        if let Some(job_id) = self.current().map(|job| job.job_id) {
+            self.handled.insert(job_id);
+            self.last_handled = Some(job_id);
+        }
+        self.current_index += 1;
+        self
+    }
+
+    pub fn validation(&self, ctx: &RunContext) -> Option<JobValidation> {
+        self.current().map(|job| {
+            JobValidation {
+                client:        ctx.client.clone(),
+                provider:      Arc::clone(&ctx.provider),
+                pool:          ctx.pool.clone(),
+                selector_kind: ctx.selector_kind,
+                filters:       Arc::clone(&ctx.filters),
+                job:           job.clone(),
+            }
+        })
+    }
 }
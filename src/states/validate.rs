@@ -1,15 +1,39 @@
-// use crate::portal::Portal;
-use crate::prelude::Result;
-use crate::states::Transition;
+use std::sync::Arc;
+
+use fantoccini::Client;
+use sqlx::SqlitePool;
+
+use crate::db::{
+    StatusType,
+    create_job_rejection,
+    delete_job_rejections,
+    get_job_status,
+    mark_job_failed,
+    update_job_skip_reason,
+    update_job_status,
+};
+use crate::filters::{FilterRules, Rejection};
+use crate::output::ReportRow;
+use crate::pre_fsm::{ScannedJob, scrape_job_description, wait_millis};
+use crate::prelude::*;
+use crate::providers::{Provider, ProviderKind, SelectorKind};
+use crate::selectors::SelectorField;
+use crate::states::{ActionState, JobListingSearch, RunContext, SearchState, States, Transition};
 
 #[derive(Debug, Clone)]
 pub enum ValidateState {
-    CheckJob(JobValidation),
+    /// Validates the job under the cursor.
+    CheckJob(Box<JobListingSearch>),
 }
 
 #[derive(Debug, Clone)]
 pub struct JobValidation {
-    // pub portal: Portal,
+    pub client:        Client,
+    pub provider:      Arc<dyn Provider + Send + Sync>,
+    pub pool:          SqlitePool,
+    pub selector_kind: SelectorKind,
+    pub filters:       Arc<FilterRules>,
+    pub job:           ScannedJob,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -23,18 +47,232 @@ pub enum InvalidReason {
     AlreadyApplied,
     NoEasyApply,
     JobClosed,
+    /// Failed one or more of the filter rules, see `db.job_rejection` for which.
+    Filtered,
+}
+
+impl From<InvalidReason> for &str {
+    fn from(reason: InvalidReason) -> Self {
+        match reason {
+            InvalidReason::AlreadyApplied => "already_applied",
+            InvalidReason::NoEasyApply => "no_easy_apply",
+            InvalidReason::JobClosed => "job_closed",
+            InvalidReason::Filtered => "filtered",
+        }
+    }
+}
+
+impl InvalidReason {
+    /// The status a job skipped for this reason is moved to.
+    pub fn status(self) -> StatusType {
+        match self {
+            InvalidReason::AlreadyApplied => StatusType::Completed,
+            InvalidReason::NoEasyApply | InvalidReason::JobClosed => StatusType::Skipped,
+            InvalidReason::Filtered => StatusType::Filtered,
+        }
+    }
+}
+
+impl JobValidation {
+    /// Decides whether the job can be applied to.
+    ///
+    /// 1. Cross-check the jobs database for a previous application
+    /// 2. Check the filter rules that only need the job card
+    /// 3. Open the job card so the details pane is showing
+    /// 4. Store the job description from the details pane
+    /// 5. Check for the "Applied" badge, job closed indicator, and Easy Apply button
+    /// 6. Check the filter rules against the job description
+    ///
+    /// When the job is invalid, the reason is recorded against the job row and the job is
+    /// moved to the matching status (see `InvalidReason::status`). A job skipped or filtered
+    /// earlier that is valid now (ie: the filters were loosened) goes back to pending.
+    pub async fn validate(&self) -> Result<ValidationResult> {
+        let status = get_job_status(&self.pool, self.job.job_id).await?;
+        let result = self.check(status).await?;
+
+        match result {
+            ValidationResult::InvalidJob(reason) => {
+                info!(
+                    "Skipping job {} ({} @ {}): {:?}",
+                    self.job.job_id, self.job.card.job_title, self.job.card.company_name, reason
+                );
+                update_job_skip_reason(&self.pool, self.job.job_id, Some(reason.into())).await?;
+                update_job_status(
+                    &self.pool,
+                    self.job.job_id,
+                    reason.status(),
+                    Some(reason.into()),
+                )
+                .await?;
+            }
+            ValidationResult::ValidJob
+                if matches!(status, Some(StatusType::Skipped | StatusType::Filtered)) =>
+            {
+                update_job_skip_reason(&self.pool, self.job.job_id, None).await?;
+                update_job_status(
+                    &self.pool,
+                    self.job.job_id,
+                    StatusType::Pending,
+                    Some("passed validation"),
+                )
+                .await?;
+            }
+            ValidationResult::ValidJob => {}
+        }
+
+        Ok(result)
+    }
+
+    async fn check(&self, status: Option<StatusType>) -> Result<ValidationResult> {
+        if matches!(status, Some(StatusType::Submitted | StatusType::Completed)) {
+            return Ok(ValidationResult::InvalidJob(InvalidReason::AlreadyApplied));
+        }
+
+        // The card already tells us there's no Easy Apply, no need to open it.
+        // Seek doesn't mark "Quick apply" on every card, so only the details pane is trusted there
+        if !self.job.card.has_easy_apply && self.provider.name() == ProviderKind::LinkedIn {
+            return Ok(ValidationResult::InvalidJob(InvalidReason::NoEasyApply));
+        }
+
+        let rejections = self.filters.check_card(&self.job.card);
+        if !rejections.is_empty() {
+            return self.reject(rejections).await;
+        }
+
+        self.provider
+            .with_action(ActionState::Click {
+                element: self.job.element.clone(),
+            })
+            .await?;
+        wait_millis(750).await;
+
+        let description = scrape_job_description(
+            &self.client,
+            self.provider.as_ref(),
+            &self.pool,
+            self.job.job_id,
+        )
+        .await;
+
+        if let Some(reason) = check_job_details(&self.client, self.provider.as_ref()).await? {
+            return Ok(ValidationResult::InvalidJob(reason));
+        }
+
+        if self.filters.has_description_rules() {
+            let rejections = self
+                .filters
+                .check_description(description.as_ref().map(|d| d.text.as_str()));
+            if !rejections.is_empty() {
+                return self.reject(rejections).await;
+            }
+        }
+
+        Ok(ValidationResult::ValidJob)
+    }
+
+    /// Records why the filters rejected the job (see `record_rejections`).
+    async fn reject(&self, rejections: Vec<Rejection>) -> Result<ValidationResult> {
+        record_rejections(&self.pool, self.job.job_id, &rejections).await?;
+        Ok(ValidationResult::InvalidJob(InvalidReason::Filtered))
+    }
+}
+
+/// Records why the filters rejected the job, replacing any earlier rejections.
+pub async fn record_rejections(
+    pool: &SqlitePool,
+    job_id: i64,
+    rejections: &[Rejection],
+) -> Result<()> {
+    delete_job_rejections(pool, job_id).await?;
+
+    for rejection in rejections {
+        debug!("Job {} rejected by {:?}: {}", job_id, rejection.rule, rejection.detail);
+        create_job_rejection(pool, job_id, rejection.rule.into(), &rejection.detail).await?;
+    }
+    Ok(())
+}
+
+/// Checks the job that is currently open (details pane or the job's own page)
+/// for the "Applied" badge, job closed indicator, and Easy Apply button.
+///
+/// Returns the reason the job can't be applied to, if any.
+pub async fn check_job_details(
+    client: &Client,
+    provider: &(dyn Provider + Send + Sync),
+) -> Result<Option<InvalidReason>> {
+    if provider
+        .find_optional_field(client, SelectorField::job_listing("applied_badge"))
+        .await?
+        .is_some()
+    {
+        return Ok(Some(InvalidReason::AlreadyApplied));
+    }
+
+    if provider
+        .find_optional_field(client, SelectorField::job_listing("job_closed_indicator"))
+        .await?
+        .is_some()
+    {
+        return Ok(Some(InvalidReason::JobClosed));
+    }
+
+    if provider
+        .find_optional_field(client, SelectorField::job_listing("easy_apply_button"))
+        .await?
+        .is_none()
+    {
+        return Ok(Some(InvalidReason::NoEasyApply));
+    }
+
+    Ok(None)
 }
 
 #[async_trait::async_trait]
 impl Transition for ValidateState {
-    async fn execute(&self) -> Result<()> {
+    async fn execute(&self, ctx: &mut RunContext) -> Result<States<'static>> {
         match self {
-            ValidateState::CheckJob(_validation) => {
-                // 1. Check for Easy Apply button (portal.selectors().easy_apply_button)
-                // 2. Check for "Applied" badge indicating already applied
-                // 3. Check for job closed/expired indicators
-                // 4. Return ValidationResult::ValidJob or InvalidJob with reason
-                Ok(())
+            ValidateState::CheckJob(search) => {
+                let Some(validation) = search.validation(ctx) else {
+                    return Ok(States::Search(SearchState::FindJobListings(search.clone())));
+                };
+
+                match validation.validate().await {
+                    Ok(ValidationResult::ValidJob) if ctx.scan_only => {
+                        info!("Job {} is valid, leaving it pending.", validation.job.job_id);
+                        ctx.counters.validated += 1;
+                        Ok(States::Search(SearchState::FindJobListings(search.clone().next_job())))
+                    }
+                    Ok(ValidationResult::ValidJob) => {
+                        info!("Job {} is valid, applying.", validation.job.job_id);
+                        ctx.counters.validated += 1;
+                        Ok(States::Action(ActionState::Apply(search.clone())))
+                    }
+                    Ok(result) => {
+                        debug!("Validation result for job {}: {:?}", validation.job.job_id, result);
+                        // Jobs we've already applied to were reported back when we applied
+                        if let ValidationResult::InvalidJob(reason) = result
+                            && reason != InvalidReason::AlreadyApplied
+                        {
+                            ctx.reports.record(
+                                &ReportRow::for_card(ctx.provider.as_ref(), &validation.job.card)
+                                    .with_skip_reason(reason),
+                            );
+                        }
+                        ctx.counters.skipped += 1;
+                        Ok(States::Search(SearchState::FindJobListings(search.clone().next_job())))
+                    }
+                    // One bad listing shouldn't end the run, move on to the next one
+                    Err(e) => {
+                        error!("Failed to validate job {}: {}", validation.job.job_id, e);
+                        // The page going away leaves the job pending for the next run,
+                        // a write that didn't land leaves it half recorded
+                        if matches!(e, Error::DatabaseError(_)) {
+                            mark_job_failed(&ctx.pool, validation.job.job_id, &e).await;
+                        }
+                        ctx.counters.errors += 1;
+                        Ok(States::Search(SearchState::FindJobListings(search.clone().next_job())))
+                    }
+                }
             }
         }
     }